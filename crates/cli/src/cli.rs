@@ -32,6 +32,7 @@ use std::{
 };
 
 pub mod get;
+pub mod init;
 
 /// Global CLI arguments for forge configuration and debugging.
 #[derive(Parser, Debug)]
@@ -48,8 +49,9 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub base_branch: Option<String>,
 
-    /// Path to the configuration file. Defaults to "releasaurus.toml" in the
-    /// repository root
+    /// Path to the configuration file. YAML, JSON, pyproject.toml and
+    /// Cargo.toml are detected by file name. Defaults to searching the
+    /// standard locations, starting with "releasaurus.toml"
     #[arg(long, global = true, env = "RELEASAURUS_CONFIG")]
     pub config: Option<PathBuf>,
 
@@ -325,6 +327,8 @@ pub struct CliPackageOverrides {
     #[serde(rename = "versioning.prerelease.strategy")]
     #[merge(strategy = merge::option::overwrite_none)]
     pub prerelease_strategy: Option<PrereleaseStrategy>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    pub release_as: Option<String>,
 }
 
 impl From<CliPackageOverrides> for PackageOverrides {
@@ -334,6 +338,7 @@ impl From<CliPackageOverrides> for PackageOverrides {
             prerelease_suffix: value.prerelease_suffix,
             tag_prefix: value.tag_prefix,
             version_type: value.version_type,
+            release_as: value.release_as,
         }
     }
 }
@@ -351,6 +356,17 @@ pub struct SharedCommandOverrides {
     )]
     package_overrides: Vec<PackagePathOverride>,
 
+    /// Release a package at an exact version instead of the computed one.
+    /// Commits are still analyzed for the release notes. Must be in the
+    /// form "package=version". Can be provided more than once
+    /// Example: --release-as my-pkg=3.0.0
+    #[arg(
+        long,
+        value_parser = parse_release_as,
+        value_name = "PACKAGE=VERSION"
+    )]
+    release_as: Vec<PackagePathOverride>,
+
     /// Global override for tag_prefix. Overrides package config. Can
     /// be overridden via explicit "--set-package" override
     #[arg(long)]
@@ -479,6 +495,25 @@ fn parse_package_override(s: &str) -> Result<PackagePathOverride> {
     })
 }
 
+fn parse_release_as(s: &str) -> Result<PackagePathOverride> {
+    match s.split_once('=') {
+        Some((package_name, version))
+            if !package_name.is_empty() && !version.is_empty() =>
+        {
+            Ok(PackagePathOverride {
+                package_name: package_name.to_string(),
+                path: "release_as".to_string(),
+                value: version.to_string(),
+            })
+        }
+        _ => Err(ReleasaurusError::invalid_config(format!(
+            "Invalid --release-as format: '{}'. Expected \
+             'package=version'. Example: --release-as 'my-pkg=3.0.0'",
+            s
+        ))),
+    }
+}
+
 fn parse_reworded_commit(s: &str) -> Result<RewordedCommit> {
     let parts: Vec<&str> = s.splitn(2, '=').collect();
     if parts.len() != 2 {
@@ -543,6 +578,27 @@ pub enum GetCommand {
         tag: String,
     },
 
+    /// Validates the configuration against the repository and outputs the
+    /// effective settings of each package along with where each value came
+    /// from (cli, package, defaults, derived or built-in)
+    #[command(visible_alias = "check-config")]
+    Config {
+        /// Output the explained configuration directly to file
+        #[arg(short, long)]
+        out_file: Option<String>,
+
+        /// Optionally restrict output to just 1 specific package
+        #[arg(short, long)]
+        package: Option<String>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = ConfigFormat::Json)]
+        format: ConfigFormat,
+
+        #[command(flatten)]
+        overrides: SharedCommandOverrides,
+    },
+
     /// Ingests json file generated from "get next-release" and converts
     /// from release json to notes json using configured tera template.
     /// Outputs a  json array of package name and associated notes. This
@@ -561,6 +617,30 @@ pub enum GetCommand {
     },
 }
 
+/// Changelog maintenance subcommands.
+#[derive(Subcommand, Debug)]
+pub enum ChangelogCommand {
+    /// Rebuilds changelog files from every tag matching each package's
+    /// tag_prefix, rendering each release with the current configuration,
+    /// and commits them to the base branch
+    Regenerate {
+        /// Optionally restrict regeneration to just 1 specific package
+        #[arg(short, long)]
+        package: Option<String>,
+
+        /// Execute in dry-run mode
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+    },
+}
+
+/// Output formats for "get config".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ConfigFormat {
+    Json,
+    Table,
+}
+
 /// Release operation subcommands.
 #[derive(Subcommand, Debug)]
 pub enum Command {
@@ -576,6 +656,11 @@ pub enum Command {
         #[arg(short, long)]
         package: Option<String>,
 
+        /// Fail instead of warning when a configured manifest target is
+        /// not updated with the new version
+        #[arg(long, default_value_t = false)]
+        strict: bool,
+
         /// Execute in dry-run mode
         #[arg(long, default_value_t = false)]
         dry_run: bool,
@@ -599,6 +684,29 @@ pub enum Command {
         command: GetCommand,
     },
 
+    /// Maintains changelog files outside of the release workflow
+    Changelog {
+        #[command(subcommand)]
+        command: ChangelogCommand,
+    },
+
+    /// Detects the packages in the repository and writes a starter
+    /// releasaurus.toml
+    Init {
+        /// Path to write the generated configuration to. Defaults to
+        /// releasaurus.toml in --local-path, or the current directory
+        #[arg(short, long)]
+        out_file: Option<PathBuf>,
+
+        /// Overwrite the output file if it already exists
+        #[arg(long, default_value_t = false)]
+        force: bool,
+
+        /// Print the generated configuration instead of writing a file
+        #[arg(long, default_value_t = false)]
+        print: bool,
+    },
+
     /// Performs patch version update in manifest version files to start next
     /// release. This does not create any PRs or perform any tagging. It updates
     /// the version files and commits the changes to the targeted base branch
@@ -646,7 +754,13 @@ impl Cli {
 
         let mut map_overrides =
             |overrides: &SharedCommandOverrides| -> Result<()> {
-                for path_override in overrides.package_overrides.clone() {
+                // --release-as is shorthand for a release_as path override,
+                // applied last so it wins over --set-package
+                for path_override in overrides
+                    .package_overrides
+                    .iter()
+                    .chain(overrides.release_as.iter())
+                {
                     let value = serde_json::json!({
                       path_override.path.clone(): path_override.value
                     });
@@ -682,7 +796,9 @@ impl Cli {
                 map_overrides(overrides)?;
             }
             Command::Get {
-                command: GetCommand::NextRelease { overrides, .. },
+                command:
+                    GetCommand::NextRelease { overrides, .. }
+                    | GetCommand::Config { overrides, .. },
             } => {
                 map_overrides(overrides)?;
             }
@@ -702,7 +818,9 @@ impl Cli {
             Command::ReleasePR { overrides, .. } => Some(overrides),
             Command::StartNext { overrides, .. } => Some(overrides),
             Command::Get {
-                command: GetCommand::NextRelease { overrides, .. },
+                command:
+                    GetCommand::NextRelease { overrides, .. }
+                    | GetCommand::Config { overrides, .. },
             } => Some(overrides),
             _ => None,
         };
@@ -875,6 +993,43 @@ mod tests {
         assert_eq!(parsed.value, "v=1");
     }
 
+    /// `--release-as` lands in the same per-package map as `--set-package`
+    /// and wins over a `release_as` set through it.
+    #[test]
+    fn get_package_overrides_includes_release_as() {
+        let cli = Cli::try_parse_from([
+            "releasaurus",
+            "release-pr",
+            "--set-package",
+            "frontend.release_as=2.0.0",
+            "--set-package",
+            "frontend.tag_prefix=fe-v",
+            "--release-as",
+            "frontend=3.0.0",
+            "--release-as",
+            "backend=1.0.0",
+        ])
+        .expect("--release-as should parse");
+
+        let overrides = cli.get_package_overrides().unwrap();
+
+        assert_eq!(overrides["frontend"].release_as.as_deref(), Some("3.0.0"));
+        assert_eq!(overrides["frontend"].tag_prefix.as_deref(), Some("fe-v"));
+        assert_eq!(overrides["backend"].release_as.as_deref(), Some("1.0.0"));
+    }
+
+    #[test]
+    fn parse_release_as_rejects_missing_version() {
+        for value in ["frontend", "frontend=", "=3.0.0"] {
+            let result = parse_release_as(value);
+
+            assert!(
+                matches!(result, Err(ReleasaurusError::InvalidConfig(_))),
+                "{value} should have errored"
+            );
+        }
+    }
+
     #[test]
     fn parse_package_override_rejects_missing_equals() {
         let result = parse_package_override("frontend.tag_prefix");
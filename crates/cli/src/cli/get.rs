@@ -1,9 +1,17 @@
 //! Shows information about prior and upcoming releases
-use releasaurus_core::{orchestrator::Orchestrator, result::Result};
+use releasaurus_core::{
+    forge::manager::ForgeManager,
+    orchestrator::Orchestrator,
+    resolver::{
+        Resolver,
+        explain::{ExplainedSetting, PackageExplanation},
+    },
+    result::{ReleasaurusError, Result},
+};
 use std::path::Path;
 use tokio::fs;
 
-use crate::cli::GetCommand;
+use crate::cli::{ConfigFormat, GetCommand};
 
 /// Get projected next release info as JSON, optionally filtered by package name
 pub async fn execute(
@@ -23,6 +31,119 @@ pub async fn execute(
         GetCommand::RecompiledNotes { file, out_file } => {
             get_notes(orchestrator, file, out_file).await
         }
+        GetCommand::Config { .. } => {
+            unreachable!("get config runs before an orchestrator is created")
+        }
+    }
+}
+
+/// Validates the configuration and shows the effective settings of each
+/// package with their provenance. Errors after printing if any configured
+/// path or manifest is missing.
+pub async fn get_config(
+    forge_manager: &ForgeManager,
+    resolver: &Resolver,
+    package: Option<String>,
+    format: ConfigFormat,
+    out_file: Option<String>,
+) -> Result<()> {
+    let mut explanations = resolver.check(forge_manager).await?;
+
+    if let Some(package) = package {
+        explanations.retain(|e| e.name == package);
+
+        if explanations.is_empty() {
+            return Err(ReleasaurusError::invalid_config(format!(
+                "Package not found: '{package}'"
+            )));
+        }
+    }
+
+    match format {
+        ConfigFormat::Json => {
+            print_json(serde_json::json!(explanations), out_file.clone())
+                .await?
+        }
+        ConfigFormat::Table => {
+            write_output(render_config_table(&explanations), out_file.clone())
+                .await?
+        }
+    }
+
+    let problems = explanations
+        .iter()
+        .flat_map(|e| e.problems.iter())
+        .collect::<Vec<_>>();
+
+    if !problems.is_empty() {
+        return Err(ReleasaurusError::invalid_config(format!(
+            "configuration check found {} problem(s): {}",
+            problems.len(),
+            problems
+                .iter()
+                .map(|p| p.as_str())
+                .collect::<Vec<_>>()
+                .join("; ")
+        )));
+    }
+
+    Ok(())
+}
+
+/// Renders explanations as one aligned key / value / source table per
+/// package, followed by any problems found.
+fn render_config_table(explanations: &[PackageExplanation]) -> String {
+    let mut out = String::new();
+
+    for explanation in explanations {
+        let rows = explanation
+            .settings
+            .iter()
+            .map(|s| (s.key.as_str(), table_value(s), s.source.to_string()))
+            .collect::<Vec<_>>();
+
+        let key_width = rows.iter().map(|r| r.0.len()).max().unwrap_or(0);
+        let value_width =
+            rows.iter().map(|r| r.1.chars().count()).max().unwrap_or(0);
+
+        out.push_str(&format!("package: {}\n", explanation.name));
+
+        for (key, value, source) in rows {
+            out.push_str(&format!(
+                "  {key:<key_width$}  {value:<value_width$}  {source}\n"
+            ));
+        }
+
+        for problem in explanation.problems.iter() {
+            out.push_str(&format!("  problem: {problem}\n"));
+        }
+
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Shows strings without quotes and keeps multi-line templates to their
+/// first line so the table stays readable.
+fn table_value(setting: &ExplainedSetting) -> String {
+    const MAX_WIDTH: usize = 48;
+
+    let value = match &setting.value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Null => "-".into(),
+        other => other.to_string(),
+    };
+
+    let mut lines = value.lines();
+    let first = lines.next().unwrap_or_default();
+    let truncated = lines.next().is_some() || first.chars().count() > MAX_WIDTH;
+
+    if truncated {
+        let head = first.chars().take(MAX_WIDTH).collect::<String>();
+        format!("{head}…")
+    } else {
+        first.to_string()
     }
 }
 
@@ -94,12 +215,62 @@ async fn print_json(
     Ok(())
 }
 
+async fn write_output(content: String, out_file: Option<String>) -> Result<()> {
+    if let Some(out_file) = out_file {
+        let file_path = Path::new(&out_file);
+
+        if let Some(parent) = file_path.parent()
+            && !parent.exists()
+        {
+            fs::create_dir_all(parent).await?;
+        }
+
+        log::info!("writing output to: {}", file_path.display());
+        fs::write(file_path, &content).await?;
+    } else {
+        print!("{content}");
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use releasaurus_core::resolver::explain::SettingSource;
     use serde_json::json;
     use tempfile::TempDir;
 
+    #[test]
+    fn render_config_table_aligns_settings_and_lists_problems() {
+        let explanations = vec![PackageExplanation {
+            name: "api".into(),
+            settings: vec![
+                ExplainedSetting {
+                    key: "tag_prefix".into(),
+                    value: json!("api-v"),
+                    source: SettingSource::Derived,
+                },
+                ExplainedSetting {
+                    key: "changelog.body".into(),
+                    value: json!("line one\nline two"),
+                    source: SettingSource::BuiltIn,
+                },
+            ],
+            problems: vec!["api: package path api does not exist".into()],
+        }];
+
+        let table = render_config_table(&explanations);
+
+        assert_eq!(
+            table,
+            "package: api\n\
+             \x20 tag_prefix      api-v      derived\n\
+             \x20 changelog.body  line one…  built-in\n\
+             \x20 problem: api: package path api does not exist\n\n"
+        );
+    }
+
     #[tokio::test]
     async fn print_json_writes_to_file() {
         let temp_dir = TempDir::new().unwrap();
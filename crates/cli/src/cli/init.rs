@@ -0,0 +1,104 @@
+//! Scaffolds a releasaurus.toml from the packages detected in a repository
+use releasaurus_core::{
+    config::DEFAULT_CONFIG_FILE,
+    forge::manager::ForgeManager,
+    result::{ReleasaurusError, Result},
+    scaffold,
+};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+/// Detect packages and either print the generated configuration or write it
+/// to `out_file`
+pub async fn execute(
+    forge_manager: &ForgeManager,
+    branch: Option<String>,
+    out_file: PathBuf,
+    force: bool,
+    print: bool,
+) -> Result<()> {
+    let packages = scaffold::detect_packages(forge_manager, branch).await?;
+
+    for package in packages.iter() {
+        log::info!(
+            "detected package {} at {} (release_type: {}, tag_prefix: \"{}\")",
+            package.name,
+            package.path,
+            package.release_type,
+            package.tag_prefix
+        );
+    }
+
+    let content = scaffold::render_config(&packages)?;
+
+    if print {
+        print!("{content}");
+        return Ok(());
+    }
+
+    write_config(&out_file, &content, force).await
+}
+
+/// Default location of the generated file: the configured local repository
+/// path when one is given, otherwise the current directory
+pub fn default_out_file(local_path: Option<&Path>) -> PathBuf {
+    local_path
+        .unwrap_or(Path::new("."))
+        .join(DEFAULT_CONFIG_FILE)
+}
+
+async fn write_config(
+    file_path: &Path,
+    content: &str,
+    force: bool,
+) -> Result<()> {
+    if !force && fs::try_exists(file_path).await? {
+        return Err(ReleasaurusError::InvalidArgs(format!(
+            "{} already exists; pass --force to overwrite it",
+            file_path.display()
+        )));
+    }
+
+    if let Some(parent) = file_path.parent()
+        && !parent.as_os_str().is_empty()
+        && !parent.exists()
+    {
+        fs::create_dir_all(parent).await?;
+    }
+
+    log::info!("writing configuration to: {}", file_path.display());
+    fs::write(file_path, content).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn default_out_file_prefers_local_path() {
+        assert_eq!(
+            default_out_file(Some(Path::new("/tmp/repo"))),
+            PathBuf::from("/tmp/repo/releasaurus.toml")
+        );
+        assert_eq!(default_out_file(None), PathBuf::from("./releasaurus.toml"));
+    }
+
+    #[tokio::test]
+    async fn write_config_refuses_to_overwrite_without_force() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("releasaurus.toml");
+        fs::write(&file_path, "existing").await.unwrap();
+
+        let result = write_config(&file_path, "new", false).await;
+
+        assert!(matches!(result, Err(ReleasaurusError::InvalidArgs(_))));
+        assert_eq!(fs::read_to_string(&file_path).await.unwrap(), "existing");
+
+        write_config(&file_path, "new", true).await.unwrap();
+
+        assert_eq!(fs::read_to_string(&file_path).await.unwrap(), "new");
+    }
+}
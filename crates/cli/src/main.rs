@@ -16,7 +16,7 @@
 
 use clap::Parser;
 use color_eyre::eyre::Result;
-use releasaurus::cli::{Cli, Command, GetCommand, get};
+use releasaurus::cli::{ChangelogCommand, Cli, Command, GetCommand, get, init};
 use releasaurus_core::config::overrides::PackageOverrides;
 use releasaurus_core::forge::manager::{ForgeManager, ForgeOptions};
 use releasaurus_core::orchestrator::Orchestrator;
@@ -30,6 +30,10 @@ const DRY_RUN_ENV_VAR: &str = "RELEASAURUS_DRY_RUN";
 fn silence_logs(cli: &Cli) -> bool {
     let mut silent = false;
 
+    if let Command::Init { print: true, .. } = &cli.command {
+        silent = true;
+    }
+
     if let Command::Get { command, .. } = &cli.command {
         match command {
             GetCommand::NextRelease { out_file, .. } => {
@@ -52,6 +56,11 @@ fn silence_logs(cli: &Cli) -> bool {
                     silent = true;
                 }
             }
+            GetCommand::Config { out_file, .. } => {
+                if out_file.is_none() {
+                    silent = true;
+                }
+            }
         }
     }
 
@@ -94,11 +103,23 @@ fn get_dry_run_value(cli: &Cli) -> bool {
         Command::Release { dry_run, .. } => dry_run,
         Command::ReleasePR { dry_run, .. } => dry_run,
         Command::StartNext { dry_run, .. } => dry_run,
+        Command::Changelog {
+            command: ChangelogCommand::Regenerate { dry_run, .. },
+        } => dry_run,
         _ => false,
     }
 }
 
-async fn create_orchestrator(cli: &Cli, dry_run: bool) -> Result<Orchestrator> {
+fn get_strict_value(cli: &Cli) -> bool {
+    matches!(cli.command, Command::ReleasePR { strict: true, .. })
+}
+
+/// Loads the configuration through the forge and builds the resolver that
+/// layers CLI overrides on top of it.
+async fn create_resolver(
+    cli: &Cli,
+    dry_run: bool,
+) -> Result<(ForgeManager, Resolver)> {
     let mut forge = cli.forge_args.forge().await?;
 
     let global_overrides = cli.get_global_overrides();
@@ -116,8 +137,16 @@ async fn create_orchestrator(cli: &Cli, dry_run: bool) -> Result<Orchestrator> {
             .await?,
     );
 
-    forge.set_commit_search_depth(config.repository.first_release_search_depth);
-    forge.set_tag_search_depth(config.repository.tag_search_depth);
+    forge.set_commit_search_depth(
+        config.repository.first_release_search_depth(),
+    );
+    forge.set_tag_search_depth(config.repository.tag_search_depth());
+
+    // regeneration needs every tag and the commits before the first one
+    if let Command::Changelog { .. } = &cli.command {
+        forge.set_commit_search_depth(0);
+        forge.set_tag_search_depth(0);
+    }
 
     let forge_manager = ForgeManager::new(forge, ForgeOptions { dry_run });
 
@@ -129,8 +158,9 @@ async fn create_orchestrator(cli: &Cli, dry_run: bool) -> Result<Orchestrator> {
     let default_branch = forge_manager.default_branch();
     let release_link_base_url = forge_manager.release_link_base_url();
     let compare_link_base_url = forge_manager.compare_link_base_url();
+    let issue_link_base_url = forge_manager.issue_link_base_url();
 
-    let resolver = Resolver::builder()
+    let mut resolver = Resolver::builder()
         .commit_modifiers(commit_modifiers)
         .global_overrides(global_overrides)
         .package_overrides(
@@ -141,12 +171,24 @@ async fn create_orchestrator(cli: &Cli, dry_run: bool) -> Result<Orchestrator> {
         )
         .release_link_base_url(release_link_base_url.clone())
         .compare_link_base_url(compare_link_base_url.clone())
+        .issue_link_base_url(issue_link_base_url.cloned())
         .repo_default_branch(default_branch)
         .repo_name(repo_name)
         .toml_config(Rc::clone(&config))
+        .strict_manifest_updates(get_strict_value(cli))
         .build()?;
 
-    let resolved_config = resolver.resolve(config.packages.clone())?;
+    // after build, so a `${VAR}` base branch is already interpolated
+    resolver.load_mailmap(&forge_manager).await?;
+
+    Ok((forge_manager, resolver))
+}
+
+async fn create_orchestrator(cli: &Cli, dry_run: bool) -> Result<Orchestrator> {
+    let (forge_manager, resolver) = create_resolver(cli, dry_run).await?;
+
+    let packages = resolver.discover_packages(&forge_manager).await?;
+    let resolved_config = resolver.resolve(packages)?;
 
     let orchestrator = Orchestrator::builder()
         .config(resolved_config)
@@ -176,25 +218,91 @@ async fn main() -> Result<()> {
 
     initialize_logger(&cli)?;
 
-    let orchestrator = create_orchestrator(&cli, dry_run).await?;
-
     // wrap all errors using ? and manually return Ok(()) to get the benefit
     // of eyre Report
-    match cli.command {
+    match &cli.command {
+        // init runs before a configuration exists, so it works directly
+        // against the forge instead of going through the orchestrator
+        Command::Init {
+            out_file,
+            force,
+            print,
+        } => {
+            let forge_manager = ForgeManager::new(
+                cli.forge_args.forge().await?,
+                ForgeOptions { dry_run: false },
+            );
+            let out_file = out_file.clone().unwrap_or_else(|| {
+                init::default_out_file(cli.forge_args.local_path.as_deref())
+            });
+
+            init::execute(
+                &forge_manager,
+                cli.base_branch.clone(),
+                out_file,
+                *force,
+                *print,
+            )
+            .await?;
+            Ok(())
+        }
+        // config checks explain the resolver's work, so they need the
+        // resolver itself rather than the orchestrator built from its output
+        Command::Get {
+            command:
+                GetCommand::Config {
+                    out_file,
+                    package,
+                    format,
+                    ..
+                },
+        } => {
+            let (forge_manager, resolver) =
+                create_resolver(&cli, dry_run).await?;
+
+            get::get_config(
+                &forge_manager,
+                &resolver,
+                package.clone(),
+                *format,
+                out_file.clone(),
+            )
+            .await?;
+            Ok(())
+        }
         Command::ReleasePR { package, .. } => {
-            orchestrator.create_release_prs(package).await?;
+            create_orchestrator(&cli, dry_run)
+                .await?
+                .create_release_prs(package.clone())
+                .await?;
             Ok(())
         }
         Command::Release { package, .. } => {
-            orchestrator.create_releases(package).await?;
+            create_orchestrator(&cli, dry_run)
+                .await?
+                .create_releases(package.clone())
+                .await?;
             Ok(())
         }
         Command::Get { command } => {
-            get::execute(orchestrator, command).await?;
+            let orchestrator = create_orchestrator(&cli, dry_run).await?;
+            get::execute(orchestrator, command.clone()).await?;
             Ok(())
         }
         Command::StartNext { packages, .. } => {
-            orchestrator.start_next_release(packages).await?;
+            create_orchestrator(&cli, dry_run)
+                .await?
+                .start_next_release(packages.clone())
+                .await?;
+            Ok(())
+        }
+        Command::Changelog {
+            command: ChangelogCommand::Regenerate { package, .. },
+        } => {
+            create_orchestrator(&cli, dry_run)
+                .await?
+                .regenerate_changelogs(package.clone())
+                .await?;
             Ok(())
         }
     }
@@ -241,6 +349,36 @@ mod tests {
         assert!(!silence_logs(&cli));
     }
 
+    #[test]
+    fn silence_logs_returns_true_for_get_config_without_out_file() {
+        let args = [
+            create_base_args(),
+            vec![
+                "get".to_string(),
+                "config".to_string(),
+                "--format".to_string(),
+                "table".to_string(),
+            ],
+        ]
+        .concat();
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        assert!(silence_logs(&cli));
+    }
+
+    #[test]
+    fn silence_logs_returns_true_only_for_init_with_print() {
+        let print = [
+            create_base_args(),
+            vec!["init".to_string(), "--print".to_string()],
+        ]
+        .concat();
+        let write = [create_base_args(), vec!["init".to_string()]].concat();
+
+        assert!(silence_logs(&Cli::try_parse_from(print).unwrap()));
+        assert!(!silence_logs(&Cli::try_parse_from(write).unwrap()));
+    }
+
     #[test]
     fn silence_logs_returns_true_for_get_current_release_without_out_file() {
         let args = [
@@ -357,4 +495,24 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn get_strict_value_is_only_set_by_release_pr_flag() {
+        let strict = Cli::try_parse_from(
+            [
+                create_base_args(),
+                vec!["release-pr".to_string(), "--strict".to_string()],
+            ]
+            .concat(),
+        )
+        .unwrap();
+
+        let lenient = Cli::try_parse_from(
+            [create_base_args(), vec!["release-pr".to_string()]].concat(),
+        )
+        .unwrap();
+
+        assert!(get_strict_value(&strict));
+        assert!(!get_strict_value(&lenient));
+    }
 }
@@ -3,14 +3,18 @@
 //! Parses conventional commits, determines semantic version bumps,
 //! and generates formatted changelogs using Tera templates.
 
+use indexmap::IndexMap;
+use semver::Version;
+
 use crate::{
     analyzer::{
+        commit::Commit,
         config::AnalyzerConfig,
         group::GroupParser,
-        release::Release,
+        release::{Release, ReleaseAnchor},
         version_strategy::{context::Context, factory::VersionStrategyFactory},
     },
-    forge::request::{ForgeCommit, Tag},
+    forge::request::{ForgeCommit, ForgeCommitAuthor, Tag},
     result::Result,
 };
 
@@ -18,9 +22,29 @@ pub mod commit;
 pub mod config;
 pub mod group;
 mod helpers;
+pub mod mailmap;
 pub mod release;
+mod trailers;
 mod version_strategy;
 
+/// Inputs to [`Analyzer::analyze_with`] that come from the package's
+/// relationships with other packages rather than from its own commits.
+#[derive(Debug, Default, Clone)]
+pub struct AnalyzeOptions {
+    /// Generated commits for released dependencies, each added as a
+    /// dependency bump entry
+    pub dependency_bumps: Vec<ForgeCommit>,
+    /// Version to release at instead of the computed one, e.g. a version
+    /// group's shared version. Releases even without commits
+    pub version: Option<Version>,
+    /// Stands in for the release sha and timestamp when the package has no
+    /// commits of its own
+    pub anchor: Option<ReleaseAnchor>,
+    /// Authors of every commit up to the current tag, which sets apart the
+    /// release's new contributors. `None` when that history wasn't fetched
+    pub previous_authors: Option<Vec<ForgeCommitAuthor>>,
+}
+
 /// Analyzes commits using conventional commit patterns to determine version
 /// bumps and generate changelogs.
 pub struct Analyzer<'a> {
@@ -47,12 +71,47 @@ impl<'a> Analyzer<'a> {
         &self,
         commits: Vec<ForgeCommit>,
         current_tag: Option<Tag>,
+    ) -> Result<Option<Release>> {
+        self.analyze_with(commits, current_tag, AnalyzeOptions::default())
+    }
+
+    /// Same as [`Self::analyze`], plus the inputs in `options`. Dependency
+    /// bumps count towards the version like any other `deps:` commit, so a
+    /// package with nothing else to release still gets a patch bump.
+    pub fn analyze_with(
+        &self,
+        commits: Vec<ForgeCommit>,
+        current_tag: Option<Tag>,
+        options: AnalyzeOptions,
     ) -> Result<Option<Release>> {
         let mut release = self.process_commits(commits)?;
 
+        self.add_dependency_bumps(&mut release, &options.dependency_bumps);
+
+        release.contributors = helpers::contributors(&release.commits);
+
+        if let Some(previous) = options.previous_authors.as_ref() {
+            release.new_contributors = helpers::new_contributors(
+                &release.contributors,
+                previous,
+                &self.config.mailmap,
+            );
+        }
+
+        if release.sha.is_empty()
+            && let Some(anchor) = options.anchor
+        {
+            release.sha = anchor.sha;
+            release.short_sha = anchor.short_sha;
+            release.timestamp = anchor.timestamp;
+        }
+
         // calculate next release
-        let releasable =
-            self.process_release(&mut release, current_tag.as_ref())?;
+        let releasable = self.process_release(
+            &mut release,
+            current_tag.as_ref(),
+            options.version,
+        )?;
 
         if !releasable {
             return Ok(None);
@@ -65,30 +124,25 @@ impl<'a> Analyzer<'a> {
         &self,
         release: &mut Release,
         current_tag: Option<&Tag>,
+        version: Option<Version>,
     ) -> Result<bool> {
-        if release.commits.is_empty() {
+        if release.commits.is_empty() && version.is_none() {
             return Ok(false);
         }
 
-        // Create version strategy based on configuration
-        let strategy = VersionStrategyFactory::create(self.config)?;
+        let forced = version.is_some();
 
-        let commits: Vec<String> = release
-            .commits
-            .iter()
-            .map(|c| c.raw_message.clone())
-            .collect();
-
-        let context = Context {
-            current_tag,
-            commits: &commits,
-            config: self.config,
-            short_sha: &release.short_sha,
-            timestamp: release.timestamp,
+        let next = match version {
+            Some(version) => version,
+            None => match Self::release_as(release, current_tag) {
+                Some(version) => {
+                    release.release_as = Some(version.clone());
+                    version
+                }
+                None => self.next_version(release, current_tag)?,
+            },
         };
 
-        let next = strategy.calculate_next_version(&context)?;
-
         // Date-based versions are derived from the clock rather than from the
         // current tag, so they carry no monotonicity guarantee: a same-day
         // re-run recomputes the version already released, and a skewed clock
@@ -96,7 +150,8 @@ impl<'a> Analyzer<'a> {
         // Semantic types always advance, so equality is the only stall worth
         // catching there.
         if let Some(current) = current_tag {
-            let stalled = if self.config.version_type.is_date_based() {
+            let stalled = if forced || self.config.version_type.is_date_based()
+            {
                 next <= current.semver
             } else {
                 next == current.semver
@@ -145,13 +200,92 @@ impl<'a> Analyzer<'a> {
 
         release.tag = next_tag;
 
-        let context = tera::Context::from_serialize(&release)?;
-        let notes = tera::Tera::one_off(&self.config.body, &context, false)?;
-        release.notes = helpers::strip_extra_lines(notes.trim());
+        release.notes = release.render(&self.config.body)?;
 
         Ok(true)
     }
 
+    /// The version a `Release-As` footer in the release's commits asks
+    /// for. A version that doesn't advance past the current tag is ignored
+    /// so releases can't go backwards.
+    fn release_as(
+        release: &Release,
+        current_tag: Option<&Tag>,
+    ) -> Option<Version> {
+        let (version, commit) = helpers::release_as(&release.commits)?;
+
+        if let Some(current) = current_tag
+            && version <= current.semver
+        {
+            log::warn!(
+                "ignoring Release-As {version} in {}: does not advance past \
+                 current tag {}",
+                commit.short_id,
+                current.semver
+            );
+            return None;
+        }
+
+        log::info!("releasing as {version} per {}", commit.short_id);
+
+        Some(version)
+    }
+
+    fn next_version(
+        &self,
+        release: &Release,
+        current_tag: Option<&Tag>,
+    ) -> Result<Version> {
+        // Create version strategy based on configuration
+        let strategy = VersionStrategyFactory::create(self.config)?;
+
+        let commits: Vec<String> = release
+            .commits
+            .iter()
+            .map(|c| c.raw_message.clone())
+            .collect();
+
+        let context = Context {
+            current_tag,
+            commits: &commits,
+            config: self.config,
+            short_sha: &release.short_sha,
+            timestamp: release.timestamp,
+        };
+
+        strategy.calculate_next_version(&context)
+    }
+
+    /// Adds generated dependency bump entries to the release, filed under
+    /// the group [`GroupParser::parse_dependency`] picks.
+    fn add_dependency_bumps(
+        &self,
+        release: &mut Release,
+        dependency_bumps: &[ForgeCommit],
+    ) {
+        // parse without named parsers so no group or skip is applied
+        let named_parsers = IndexMap::new();
+        let ungrouped = GroupParser::new(&named_parsers, &[]);
+
+        for bump in dependency_bumps {
+            let Some(mut commit) =
+                Commit::parse_forge_commit(&ungrouped, bump, self.config)
+            else {
+                continue;
+            };
+
+            let parsed = self.group_parser.parse_dependency(&commit);
+
+            if parsed.skip {
+                log::debug!("omitting dependency bump: {}", commit.raw_title);
+                continue;
+            }
+
+            commit.group = parsed.group;
+            release.commits.push(commit);
+        }
+    }
+
     /// Parse commits into structured format with conventional commit
     /// categorization and grouping.
     fn process_commits(
@@ -163,6 +297,7 @@ impl<'a> Analyzer<'a> {
         let mut release = release::Release {
             include_author: self.config.include_author,
             include_pr_link: self.config.include_pr_link,
+            include_contributors: self.config.include_contributors,
             ..Default::default()
         };
 
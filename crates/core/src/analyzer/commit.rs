@@ -1,10 +1,15 @@
 use derive_builder::Builder;
 use git_conventional::Commit as ConventionalCommit;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 
 use crate::{
-    analyzer::{config::AnalyzerConfig, group::GroupParser},
+    analyzer::{
+        config::AnalyzerConfig,
+        group::GroupParser,
+        trailers::{Trailers, parse_identity},
+    },
     forge::request::{ForgeCommit, ForgeCommitPR},
 };
 
@@ -24,6 +29,21 @@ impl From<ForgeCommitPR> for CommitPR {
     }
 }
 
+/// A person credited on a commit besides its author
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitAuthor {
+    pub name: String,
+    pub email: String,
+}
+
+/// An issue or ticket reference found in a commit message
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitReference {
+    /// Referenced text as it appears in the message, e.g. `#123`
+    pub id: String,
+    pub link: String,
+}
+
 /// Structured commit with parsed conventional commit fields, author
 /// metadata, and changelog categorization.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, Builder)]
@@ -43,8 +63,21 @@ pub struct Commit {
     pub timestamp: i64,
     pub author_name: String,
     pub author_email: String,
+    /// Forge account of the author, on forges that link commits to one
+    pub author_username: Option<String>,
     pub raw_title: String,
     pub raw_message: String,
+    /// Issue or ticket references in the message, in order of first
+    /// appearance
+    #[serde(default)]
+    pub references: Vec<CommitReference>,
+    /// Git trailers closing the message, e.g. `Signed-off-by`, keyed by
+    /// lowercased token with every value in order of appearance
+    #[serde(default)]
+    pub trailers: IndexMap<String, Vec<String>>,
+    /// Authors credited with `Co-authored-by` trailers
+    #[serde(default)]
+    pub co_authors: Vec<CommitAuthor>,
 }
 
 impl Commit {
@@ -55,8 +88,10 @@ impl Commit {
         forge_commit: &ForgeCommit,
         config: &AnalyzerConfig,
     ) -> Option<Self> {
-        let author_name = forge_commit.author_name.clone();
-        let author_email = forge_commit.author_email.clone();
+        let (author_name, author_email) = config
+            .mailmap
+            .resolve(&forge_commit.author_name, &forge_commit.author_email);
+        let author_username = forge_commit.author_username.clone();
         let commit_id = forge_commit.id.clone();
         let short_id = forge_commit.short_id.clone();
         let merge_commit = forge_commit.merge_commit;
@@ -102,6 +137,33 @@ impl Commit {
                 Err(_) => (None, raw_title.clone(), raw_body, false, None),
             };
 
+        let trailers = Trailers::parse(&raw_message).unwrap_or_default();
+
+        // conventional commit footers are already split from the body, but
+        // the raw fallback keeps them, so strip either way and put them back
+        // when trailers are kept
+        let body = body.and_then(|b| trailers.strip_from(&b));
+        let body = if config.strip_trailers || trailers.block.is_empty() {
+            body
+        } else {
+            Some(match body {
+                Some(body) => format!("{body}\n\n{}", trailers.block),
+                None => trailers.block.clone(),
+            })
+        };
+
+        let co_authors = trailers
+            .values
+            .get("co-authored-by")
+            .into_iter()
+            .flatten()
+            .map(|value| {
+                let (name, email) = parse_identity(value);
+                let (name, email) = config.mailmap.resolve(&name, &email);
+                CommitAuthor { name, email }
+            })
+            .collect();
+
         if !breaking
             && let Some(custom) = config.custom_major_increment_regex.as_ref()
             && custom.is_match(&raw_message)
@@ -109,6 +171,8 @@ impl Commit {
             breaking = true;
         }
 
+        let references = extract_references(&raw_message, pr.as_ref(), config);
+
         let mut commit = Self {
             id: commit_id,
             short_id,
@@ -126,6 +190,10 @@ impl Commit {
             timestamp,
             author_name,
             author_email,
+            author_username,
+            references,
+            trailers: trailers.values,
+            co_authors,
         };
 
         if let Some(parsed) = group_parser.parse(&commit) {
@@ -154,6 +222,45 @@ impl Commit {
     }
 }
 
+/// Collects the references each configured pattern matches in `message`,
+/// skipping repeats of one already found. A `#12` naming the commit's own
+/// PR, like the `(#12)` a squash merge appends, is skipped too since the
+/// PR link already covers it.
+fn extract_references(
+    message: &str,
+    pr: Option<&CommitPR>,
+    config: &AnalyzerConfig,
+) -> Vec<CommitReference> {
+    let mut matches = config
+        .references
+        .iter()
+        .flat_map(|matcher| {
+            matcher.pattern.captures_iter(message).map(|captures| {
+                let mut link = String::new();
+                captures.expand(&matcher.url, &mut link);
+
+                let whole = captures.get_match();
+                (whole.start(), whole.as_str().to_string(), link)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    matches.sort_by_key(|(start, _, _)| *start);
+
+    let mut references: Vec<CommitReference> = vec![];
+
+    for (_, id, link) in matches {
+        // whole matches are compared, so the PR's `#123` doesn't hide `#12`
+        let names_pr = pr.is_some_and(|pr| id == format!("#{}", pr.id));
+
+        if !names_pr && !references.iter().any(|r| r.id == id) {
+            references.push(CommitReference { id, link });
+        }
+    }
+
+    references
+}
+
 /// Helper function for trimming string: only allocates when trim actually
 /// modifies the string.
 ///
@@ -176,7 +283,10 @@ fn trim_to_cow(s: &str) -> Cow<'_, str> {
 
 #[cfg(test)]
 mod tests {
+    use regex::Regex;
+
     use crate::{
+        analyzer::config::ReferenceMatcher,
         config::versioning::{Group, NAMED_PARSERS},
         forge::request::ForgeCommitBuilder,
     };
@@ -1537,4 +1647,237 @@ mod tests {
         assert!(matches!(result, Cow::Owned(_)));
         assert_eq!(result, "feat: add feature");
     }
+
+    #[test]
+    fn test_parse_extracts_references_in_message_order() {
+        let analyzer_config = AnalyzerConfig {
+            references: vec![
+                ReferenceMatcher {
+                    pattern: Regex::new(r"#(\d+)\b").unwrap(),
+                    url: "https://example.com/issues/${1}".into(),
+                },
+                ReferenceMatcher {
+                    pattern: Regex::new(r"\b(?<project>[A-Z]+)-(?<num>\d+)\b")
+                        .unwrap(),
+                    url: "https://jira.example.com/${project}/${num}".into(),
+                },
+            ],
+            ..AnalyzerConfig::default()
+        };
+        let group_parser = GroupParser::new(&NAMED_PARSERS, &[]);
+        let forge_commit = ForgeCommitBuilder::default()
+            .id("abc123")
+            .message("fix: crash\n\nFixes PLAT-991, closes #12 and #4 and #12")
+            .build()
+            .unwrap();
+
+        let commit = Commit::parse_forge_commit(
+            &group_parser,
+            &forge_commit,
+            &analyzer_config,
+        )
+        .unwrap();
+
+        assert_eq!(
+            commit.references,
+            vec![
+                CommitReference {
+                    id: "PLAT-991".into(),
+                    link: "https://jira.example.com/PLAT/991".into(),
+                },
+                CommitReference {
+                    id: "#12".into(),
+                    link: "https://example.com/issues/12".into(),
+                },
+                CommitReference {
+                    id: "#4".into(),
+                    link: "https://example.com/issues/4".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_keeps_title_references_but_skips_the_pr() {
+        let analyzer_config = AnalyzerConfig {
+            references: vec![ReferenceMatcher {
+                pattern: Regex::new(r"#(\d+)\b").unwrap(),
+                url: "https://example.com/issues/${1}".into(),
+            }],
+            ..AnalyzerConfig::default()
+        };
+        let group_parser = GroupParser::new(&NAMED_PARSERS, &[]);
+        let forge_commit = ForgeCommitBuilder::default()
+            .id("abc123")
+            .message("fix: crash on #7 (#12)\n\nFollow-up to #12, closes #4")
+            .pr(ForgeCommitPR {
+                id: "12".into(),
+                link: "https://example.com/pulls/12".into(),
+            })
+            .build()
+            .unwrap();
+
+        let commit = Commit::parse_forge_commit(
+            &group_parser,
+            &forge_commit,
+            &analyzer_config,
+        )
+        .unwrap();
+
+        assert_eq!(
+            commit.references,
+            vec![
+                CommitReference {
+                    id: "#7".into(),
+                    link: "https://example.com/issues/7".into(),
+                },
+                CommitReference {
+                    id: "#4".into(),
+                    link: "https://example.com/issues/4".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_skips_only_the_whole_pr_reference() {
+        let analyzer_config = AnalyzerConfig {
+            references: vec![ReferenceMatcher {
+                pattern: Regex::new(r"#(\d+)\b").unwrap(),
+                url: "https://example.com/issues/${1}".into(),
+            }],
+            ..AnalyzerConfig::default()
+        };
+        let group_parser = GroupParser::new(&NAMED_PARSERS, &[]);
+        let forge_commit = ForgeCommitBuilder::default()
+            .id("abc123")
+            .message("fix: crash (#123)\n\nRefs #12")
+            .pr(ForgeCommitPR {
+                id: "123".into(),
+                link: "https://example.com/pulls/123".into(),
+            })
+            .build()
+            .unwrap();
+
+        let commit = Commit::parse_forge_commit(
+            &group_parser,
+            &forge_commit,
+            &analyzer_config,
+        )
+        .unwrap();
+
+        assert_eq!(
+            commit.references,
+            vec![CommitReference {
+                id: "#12".into(),
+                link: "https://example.com/issues/12".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_without_reference_patterns_has_no_references() {
+        let analyzer_config = AnalyzerConfig::default();
+        let group_parser = GroupParser::new(&NAMED_PARSERS, &[]);
+        let forge_commit = ForgeCommitBuilder::default()
+            .id("abc123")
+            .message("fix: crash (#12)")
+            .build()
+            .unwrap();
+
+        let commit = Commit::parse_forge_commit(
+            &group_parser,
+            &forge_commit,
+            &analyzer_config,
+        )
+        .unwrap();
+
+        assert!(commit.references.is_empty());
+    }
+
+    #[test]
+    fn test_parse_collects_trailers_and_strips_them_from_body() {
+        let analyzer_config = AnalyzerConfig::default();
+        let group_parser = GroupParser::new(&NAMED_PARSERS, &[]);
+
+        for message in [
+            "fix: crash on start\n\nGuard the config read.\n\nCo-authored-by: Jane Doe <jane@example.com>\nSigned-off-by: John <john@example.com>",
+            // the raw fallback for non-conventional messages as well
+            "Crash on start\n\nGuard the config read.\n\nCo-authored-by: Jane Doe <jane@example.com>\nSigned-off-by: John <john@example.com>",
+        ] {
+            let forge_commit = ForgeCommitBuilder::default()
+                .id("abc123")
+                .message(message)
+                .build()
+                .unwrap();
+
+            let commit = Commit::parse_forge_commit(
+                &group_parser,
+                &forge_commit,
+                &analyzer_config,
+            )
+            .unwrap();
+
+            assert_eq!(commit.body.as_deref(), Some("Guard the config read."));
+            assert_eq!(
+                commit.trailers["signed-off-by"],
+                vec!["John <john@example.com>"]
+            );
+            assert_eq!(
+                commit.co_authors,
+                vec![CommitAuthor {
+                    name: "Jane Doe".into(),
+                    email: "jane@example.com".into(),
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_keeps_trailers_in_body_when_not_stripping() {
+        let analyzer_config = AnalyzerConfig {
+            strip_trailers: false,
+            ..AnalyzerConfig::default()
+        };
+        let group_parser = GroupParser::new(&NAMED_PARSERS, &[]);
+        let forge_commit = ForgeCommitBuilder::default()
+            .id("abc123")
+            .message("fix: crash\n\nGuard the read.\n\nRefs: #12")
+            .build()
+            .unwrap();
+
+        let commit = Commit::parse_forge_commit(
+            &group_parser,
+            &forge_commit,
+            &analyzer_config,
+        )
+        .unwrap();
+
+        assert_eq!(
+            commit.body.as_deref(),
+            Some("Guard the read.\n\nRefs: #12")
+        );
+        assert_eq!(commit.trailers["refs"], vec!["#12"]);
+    }
+
+    #[test]
+    fn test_parse_body_of_only_trailers_is_none() {
+        let analyzer_config = AnalyzerConfig::default();
+        let group_parser = GroupParser::new(&NAMED_PARSERS, &[]);
+        let forge_commit = ForgeCommitBuilder::default()
+            .id("abc123")
+            .message("Fix crash\n\nSigned-off-by: John <john@example.com>")
+            .build()
+            .unwrap();
+
+        let commit = Commit::parse_forge_commit(
+            &group_parser,
+            &forge_commit,
+            &analyzer_config,
+        )
+        .unwrap();
+
+        assert!(commit.body.is_none());
+        assert!(commit.co_authors.is_empty());
+    }
 }
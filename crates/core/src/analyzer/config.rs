@@ -5,8 +5,12 @@ use indexmap::IndexMap;
 use regex::Regex;
 use url::Url;
 
+use crate::analyzer::mailmap::Mailmap;
 use crate::config::{
-    changelog::{DEFAULT_INCLUDE_AUTHOR, DEFAULT_INCLUDE_PR_LINK},
+    changelog::{
+        DEFAULT_INCLUDE_AUTHOR, DEFAULT_INCLUDE_CONTRIBUTORS,
+        DEFAULT_INCLUDE_PR_LINK, DEFAULT_STRIP_TRAILERS,
+    },
     overrides::CommitModifiers,
     prerelease::PrereleaseConfig,
     versioning::{
@@ -15,6 +19,14 @@ use crate::config::{
     },
 };
 
+/// A compiled `references` entry: text matching `pattern` in a commit
+/// message links to `url` with the match's capture groups substituted.
+#[derive(Debug, Clone)]
+pub struct ReferenceMatcher {
+    pub pattern: Regex,
+    pub url: String,
+}
+
 /// Configuration for commit analysis and changelog generation.
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(into, strip_option), default)]
@@ -27,6 +39,11 @@ pub struct AnalyzerConfig {
     pub include_author: bool,
     /// Includes commit PRs
     pub include_pr_link: bool,
+    /// Includes the contributors section in default body template and
+    /// detects first-time contributors (default: false)
+    pub include_contributors: bool,
+    /// Removes git trailers from commit bodies (default: true)
+    pub strip_trailers: bool,
     /// Optional prefix for package tags.
     pub tag_prefix: Option<String>,
     /// Base URL for release links in changelog.
@@ -69,6 +86,12 @@ pub struct AnalyzerConfig {
     /// [`Parser::group_title`][crate::config::versioning::Parser::group_title]
     /// and config resolution rejects a title that carries one by hand.
     pub custom_parsers: Vec<Parser>,
+    /// Issue or ticket references extracted from commit messages into each
+    /// commit's `references`
+    pub references: Vec<ReferenceMatcher>,
+    /// The repository's `.mailmap`, applied to commit authors and
+    /// co-authors before grouping and crediting
+    pub mailmap: Mailmap,
 }
 
 impl Default for AnalyzerConfig {
@@ -78,6 +101,8 @@ impl Default for AnalyzerConfig {
             skip_merge_commits: DEFAULT_SKIP_MERGE_COMMITS,
             include_author: DEFAULT_INCLUDE_AUTHOR,
             include_pr_link: DEFAULT_INCLUDE_PR_LINK,
+            include_contributors: DEFAULT_INCLUDE_CONTRIBUTORS,
+            strip_trailers: DEFAULT_STRIP_TRAILERS,
             tag_prefix: None,
             release_link_base_url: None,
             compare_link_base_url: None,
@@ -90,6 +115,8 @@ impl Default for AnalyzerConfig {
             commit_modifiers: CommitModifiers::default(),
             named_parsers: NAMED_PARSERS.clone(),
             custom_parsers: Vec::new(),
+            references: Vec::new(),
+            mailmap: Mailmap::default(),
         }
     }
 }
@@ -2,7 +2,7 @@ use indexmap::IndexMap;
 
 use crate::{
     analyzer::commit::Commit,
-    config::versioning::{Group, Parser},
+    config::versioning::{DEPENDENCIES_PARSER, Group, Parser},
 };
 
 /// The changelog group a commit was assigned to, plus whether the
@@ -52,12 +52,11 @@ impl<'a> GroupParser<'a> {
     /// produces.
     pub fn parse(&self, commit: &Commit) -> Option<Parsed> {
         let msg = commit.raw_message.trim();
+        let author = author_of(commit);
 
         // custom parsers always take precedence
-        for parser in self.custom_parsers.iter() {
-            if parser.is_match(msg) {
-                return Some(parser.into());
-            }
+        if let Some(parsed) = self.parse_custom(msg, &author) {
+            return Some(parsed);
         }
 
         // Handle breaking first as this one doesn't always have a pattern
@@ -80,7 +79,7 @@ impl<'a> GroupParser<'a> {
                 // miscellaneous is handled below
                 continue;
             }
-            if parser.is_match(msg) {
+            if parser.is_match(msg, &author) {
                 return Some(parser.into());
             }
         }
@@ -95,6 +94,26 @@ impl<'a> GroupParser<'a> {
             .get(&Group::Miscellaneous)
             .map(Parsed::from)
     }
+
+    /// Group for a generated dependency bump entry: a matching custom parser
+    /// if there is one, otherwise [`DEPENDENCIES_PARSER`]. Named parsers
+    /// aren't consulted since they'd file every bump under the catch-all.
+    pub fn parse_dependency(&self, commit: &Commit) -> Parsed {
+        self.parse_custom(commit.raw_message.trim(), &author_of(commit))
+            .unwrap_or_else(|| (&*DEPENDENCIES_PARSER).into())
+    }
+
+    fn parse_custom(&self, msg: &str, author: &str) -> Option<Parsed> {
+        self.custom_parsers
+            .iter()
+            .find(|parser| parser.is_match(msg, author))
+            .map(Parsed::from)
+    }
+}
+
+/// The `Name <email>` string [`Parser::author`] patterns are matched against.
+fn author_of(commit: &Commit) -> String {
+    format!("{} <{}>", commit.author_name, commit.author_email)
 }
 
 #[cfg(test)]
@@ -128,6 +147,10 @@ mod tests {
             raw_message: raw_message.into(),
             author_name: "".into(),
             author_email: "".into(),
+            author_username: None,
+            references: vec![],
+            trailers: IndexMap::new(),
+            co_authors: vec![],
         }
     }
 
@@ -436,6 +459,71 @@ mod tests {
         assert_eq!(parsed.group, custom[0].group_title());
     }
 
+    #[test]
+    fn test_group_parser_custom_parser_matches_author() {
+        let custom = [
+            Parser {
+                author: Some(Regex::new(r"^dependabot\[bot\] <").unwrap()),
+                ..Parser::new(None, "📦 Dependencies".into(), false, 2)
+            },
+            Parser {
+                author: Some(Regex::new(r"<ci@example\.com>$").unwrap()),
+                ..Parser::new(None, "Bots".into(), true, 3)
+            },
+        ];
+        let parser = GroupParser::new(&NAMED_PARSERS, &custom);
+
+        let mut bump = create_test_commit("chore: bump serde", false);
+        bump.author_name = "dependabot[bot]".into();
+        bump.author_email = "bot@example.com".into();
+        let parsed = parser.parse(&bump).unwrap();
+        assert_eq!(parsed.group, custom[0].group_title());
+        assert!(!parsed.skip);
+
+        let mut ci = create_test_commit("feat: publish docs", false);
+        ci.author_name = "CI".into();
+        ci.author_email = "ci@example.com".into();
+        assert!(parser.parse(&ci).unwrap().skip);
+
+        // anyone else falls through to the named parsers
+        let mut human = create_test_commit("chore: bump serde", false);
+        human.author_name = "Jane".into();
+        human.author_email = "jane@example.com".into();
+        let parsed = parser.parse(&human).unwrap();
+        assert_eq!(parsed.group, NAMED_PARSERS[&Group::Chore].group_title());
+    }
+
+    #[test]
+    fn test_group_parser_custom_parser_with_pattern_and_author_needs_both() {
+        let custom = [Parser {
+            author: Some(Regex::new(r"\[bot\]").unwrap()),
+            ..Parser::new(
+                Some(Regex::new(r"^chore\(deps\)").unwrap()),
+                "📦 Dependencies".into(),
+                false,
+                2,
+            )
+        }];
+        let parser = GroupParser::new(&NAMED_PARSERS, &custom);
+
+        let mut bot = create_test_commit("chore(deps): bump serde", false);
+        bot.author_name = "renovate[bot]".into();
+        assert_eq!(parser.parse(&bot).unwrap().group, custom[0].group_title());
+
+        let mut other = create_test_commit("chore: tidy", false);
+        other.author_name = "renovate[bot]".into();
+        assert_eq!(
+            parser.parse(&other).unwrap().group,
+            NAMED_PARSERS[&Group::Chore].group_title()
+        );
+
+        let human = create_test_commit("chore(deps): bump serde", false);
+        assert_eq!(
+            parser.parse(&human).unwrap().group,
+            NAMED_PARSERS[&Group::Chore].group_title()
+        );
+    }
+
     /// `Group::Breaking` is the one group this parser does not select by
     /// `pattern` - it reads [`Commit::breaking`] instead. A user pattern
     /// reaches that flag earlier, in
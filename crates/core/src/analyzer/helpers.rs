@@ -4,11 +4,14 @@ use std::{borrow::Cow, sync::LazyLock};
 
 use crate::{
     analyzer::{
-        commit::Commit, config::AnalyzerConfig, group::GroupParser,
-        release::Release,
+        commit::Commit,
+        config::AnalyzerConfig,
+        group::GroupParser,
+        mailmap::Mailmap,
+        release::{Contributor, Release},
     },
     config::prerelease::PrereleaseStrategy,
-    forge::request::ForgeCommit,
+    forge::request::{ForgeCommit, ForgeCommitAuthor},
     result::Result,
 };
 
@@ -16,6 +19,10 @@ use crate::{
 static EXTRA_NEW_LINES_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\n{3,}").unwrap());
 
+/// Matches a `Release-As: x.y.z` commit footer
+static RELEASE_AS_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?mi)^release-as:\s*v?(\S+)\s*$").unwrap());
+
 /// Parse a forge commit and, if it passes the configured filters, add it
 /// to the release's commit list.
 pub fn update_release_with_commit(
@@ -39,6 +46,98 @@ pub fn update_release_with_commit(
     }
 }
 
+/// The version requested by the newest `Release-As` footer among `commits`,
+/// which are ordered newest-first, along with the commit carrying it.
+/// Footers that aren't valid versions are skipped.
+pub fn release_as(commits: &[Commit]) -> Option<(Version, &Commit)> {
+    commits.iter().find_map(|commit| {
+        let value = RELEASE_AS_REGEX
+            .captures_iter(&commit.raw_message)
+            .last()?
+            .get(1)?
+            .as_str();
+
+        match Version::parse(value) {
+            Ok(version) => Some((version, commit)),
+            Err(e) => {
+                log::warn!(
+                    "ignoring invalid Release-As footer {value} in {}: {e}",
+                    commit.short_id
+                );
+                None
+            }
+        }
+    })
+}
+
+/// Everyone credited on `commits`, authors and co-authors alike, once each
+/// and sorted by name. Commits with no author, such as generated dependency
+/// bumps, credit no one.
+pub fn contributors(commits: &[Commit]) -> Vec<Contributor> {
+    let mut contributors: Vec<Contributor> = vec![];
+
+    let credited = commits.iter().flat_map(|commit| {
+        std::iter::once(Contributor {
+            name: commit.author_name.clone(),
+            email: commit.author_email.clone(),
+            username: commit.author_username.clone(),
+        })
+        .chain(commit.co_authors.iter().map(|co_author| {
+            Contributor {
+                name: co_author.name.clone(),
+                email: co_author.email.clone(),
+                username: None,
+            }
+        }))
+    });
+
+    for contributor in credited {
+        if contributor.name.is_empty() && contributor.email.is_empty() {
+            continue;
+        }
+
+        match contributors.iter_mut().find(|c| c.is(&contributor)) {
+            // keep the first identity seen, filling in a username another
+            // commit of theirs links to
+            Some(existing) => {
+                if existing.username.is_none() {
+                    existing.username = contributor.username;
+                }
+            }
+            None => contributors.push(contributor),
+        }
+    }
+
+    contributors.sort_by_key(|c| c.name.to_lowercase());
+    contributors
+}
+
+/// The `contributors` not among the `previous` authors, i.e. those
+/// contributing for the first time. Previous authors go through `mailmap`
+/// first so they compare by the same canonical identity as `contributors`.
+pub fn new_contributors(
+    contributors: &[Contributor],
+    previous: &[ForgeCommitAuthor],
+    mailmap: &Mailmap,
+) -> Vec<Contributor> {
+    let previous = previous
+        .iter()
+        .cloned()
+        .map(Contributor::from)
+        .map(|mut author| {
+            (author.name, author.email) =
+                mailmap.resolve(&author.name, &author.email);
+            author
+        })
+        .collect::<Vec<_>>();
+
+    contributors
+        .iter()
+        .filter(|c| !previous.iter().any(|p| p.is(c)))
+        .cloned()
+        .collect()
+}
+
 /// Normalize changelog formatting by replacing consecutive blank lines (3+)
 /// with double newlines and trimming whitespace.
 pub fn strip_extra_lines(changelog: &str) -> String {
@@ -0,0 +1,207 @@
+//! Git `.mailmap` parsing, mapping the names and emails commits were made
+//! under to each author's canonical identity.
+
+use crate::{forge::traits::FileLoader, result::Result};
+
+/// Repository path the mailmap is loaded from.
+pub const MAILMAP_FILE: &str = ".mailmap";
+
+/// One `.mailmap` line.
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    proper_name: Option<String>,
+    proper_email: Option<String>,
+    commit_name: Option<String>,
+    commit_email: String,
+}
+
+/// Parsed `.mailmap` entries. An empty mailmap leaves every identity as is.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Mailmap {
+    entries: Vec<Entry>,
+}
+
+impl Mailmap {
+    /// Parses the forms git accepts:
+    ///
+    /// ```text
+    /// Proper Name <commit@email>
+    /// <proper@email> <commit@email>
+    /// Proper Name <proper@email> <commit@email>
+    /// Proper Name <proper@email> Commit Name <commit@email>
+    /// ```
+    ///
+    /// Blank lines, `#` comments and lines without an email are skipped, as
+    /// git skips them.
+    pub fn parse(content: &str) -> Self {
+        let entries = content
+            .lines()
+            .filter_map(|line| {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    return None;
+                }
+
+                let entry = parse_entry(line);
+                if entry.is_none() {
+                    log::debug!("ignoring malformed .mailmap line: {line}");
+                }
+                entry
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Loads and parses [`MAILMAP_FILE`] from `branch`, returning an empty
+    /// mailmap when the repository doesn't have one.
+    pub async fn load<F: FileLoader + ?Sized>(
+        loader: &F,
+        branch: Option<String>,
+    ) -> Result<Self> {
+        let content = loader.load_file(branch, MAILMAP_FILE.into()).await?;
+
+        Ok(content.as_deref().map(Self::parse).unwrap_or_default())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The canonical `(name, email)` for a commit identity. Emails and names
+    /// are compared case-insensitively, an entry naming the commit name wins
+    /// over one matching the email alone, and later lines win over earlier
+    /// ones.
+    pub fn resolve(&self, name: &str, email: &str) -> (String, String) {
+        let matching = |by_name: bool| {
+            self.entries.iter().rev().find(|entry| {
+                entry.commit_email.eq_ignore_ascii_case(email)
+                    && match entry.commit_name.as_deref() {
+                        Some(commit_name) => {
+                            by_name && commit_name.eq_ignore_ascii_case(name)
+                        }
+                        None => !by_name,
+                    }
+            })
+        };
+
+        match matching(true).or_else(|| matching(false)) {
+            Some(entry) => (
+                entry.proper_name.as_deref().unwrap_or(name).to_string(),
+                entry.proper_email.as_deref().unwrap_or(email).to_string(),
+            ),
+            None => (name.to_string(), email.to_string()),
+        }
+    }
+}
+
+/// Splits a line into its leading name, `<email>`, optional second name and
+/// optional second `<email>`.
+fn parse_entry(line: &str) -> Option<Entry> {
+    let (first_name, rest) = line.split_once('<')?;
+    let (first_email, rest) = rest.split_once('>')?;
+
+    let second = rest.split_once('<').and_then(|(name, rest)| {
+        rest.split_once('>').map(|(email, _)| (name, email))
+    });
+
+    let non_empty = |value: &str| {
+        let value = value.trim();
+        (!value.is_empty()).then(|| value.to_string())
+    };
+
+    let proper_name = non_empty(first_name);
+
+    Some(match second {
+        Some((commit_name, commit_email)) => Entry {
+            proper_name,
+            proper_email: non_empty(first_email),
+            commit_name: non_empty(commit_name),
+            commit_email: commit_email.trim().to_string(),
+        },
+        None => Entry {
+            proper_name,
+            proper_email: None,
+            commit_name: None,
+            commit_email: first_email.trim().to_string(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolved(name: &str, email: &str) -> (String, String) {
+        (name.to_string(), email.to_string())
+    }
+
+    #[test]
+    fn parse_reads_each_git_form() {
+        let mailmap = Mailmap::parse(
+            "# canonical identities\n\
+             Jane Doe <jane@example.com>\n\
+             <bob@example.com> <bob@old.example.com>\n\
+             Al Smith <al@example.com> <al@laptop.local>\n\
+             Cy Young <cy@example.com> cy <CY@Work.example.com>\n\
+             \n\
+             not a mailmap line\n",
+        );
+
+        assert_eq!(mailmap.entries.len(), 4);
+        assert_eq!(
+            mailmap.resolve("jdoe", "jane@example.com"),
+            resolved("Jane Doe", "jane@example.com")
+        );
+        assert_eq!(
+            mailmap.resolve("Bob", "bob@old.example.com"),
+            resolved("Bob", "bob@example.com")
+        );
+        assert_eq!(
+            mailmap.resolve("al", "al@laptop.local"),
+            resolved("Al Smith", "al@example.com")
+        );
+        assert_eq!(
+            mailmap.resolve("CY", "cy@work.example.com"),
+            resolved("Cy Young", "cy@example.com")
+        );
+    }
+
+    #[test]
+    fn resolve_requires_the_commit_name_when_an_entry_has_one() {
+        let mailmap = Mailmap::parse(
+            "Shared Bot <bot@example.com> ci <shared@example.com>\n",
+        );
+
+        assert_eq!(
+            mailmap.resolve("Someone Else", "shared@example.com"),
+            resolved("Someone Else", "shared@example.com")
+        );
+    }
+
+    #[test]
+    fn resolve_prefers_name_matches_then_later_lines() {
+        let mailmap = Mailmap::parse(
+            "Old Name <team@example.com>\n\
+             New Name <team@example.com>\n\
+             Release Bot <bot@example.com> bot <team@example.com>\n",
+        );
+
+        assert_eq!(
+            mailmap.resolve("bot", "team@example.com"),
+            resolved("Release Bot", "bot@example.com")
+        );
+        assert_eq!(
+            mailmap.resolve("someone", "team@example.com"),
+            resolved("New Name", "team@example.com")
+        );
+    }
+
+    #[test]
+    fn resolve_leaves_unmapped_identities_alone() {
+        assert_eq!(
+            Mailmap::default().resolve("Jane", "jane@example.com"),
+            resolved("Jane", "jane@example.com")
+        );
+    }
+}
@@ -1,7 +1,11 @@
 //! Data types for releases, tags, and commits.
 use serde::{Deserialize, Serialize, ser::SerializeStruct};
 
-use crate::{analyzer::commit::Commit, forge::request::Tag};
+use crate::{
+    analyzer::{commit::Commit, helpers},
+    forge::request::{ForgeCommitAuthor, Tag},
+    result,
+};
 
 /// Represents the serialized structure of a release that we actually use when
 /// passing as a context to the Tera template. Really the only difference
@@ -25,6 +29,50 @@ struct ShadowRelease {
     pub tag_compare_link: Option<String>,
     pub sha_compare_link: Option<String>,
     pub include_pr_link: Option<bool>,
+    pub release_as: Option<String>,
+    pub include_contributors: Option<bool>,
+    #[serde(default)]
+    pub contributors: Vec<Contributor>,
+    #[serde(default)]
+    pub new_contributors: Vec<Contributor>,
+}
+
+/// Someone credited on a release's commits, as author or co-author
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Contributor {
+    pub name: String,
+    pub email: String,
+    /// Forge account, on forges that link commits to one
+    pub username: Option<String>,
+}
+
+impl Contributor {
+    /// Whether `other` is the same person: they share an email or a
+    /// username, or a name when either has no email to compare.
+    pub fn is(&self, other: &Contributor) -> bool {
+        let same =
+            |a: &str, b: &str| !a.is_empty() && a.eq_ignore_ascii_case(b);
+
+        let same_username = match (&self.username, &other.username) {
+            (Some(a), Some(b)) => same(a, b),
+            _ => false,
+        };
+
+        same(&self.email, &other.email)
+            || same_username
+            || ((self.email.is_empty() || other.email.is_empty())
+                && same(&self.name, &other.name))
+    }
+}
+
+impl From<ForgeCommitAuthor> for Contributor {
+    fn from(value: ForgeCommitAuthor) -> Self {
+        Self {
+            name: value.name,
+            email: value.email,
+            username: value.username,
+        }
+    }
 }
 
 /// Complete release package containing version tag, changelog notes, and all
@@ -57,6 +105,52 @@ pub struct Release {
     pub notes: String,
     /// Release timestamp
     pub timestamp: i64,
+    /// Version requested by a `Release-As` commit footer or a `release_as`
+    /// override, which replaces the computed one
+    pub release_as: Option<semver::Version>,
+    /// Whether or not to include the contributors section in changelog
+    pub include_contributors: bool,
+    /// Everyone credited on the release's commits
+    pub contributors: Vec<Contributor>,
+    /// Contributors with no commits in any earlier release
+    pub new_contributors: Vec<Contributor>,
+}
+
+impl Release {
+    /// Renders a Tera template with this release as its context, the way
+    /// the release notes are rendered from the changelog body.
+    pub fn render(&self, template: &str) -> result::Result<String> {
+        Self::render_context(template, &tera::Context::from_serialize(self)?)
+    }
+
+    /// Renders a Tera template with `context`, usually a release's own
+    /// context extended with extra variables.
+    pub fn render_context(
+        template: &str,
+        context: &tera::Context,
+    ) -> result::Result<String> {
+        let rendered = tera::Tera::one_off(template, context, false)?;
+        Ok(helpers::strip_extra_lines(rendered.trim()))
+    }
+}
+
+/// The sha and timestamp of a release made for another package, used when
+/// a package is released without any commits of its own.
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseAnchor {
+    pub sha: String,
+    pub short_sha: String,
+    pub timestamp: i64,
+}
+
+impl From<&Release> for ReleaseAnchor {
+    fn from(release: &Release) -> Self {
+        Self {
+            sha: release.sha.clone(),
+            short_sha: release.short_sha.clone(),
+            timestamp: release.timestamp,
+        }
+    }
 }
 
 impl From<ShadowRelease> for Release {
@@ -72,6 +166,14 @@ impl From<ShadowRelease> for Release {
             sha: value.sha,
             short_sha: value.short_sha.unwrap_or_default(),
             timestamp: value.timestamp,
+            release_as: value
+                .release_as
+                .and_then(|v| semver::Version::parse(&v).ok()),
+            include_contributors: value
+                .include_contributors
+                .unwrap_or_default(),
+            contributors: value.contributors,
+            new_contributors: value.new_contributors,
             tag: Tag {
                 name: value.tag_name,
                 semver: semver::Version::parse(&value.version)
@@ -95,6 +197,8 @@ impl std::fmt::Debug for Release {
             .field("include_author", &self.include_author)
             .field("include_pr_link", &self.include_pr_link)
             .field("timestamp", &self.timestamp)
+            .field("release_as", &self.release_as)
+            .field("include_contributors", &self.include_contributors)
             .finish()
     }
 }
@@ -104,7 +208,7 @@ impl Serialize for Release {
     where
         S: serde::Serializer,
     {
-        let mut s = serializer.serialize_struct("Release", 12)?;
+        let mut s = serializer.serialize_struct("Release", 16)?;
         s.serialize_field("link", &self.link)?;
         s.serialize_field("tag_compare_link", &self.tag_compare_link)?;
         s.serialize_field("sha_compare_link", &self.sha_compare_link)?;
@@ -117,6 +221,13 @@ impl Serialize for Release {
         s.serialize_field("commits", &self.commits)?;
         s.serialize_field("notes", &self.notes)?;
         s.serialize_field("timestamp", &self.timestamp)?;
+        s.serialize_field(
+            "release_as",
+            &self.release_as.as_ref().map(|v| v.to_string()),
+        )?;
+        s.serialize_field("include_contributors", &self.include_contributors)?;
+        s.serialize_field("contributors", &self.contributors)?;
+        s.serialize_field("new_contributors", &self.new_contributors)?;
         s.end()
     }
 }
@@ -240,6 +351,10 @@ mod tests {
             include_pr_link: true,
             notes: "Some long release notes...".to_string(),
             timestamp: 9876543210,
+            release_as: None,
+            include_contributors: false,
+            contributors: vec![],
+            new_contributors: vec![],
         };
 
         let debug_str = format!("{:?}", release);
@@ -290,6 +405,14 @@ mod tests {
             include_pr_link: true,
             notes: "# Release Notes\n\n- Added feature".to_string(),
             timestamp: 1234567890,
+            release_as: Some(Version::new(2, 1, 0)),
+            include_contributors: true,
+            contributors: vec![Contributor {
+                name: "Jane".to_string(),
+                email: "jane@example.com".to_string(),
+                username: Some("jane".to_string()),
+            }],
+            new_contributors: vec![],
         };
 
         let json = serde_json::to_value(&release).unwrap();
@@ -313,6 +436,10 @@ mod tests {
         assert_eq!(json["commits"].as_array().unwrap().len(), 1);
         assert_eq!(json["notes"], "# Release Notes\n\n- Added feature");
         assert_eq!(json["timestamp"], 1234567890);
+        assert_eq!(json["release_as"], "2.1.0");
+        assert_eq!(json["include_contributors"], true);
+        assert_eq!(json["contributors"][0]["username"], "jane");
+        assert_eq!(json["new_contributors"], serde_json::json!([]));
     }
 
     #[test]
@@ -329,6 +456,10 @@ mod tests {
             include_pr_link: false,
             notes: "".to_string(),
             timestamp: 0,
+            release_as: None,
+            include_contributors: false,
+            contributors: vec![],
+            new_contributors: vec![],
         };
 
         let json = serde_json::to_value(&release).unwrap();
@@ -368,6 +499,10 @@ mod tests {
             include_pr_link: false,
             notes: "".to_string(),
             timestamp: 0,
+            release_as: None,
+            include_contributors: false,
+            contributors: vec![],
+            new_contributors: vec![],
         };
 
         assert_eq!(release.commits.len(), 3);
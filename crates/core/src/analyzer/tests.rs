@@ -2,15 +2,19 @@
 //!
 //! Test organization:
 //! - `basic_versioning`: Basic analyzer functionality (construction, version bumping)
+//! - `contributors`: Release contributors and first-time contributors
 //! - `filtering`: Commit filtering tests (skip_ci, skip_chore, etc.)
 //! - `prerelease`: Prerelease versioning tests
+//! - `release_as`: `Release-As` commit footer overrides
 //! - `rendering`: Default `body` template output and group ordering
 //! - `version_rules`: Custom version increment rules and regex tests
 //! - `version_types`: Version type tests (semantic+build, date-based)
 
 mod basic_versioning;
+mod contributors;
 mod filtering;
 mod prerelease;
+mod release_as;
 mod rendering;
 mod version_rules;
 mod version_types;
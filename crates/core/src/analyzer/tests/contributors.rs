@@ -0,0 +1,225 @@
+//! Release contributor tests.
+//!
+//! Tests for:
+//! - Collecting unique authors and co-authors of a release's commits
+//! - Leaving generated commits without an author uncredited
+//! - Setting apart contributors missing from earlier history
+//! - Crediting canonical `.mailmap` identities
+
+use semver::Version as SemVer;
+
+use crate::{
+    analyzer::{
+        AnalyzeOptions, Analyzer, config::AnalyzerConfig, mailmap::Mailmap,
+        release::Contributor,
+    },
+    forge::request::{ForgeCommit, ForgeCommitAuthor, Tag},
+};
+
+fn current_tag() -> Tag {
+    Tag {
+        sha: "old123".to_string(),
+        name: "1.0.0".to_string(),
+        semver: SemVer::parse("1.0.0").unwrap(),
+        ..Tag::default()
+    }
+}
+
+fn commit(
+    id: &str,
+    message: &str,
+    author: (&str, &str),
+    username: Option<&str>,
+) -> ForgeCommit {
+    ForgeCommit {
+        id: id.to_string(),
+        short_id: id.to_string(),
+        message: message.to_string(),
+        author_name: author.0.to_string(),
+        author_email: author.1.to_string(),
+        author_username: username.map(String::from),
+        timestamp: 1000,
+        ..ForgeCommit::default()
+    }
+}
+
+fn contributor(name: &str, email: &str, username: Option<&str>) -> Contributor {
+    Contributor {
+        name: name.to_string(),
+        email: email.to_string(),
+        username: username.map(String::from),
+    }
+}
+
+fn previous_author(name: &str, email: &str) -> ForgeCommitAuthor {
+    ForgeCommitAuthor {
+        name: name.to_string(),
+        email: email.to_string(),
+        ..ForgeCommitAuthor::default()
+    }
+}
+
+#[test]
+fn contributors_lists_each_author_and_co_author_once_by_name() {
+    let config = AnalyzerConfig::default();
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let commits = vec![
+        commit(
+            "aaa",
+            "fix: a bug\n\nCo-authored-by: Bob <bob@example.com>",
+            ("Zoe", "zoe@example.com"),
+            None,
+        ),
+        commit("bbb", "feat: a thing", ("Bob", "BOB@example.com"), None),
+        commit(
+            "ccc",
+            "fix: another",
+            ("Zoe", "zoe@example.com"),
+            Some("zoe"),
+        ),
+    ];
+
+    let release = analyzer
+        .analyze(commits, Some(current_tag()))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        release.contributors,
+        vec![
+            contributor("Bob", "bob@example.com", None),
+            contributor("Zoe", "zoe@example.com", Some("zoe")),
+        ]
+    );
+}
+
+#[test]
+fn contributors_omits_generated_commits_without_an_author() {
+    let config = AnalyzerConfig::default();
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let release = analyzer
+        .analyze_with(
+            vec![],
+            Some(current_tag()),
+            AnalyzeOptions {
+                dependency_bumps: vec![ForgeCommit {
+                    id: "dep".to_string(),
+                    message: "deps: bump core to 2.0.0".to_string(),
+                    ..ForgeCommit::default()
+                }],
+                ..AnalyzeOptions::default()
+            },
+        )
+        .unwrap()
+        .unwrap();
+
+    assert!(release.contributors.is_empty());
+}
+
+#[test]
+fn new_contributors_excludes_previous_authors() {
+    let config = AnalyzerConfig::default();
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let commits = vec![
+        commit("aaa", "fix: a bug", ("Ann", "ann@example.com"), None),
+        commit("bbb", "fix: more", ("Ned", "ned@example.com"), Some("ned")),
+        // a new email, but the same name as an author without one
+        commit("ccc", "fix: again", ("Old Timer", "new@example.com"), None),
+    ];
+
+    let release = analyzer
+        .analyze_with(
+            commits,
+            Some(current_tag()),
+            AnalyzeOptions {
+                previous_authors: Some(vec![
+                    previous_author("Ann B.", "ANN@example.com"),
+                    previous_author("Old Timer", ""),
+                ]),
+                ..AnalyzeOptions::default()
+            },
+        )
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        release.new_contributors,
+        vec![contributor("Ned", "ned@example.com", Some("ned"))]
+    );
+}
+
+#[test]
+fn new_contributors_is_empty_without_previous_history() {
+    let config = AnalyzerConfig::default();
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let release = analyzer
+        .analyze(
+            vec![commit(
+                "aaa",
+                "fix: a bug",
+                ("Ann", "ann@example.com"),
+                None,
+            )],
+            Some(current_tag()),
+        )
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(release.contributors.len(), 1);
+    assert!(release.new_contributors.is_empty());
+}
+
+#[test]
+fn contributors_use_mailmap_identities() {
+    let config = AnalyzerConfig {
+        mailmap: Mailmap::parse(
+            "Ann Lee <ann@example.com> <ann@laptop.local>\n\
+             Bob Ray <bob@example.com> bob <bob@old.example.com>\n",
+        ),
+        ..AnalyzerConfig::default()
+    };
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let commits = vec![
+        commit(
+            "aaa",
+            "fix: a bug\n\nCo-authored-by: bob <bob@old.example.com>",
+            ("ann", "ann@laptop.local"),
+            None,
+        ),
+        commit("bbb", "fix: more", ("Ann Lee", "ann@example.com"), None),
+    ];
+
+    let release = analyzer
+        .analyze_with(
+            commits,
+            Some(current_tag()),
+            AnalyzeOptions {
+                previous_authors: Some(vec![previous_author(
+                    "bob",
+                    "bob@old.example.com",
+                )]),
+                ..AnalyzeOptions::default()
+            },
+        )
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(release.commits[0].author_name, "Ann Lee");
+    assert_eq!(release.commits[0].author_email, "ann@example.com");
+    assert_eq!(
+        release.contributors,
+        vec![
+            contributor("Ann Lee", "ann@example.com", None),
+            contributor("Bob Ray", "bob@example.com", None),
+        ]
+    );
+    assert_eq!(
+        release.new_contributors,
+        vec![contributor("Ann Lee", "ann@example.com", None)]
+    );
+}
@@ -0,0 +1,137 @@
+//! `Release-As` commit footer tests.
+//!
+//! Tests for:
+//! - Overriding the computed version with a footer
+//! - Newest footer winning when several commits carry one
+//! - Ignoring footers that would move the version backwards
+//! - Ignoring footers that aren't valid versions
+
+use semver::Version as SemVer;
+
+use crate::{
+    analyzer::{Analyzer, config::AnalyzerConfig},
+    forge::request::{ForgeCommit, Tag},
+};
+
+fn current_tag() -> Tag {
+    Tag {
+        sha: "old123".to_string(),
+        name: "1.2.0".to_string(),
+        semver: SemVer::parse("1.2.0").unwrap(),
+        ..Tag::default()
+    }
+}
+
+fn commit(id: &str, message: &str, timestamp: i64) -> ForgeCommit {
+    ForgeCommit {
+        id: id.to_string(),
+        short_id: id[..3].to_string(),
+        message: message.to_string(),
+        timestamp,
+        ..ForgeCommit::default()
+    }
+}
+
+#[test]
+fn release_as_footer_overrides_computed_version() {
+    let config = AnalyzerConfig::default();
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let commits = vec![
+        commit("abc123", "fix: patch it\n\nRelease-As: 2.0.0", 2000),
+        commit("def456", "feat: add a thing", 1000),
+    ];
+
+    let release = analyzer
+        .analyze(commits, Some(current_tag()))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(release.tag.semver, SemVer::parse("2.0.0").unwrap());
+    assert_eq!(release.release_as, Some(SemVer::parse("2.0.0").unwrap()));
+}
+
+#[test]
+fn release_as_footer_is_case_insensitive_and_accepts_v_prefix() {
+    let config = AnalyzerConfig::default();
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let commits = vec![commit(
+        "abc123",
+        "chore: cut a release\n\nrelease-as: v1.5.0-rc.1",
+        1000,
+    )];
+
+    let release = analyzer
+        .analyze(commits, Some(current_tag()))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(release.tag.semver, SemVer::parse("1.5.0-rc.1").unwrap());
+}
+
+#[test]
+fn newest_release_as_footer_wins() {
+    let config = AnalyzerConfig::default();
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let commits = vec![
+        commit("abc123", "fix: newer\n\nRelease-As: 3.0.0", 2000),
+        commit("def456", "fix: older\n\nRelease-As: 4.0.0", 1000),
+    ];
+
+    let release = analyzer
+        .analyze(commits, Some(current_tag()))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(release.tag.semver, SemVer::parse("3.0.0").unwrap());
+}
+
+#[test]
+fn release_as_footer_cannot_move_version_backwards() {
+    let config = AnalyzerConfig::default();
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let commits =
+        vec![commit("abc123", "feat: add it\n\nRelease-As: 1.1.0", 1000)];
+
+    let release = analyzer
+        .analyze(commits, Some(current_tag()))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(release.tag.semver, SemVer::parse("1.3.0").unwrap());
+    assert!(release.release_as.is_none());
+}
+
+#[test]
+fn invalid_release_as_footer_is_ignored() {
+    let config = AnalyzerConfig::default();
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let commits = vec![
+        commit("abc123", "fix: patch it\n\nRelease-As: next", 2000),
+        commit("def456", "fix: older\n\nRelease-As: 2.0.0", 1000),
+    ];
+
+    let release = analyzer
+        .analyze(commits, Some(current_tag()))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(release.tag.semver, SemVer::parse("2.0.0").unwrap());
+}
+
+#[test]
+fn release_as_footer_applies_to_first_release() {
+    let config = AnalyzerConfig::default();
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let commits =
+        vec![commit("abc123", "feat: initial\n\nRelease-As: 1.0.0", 1000)];
+
+    let release = analyzer.analyze(commits, None).unwrap().unwrap();
+
+    assert_eq!(release.tag.semver, SemVer::parse("1.0.0").unwrap());
+}
@@ -11,12 +11,16 @@ use regex::Regex;
 use semver::Version as SemVer;
 
 use crate::{
-    analyzer::{Analyzer, config::AnalyzerConfig},
+    analyzer::AnalyzeOptions,
+    analyzer::{
+        Analyzer,
+        config::{AnalyzerConfig, ReferenceMatcher},
+    },
     config::{
-        changelog::DEFAULT_BODY,
+        changelog::{DEFAULT_BODY, DEFAULT_KEEP_A_CHANGELOG_BODY},
         versioning::{Group, NAMED_PARSERS, Parser},
     },
-    forge::request::{ForgeCommit, ForgeCommitPR, Tag},
+    forge::request::{ForgeCommit, ForgeCommitAuthor, ForgeCommitPR, Tag},
 };
 
 /// The entry a commit renders as when no PR segment is appended.
@@ -491,3 +495,260 @@ fn default_body_omits_pr_link_when_commit_has_no_pr() {
         "unexpected entry for a commit with no PR:\n{notes}"
     );
 }
+
+#[test]
+fn keep_a_changelog_body_files_commits_by_change_type() {
+    let config = AnalyzerConfig {
+        body: DEFAULT_KEEP_A_CHANGELOG_BODY.into(),
+        ..AnalyzerConfig::default()
+    };
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let commits = vec![
+        make_linked_commit("aaa1111", "fix: a bug fix", 4000),
+        make_linked_commit("bbb2222", "feat(api): a feature", 3000),
+        make_linked_commit("ccc3333", "refactor!: reshape config", 2000),
+        make_linked_commit("ddd4444", "revert: drop an experiment", 1000),
+    ];
+
+    let release = analyzer
+        .analyze(commits, Some(current_tag()))
+        .unwrap()
+        .unwrap();
+
+    assert!(
+        release.notes.starts_with("## [2.0.0] - "),
+        "unexpected heading:\n{}",
+        release.notes
+    );
+    assert_eq!(
+        headings(&release.notes),
+        vec!["Added", "Changed", "Removed", "Fixed"]
+    );
+    assert_eq!(
+        entries(&release.notes),
+        vec![
+            "- _(api)_ a feature \
+             ([bbb2222](https://example.com/org/repo/commit/bbb2222))",
+            "- **BREAKING** reshape config \
+             ([ccc3333](https://example.com/org/repo/commit/ccc3333))",
+            "- drop an experiment \
+             ([ddd4444](https://example.com/org/repo/commit/ddd4444))",
+            "- a bug fix \
+             ([aaa1111](https://example.com/org/repo/commit/aaa1111))",
+        ]
+    );
+}
+
+/// Issue and Jira references render as links after the commit link, on
+/// both the regular and the breaking line of the template.
+#[test]
+fn default_body_renders_reference_links() {
+    let config = AnalyzerConfig {
+        body: DEFAULT_BODY.into(),
+        references: vec![
+            ReferenceMatcher {
+                pattern: Regex::new(r"#(\d+)\b").unwrap(),
+                url: "https://example.com/org/repo/issues/${1}".into(),
+            },
+            ReferenceMatcher {
+                pattern: Regex::new(r"\bPLAT-\d+\b").unwrap(),
+                url: "https://jira.example.com/browse/$0".into(),
+            },
+        ],
+        ..AnalyzerConfig::default()
+    };
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let notes = analyzer
+        .analyze(
+            vec![
+                make_linked_commit(
+                    "aaa1111",
+                    "fix: a bug fix (#12)\n\nRefs PLAT-991",
+                    2000,
+                ),
+                make_linked_commit("bbb2222", "feat!: drop v1 for #7", 1000),
+            ],
+            Some(current_tag()),
+        )
+        .unwrap()
+        .unwrap()
+        .notes;
+
+    assert!(
+        notes.contains(
+            "- a bug fix (#12) \
+             [_(aaa1111)_](https://example.com/org/repo/commit/aaa1111) \
+             ([#12](https://example.com/org/repo/issues/12), \
+             [PLAT-991](https://jira.example.com/browse/PLAT-991))"
+        ),
+        "unexpected notes:\n{notes}"
+    );
+    assert!(
+        notes.contains(
+            "[**breaking**]: drop v1 for #7 \
+             [_(bbb2222)_](https://example.com/org/repo/commit/bbb2222) \
+             ([#7](https://example.com/org/repo/issues/7))"
+        ),
+        "unexpected notes:\n{notes}"
+    );
+}
+
+/// The PR link already covers a squash merge's `(#12)`, so the entry
+/// doesn't link it again.
+#[test]
+fn default_body_skips_references_to_the_commits_pr() {
+    let config = AnalyzerConfig {
+        body: DEFAULT_BODY.into(),
+        include_pr_link: true,
+        references: vec![ReferenceMatcher {
+            pattern: Regex::new(r"#(\d+)\b").unwrap(),
+            url: "https://example.com/org/repo/issues/${1}".into(),
+        }],
+        ..AnalyzerConfig::default()
+    };
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let notes = analyzer
+        .analyze(
+            vec![make_commit_with_pr(
+                "aaa1111",
+                "fix: a bug fix (#12)\n\nFollow-up to #12",
+                1000,
+                "12",
+            )],
+            Some(current_tag()),
+        )
+        .unwrap()
+        .unwrap()
+        .notes;
+
+    assert_eq!(
+        entries(&notes),
+        vec![
+            "- a bug fix (#12) \
+             [_(aaa1111)_](https://example.com/org/repo/commit/aaa1111) \
+             ([PR 12](https://example.com/org/repo/pulls/12))"
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn default_body_omits_reference_segment_without_references() {
+    let notes = render_with_pr_link(
+        false,
+        vec![make_linked_commit("aaa1111", "fix: a bug fix", 1000)],
+    );
+
+    assert_eq!(entries(&notes), vec![BARE_ENTRY.to_string()]);
+}
+
+#[test]
+fn default_body_lists_co_authors_next_to_author() {
+    let config = AnalyzerConfig {
+        body: DEFAULT_BODY.into(),
+        include_author: true,
+        ..AnalyzerConfig::default()
+    };
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    let notes = analyzer
+        .analyze(
+            vec![ForgeCommit {
+                author_name: "John".into(),
+                ..make_linked_commit(
+                    "aaa1111",
+                    "fix: a bug fix\n\nCo-authored-by: Jane <jane@example.com>\nCo-authored-by: Bob <bob@example.com>",
+                    1000,
+                )
+            }],
+            Some(current_tag()),
+        )
+        .unwrap()
+        .unwrap()
+        .notes;
+
+    assert_eq!(
+        entries(&notes),
+        vec![format!("{BARE_ENTRY} (John, Jane, Bob)")],
+        "unexpected notes:\n{notes}"
+    );
+    assert!(
+        !notes.contains("Co-authored-by"),
+        "trailers should be stripped from the body:\n{notes}"
+    );
+}
+
+fn render_with_contributors(include_contributors: bool) -> String {
+    let config = AnalyzerConfig {
+        body: DEFAULT_BODY.into(),
+        include_contributors,
+        ..AnalyzerConfig::default()
+    };
+    let analyzer = Analyzer::new(&config).unwrap();
+
+    analyzer
+        .analyze_with(
+            vec![
+                ForgeCommit {
+                    author_name: "Jane".into(),
+                    author_email: "jane@example.com".into(),
+                    author_username: Some("jane-gh".into()),
+                    ..make_linked_commit("aaa1111", "fix: a bug fix", 2000)
+                },
+                ForgeCommit {
+                    author_name: "John".into(),
+                    author_email: "john@example.com".into(),
+                    ..make_linked_commit("bbb2222", "feat: a feature", 1000)
+                },
+            ],
+            Some(current_tag()),
+            AnalyzeOptions {
+                previous_authors: Some(vec![ForgeCommitAuthor {
+                    name: "John".into(),
+                    email: "john@example.com".into(),
+                    ..ForgeCommitAuthor::default()
+                }]),
+                ..AnalyzeOptions::default()
+            },
+        )
+        .unwrap()
+        .unwrap()
+        .notes
+}
+
+#[test]
+fn default_body_renders_contributors_when_enabled() {
+    let notes = render_with_contributors(true);
+
+    assert_eq!(
+        headings(&notes),
+        vec![
+            "🚀 Features",
+            "🐛 Bug Fixes",
+            "Contributors",
+            "New Contributors"
+        ],
+        "unexpected notes:\n{notes}"
+    );
+    assert!(
+        notes.ends_with(
+            "### Contributors\n\n- @jane-gh\n- John\n\n\
+             ### New Contributors\n\n- @jane-gh"
+        ),
+        "unexpected notes:\n{notes}"
+    );
+}
+
+#[test]
+fn default_body_omits_contributors_when_disabled() {
+    let notes = render_with_contributors(false);
+
+    assert_eq!(
+        headings(&notes),
+        vec!["🚀 Features", "🐛 Bug Fixes"],
+        "unexpected notes:\n{notes}"
+    );
+}
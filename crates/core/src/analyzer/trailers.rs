@@ -0,0 +1,175 @@
+//! Git trailer parsing, e.g. `Co-authored-by: Jane <jane@example.com>`.
+
+use indexmap::IndexMap;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Matches a trailer line: a `Token: value` pair, or the `Token #value` form
+/// conventional commits use for references such as `Refs #123`
+static TRAILER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?<token>[A-Za-z][A-Za-z0-9-]*|BREAKING CHANGE)(?::\s+|\s+#)(?<value>.*)$")
+        .unwrap()
+});
+
+/// Matches a `Name <email>` identity, as in `Co-authored-by` trailers
+static IDENTITY_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?<name>.*?)\s*<(?<email>[^>]*)>\s*$").unwrap()
+});
+
+/// The trailer block closing a commit message.
+#[derive(Debug, Default, PartialEq)]
+pub struct Trailers {
+    /// The block exactly as it appears in the message
+    pub block: String,
+    /// Values by lowercased token, in order of appearance
+    pub values: IndexMap<String, Vec<String>>,
+}
+
+impl Trailers {
+    /// Parses the trailers from the final paragraph of `message`. The title
+    /// paragraph is never a trailer block, and a paragraph counts only when
+    /// every line is a trailer or a continuation of the one above it.
+    pub fn parse(message: &str) -> Option<Self> {
+        let message = message.trim_end();
+        let (_, block) = message.rsplit_once("\n\n")?;
+        let block = block.trim_start_matches('\n');
+
+        let mut values: IndexMap<String, Vec<String>> = IndexMap::new();
+        let mut last: Option<(String, usize)> = None;
+
+        for line in block.lines() {
+            if let Some(captures) = TRAILER_REGEX.captures(line) {
+                let token = captures["token"].to_lowercase();
+                let value = captures["value"].trim().to_string();
+                let entry = values.entry(token.clone()).or_default();
+                entry.push(value);
+                last = Some((token, entry.len() - 1));
+            } else if line.starts_with([' ', '\t'])
+                && let Some((token, index)) = last.as_ref()
+            {
+                let value = &mut values[token.as_str()][*index];
+                value.push(' ');
+                value.push_str(line.trim());
+            } else {
+                return None;
+            }
+        }
+
+        if values.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            block: block.to_string(),
+            values,
+        })
+    }
+
+    /// Removes the trailer block from the end of `body` if it's there,
+    /// returning `None` when nothing else is left.
+    pub fn strip_from(&self, body: &str) -> Option<String> {
+        let body = body.trim_end();
+
+        let stripped = match body.strip_suffix(&self.block) {
+            Some(rest) if rest.is_empty() || rest.ends_with('\n') => {
+                rest.trim_end()
+            }
+            _ => body,
+        };
+
+        (!stripped.is_empty()).then(|| stripped.to_string())
+    }
+}
+
+/// Splits a `Name <email>` identity into its parts. Values without an email
+/// are taken as a name alone.
+pub fn parse_identity(value: &str) -> (String, String) {
+    match IDENTITY_REGEX.captures(value) {
+        Some(captures) => {
+            (captures["name"].to_string(), captures["email"].to_string())
+        }
+        None => (value.trim().to_string(), String::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_collects_trailers_by_lowercased_token() {
+        let trailers = Trailers::parse(
+            "fix: crash\n\nSome body.\n\nCo-authored-by: Jane <jane@example.com>\nco-authored-by: Bob <bob@example.com>\nRefs #12\nSigned-off-by: Al <al@example.com>\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            trailers.values["co-authored-by"],
+            vec!["Jane <jane@example.com>", "Bob <bob@example.com>"]
+        );
+        assert_eq!(trailers.values["refs"], vec!["12"]);
+        assert_eq!(
+            trailers.values["signed-off-by"],
+            vec!["Al <al@example.com>"]
+        );
+        assert_eq!(
+            trailers.values.keys().collect::<Vec<_>>(),
+            vec!["co-authored-by", "refs", "signed-off-by"]
+        );
+    }
+
+    #[test]
+    fn parse_joins_continuation_lines() {
+        let trailers = Trailers::parse(
+            "feat: thing\n\nBREAKING CHANGE: the config format\n  changed entirely",
+        )
+        .unwrap();
+
+        assert_eq!(
+            trailers.values["breaking change"],
+            vec!["the config format changed entirely"]
+        );
+    }
+
+    #[test]
+    fn parse_ignores_paragraph_that_is_not_all_trailers() {
+        assert!(
+            Trailers::parse("fix: crash\n\nNote: this also\nfixes a leak")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn parse_ignores_title_only_message() {
+        assert!(Trailers::parse("Refs: not a trailer block").is_none());
+    }
+
+    #[test]
+    fn strip_from_removes_trailing_block() {
+        let trailers =
+            Trailers::parse("fix: crash\n\nBody text.\n\nRefs: #12").unwrap();
+
+        assert_eq!(
+            trailers.strip_from("Body text.\n\nRefs: #12"),
+            Some("Body text.".to_string())
+        );
+        assert_eq!(trailers.strip_from("Refs: #12"), None);
+        // a body the trailers were already split from is left as is
+        assert_eq!(
+            trailers.strip_from("Body text."),
+            Some("Body text.".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_identity_splits_name_and_email() {
+        assert_eq!(
+            parse_identity("Jane Doe <jane@example.com>"),
+            ("Jane Doe".to_string(), "jane@example.com".to_string())
+        );
+        assert_eq!(
+            parse_identity("Jane Doe"),
+            ("Jane Doe".to_string(), String::new())
+        );
+    }
+}
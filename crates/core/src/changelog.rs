@@ -0,0 +1,4 @@
+//! Changelog file formats beyond the default Markdown entries.
+
+pub mod keep_a_changelog;
+pub mod packaging;
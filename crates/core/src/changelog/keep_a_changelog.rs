@@ -0,0 +1,543 @@
+//! Changelog files following [Keep a Changelog](https://keepachangelog.com).
+//!
+//! Developers describe upcoming changes by hand under `## [Unreleased]`.
+//! When a release is added, that content moves into the new version section
+//! and is merged with the commit-derived entries heading by heading, and the
+//! reference-style compare links at the bottom of the file are updated.
+
+use regex::Regex;
+use serde::Serialize;
+use std::{fmt::Display, sync::LazyLock};
+use url::Url;
+
+use crate::{forge::request::Tag, result::Result};
+
+/// Title and intro of a changelog created from scratch.
+pub const HEADER: &str = "# Changelog
+
+All notable changes to this project will be documented in this file.
+
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),
+and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).";
+
+/// Change type headings in the order Keep a Changelog lists them. Other
+/// headings follow these in the order they first appear.
+const HEADINGS: [&str; 6] = [
+    "Added",
+    "Changed",
+    "Deprecated",
+    "Removed",
+    "Fixed",
+    "Security",
+];
+
+const UNRELEASED_HEADING: &str = "## [Unreleased]";
+
+static UNRELEASED_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^##\s+\[?unreleased\]?").unwrap());
+
+static LINK_DEFINITION_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\[([^\]]+)\]:\s*\S+").unwrap());
+
+/// Link targets for the reference definitions at the bottom of the file.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct ReleaseLinks {
+    /// Label of the release's link, i.e. the version in its heading
+    pub version: String,
+    /// Where the version heading links to, usually the compare link from
+    /// the previous release. Empty for no link
+    pub compare_link: String,
+    /// Compare link from this release to `HEAD`. Empty for no link
+    pub unreleased_link: String,
+}
+
+impl ReleaseLinks {
+    /// Links for the release tagged `tag`. The version links to
+    /// `tag_compare_link`, or to the release itself for a first release.
+    pub fn new(
+        tag: &Tag,
+        tag_compare_link: &str,
+        release_link_base_url: &Url,
+        compare_link_base_url: &Url,
+    ) -> Result<Self> {
+        let compare_link = if tag_compare_link.is_empty() {
+            release_link_base_url.join(&tag.name)?.to_string()
+        } else {
+            tag_compare_link.to_string()
+        };
+
+        Ok(Self {
+            version: tag.semver.to_string(),
+            compare_link,
+            unreleased_link: compare_link_base_url
+                .join(&format!("{}...HEAD", tag.name))?
+                .to_string(),
+        })
+    }
+}
+
+/// Adds the rendered release `entry` to `existing`, moving the content of
+/// the Unreleased section into it. The entry's first line is its heading.
+pub fn insert_release(
+    entry: &str,
+    existing: &str,
+    links: &ReleaseLinks,
+) -> String {
+    let mut document = Document::parse(existing);
+    document.add_release(entry, links);
+    document.to_string()
+}
+
+/// Builds a changelog from `releases`, oldest first, keeping the title and
+/// the Unreleased section of `existing`. Every version section and link
+/// definition is replaced.
+pub fn rebuild(existing: &str, releases: &[(String, ReleaseLinks)]) -> String {
+    let existing = Document::parse(existing);
+
+    let mut document = Document {
+        head: existing.head,
+        unreleased_heading: existing.unreleased_heading,
+        ..Document::default()
+    };
+
+    for (entry, links) in releases {
+        document.add_release(entry, links);
+    }
+
+    document.unreleased = existing.unreleased;
+    document.to_string()
+}
+
+/// A Keep a Changelog file split into the parts a release touches.
+#[derive(Debug, Default)]
+struct Document {
+    /// Everything before the Unreleased section
+    head: String,
+    unreleased_heading: String,
+    /// Hand-written content of the Unreleased section
+    unreleased: Vec<String>,
+    /// Version sections, newest first
+    releases: String,
+    /// Reference-style link definitions at the end of the file
+    links: Vec<String>,
+}
+
+impl Document {
+    fn parse(existing: &str) -> Self {
+        if existing.trim().is_empty() {
+            return Self {
+                head: HEADER.into(),
+                unreleased_heading: UNRELEASED_HEADING.into(),
+                ..Self::default()
+            };
+        }
+
+        let mut lines = existing.lines().collect::<Vec<_>>();
+        let mut links = vec![];
+
+        while let Some(line) = lines.last() {
+            if line.trim().is_empty() {
+                lines.pop();
+            } else if LINK_DEFINITION_REGEX.is_match(line) {
+                links.insert(0, line.to_string());
+                lines.pop();
+            } else {
+                break;
+            }
+        }
+
+        let is_section = |line: &&str| line.starts_with("## ");
+
+        // without an Unreleased section one is added above the newest
+        // version section
+        let (head_end, unreleased_heading, content_start) = match lines
+            .iter()
+            .position(|line| UNRELEASED_REGEX.is_match(line))
+        {
+            Some(i) => (i, lines[i].to_string(), i + 1),
+            None => {
+                let i =
+                    lines.iter().position(is_section).unwrap_or(lines.len());
+                (i, UNRELEASED_HEADING.to_string(), i)
+            }
+        };
+
+        let releases_start = lines[content_start..]
+            .iter()
+            .position(is_section)
+            .map(|i| content_start + i)
+            .unwrap_or(lines.len());
+
+        Self {
+            head: lines[..head_end].join("\n").trim_end().to_string(),
+            unreleased_heading,
+            unreleased: lines[content_start..releases_start]
+                .iter()
+                .map(|line| line.to_string())
+                .collect(),
+            releases: lines[releases_start..].join("\n").trim().to_string(),
+            links,
+        }
+    }
+
+    /// Adds a version section for `entry` above the existing ones, merging
+    /// the Unreleased content into it, and links its heading.
+    fn add_release(&mut self, entry: &str, links: &ReleaseLinks) {
+        let entry = entry.trim();
+        let (heading, body) = entry.split_once('\n').unwrap_or((entry, ""));
+
+        let mut sections = Sections::parse(&self.unreleased);
+        sections.merge(Sections::parse(body.lines()));
+
+        let section = [heading.trim_end().to_string(), sections.to_string()]
+            .into_iter()
+            .filter(|block| !block.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        self.releases = [section, std::mem::take(&mut self.releases)]
+            .into_iter()
+            .filter(|block| !block.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        self.unreleased.clear();
+
+        // replaces the definitions of both labels from earlier runs
+        self.links.retain(|line| {
+            LINK_DEFINITION_REGEX
+                .captures(line)
+                .map(|c| c[1].to_string())
+                .is_none_or(|label| {
+                    !label.eq_ignore_ascii_case("unreleased")
+                        && label != links.version
+                })
+        });
+
+        let new_links = [
+            ("Unreleased", &links.unreleased_link),
+            (links.version.as_str(), &links.compare_link),
+        ]
+        .into_iter()
+        .filter(|(_, link)| !link.is_empty())
+        .map(|(label, link)| format!("[{label}]: {link}"));
+
+        self.links.splice(0..0, new_links);
+    }
+}
+
+impl Display for Document {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut unreleased = self.unreleased_heading.clone();
+        let content = trim_blank(&self.unreleased).join("\n");
+
+        if !content.is_empty() {
+            unreleased = format!("{unreleased}\n\n{content}");
+        }
+
+        let blocks = [&self.head, &unreleased, &self.releases]
+            .into_iter()
+            .filter(|block| !block.is_empty())
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+
+        writeln!(f, "{}", blocks.join("\n\n"))?;
+
+        if !self.links.is_empty() {
+            writeln!(f, "\n{}", self.links.join("\n"))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A section's content grouped under its `### ` change type headings.
+#[derive(Debug, Default)]
+struct Sections {
+    /// Lines before the first heading
+    preamble: Vec<String>,
+    groups: Vec<(String, Vec<String>)>,
+}
+
+impl Sections {
+    fn parse<S: AsRef<str>>(lines: impl IntoIterator<Item = S>) -> Self {
+        let mut sections = Self::default();
+
+        for line in lines {
+            let line = line.as_ref();
+
+            if let Some(heading) = line.strip_prefix("### ") {
+                sections.groups.push((heading.trim().to_string(), vec![]));
+            } else if let Some((_, group)) = sections.groups.last_mut() {
+                group.push(line.to_string());
+            } else {
+                sections.preamble.push(line.to_string());
+            }
+        }
+
+        sections
+    }
+
+    /// Appends `other`'s entries below the ones already under the same
+    /// heading, then puts the headings in Keep a Changelog order.
+    fn merge(&mut self, other: Self) {
+        self.preamble.extend(other.preamble);
+
+        for (heading, lines) in other.groups {
+            match self
+                .groups
+                .iter_mut()
+                .find(|(h, _)| h.eq_ignore_ascii_case(&heading))
+            {
+                Some((_, existing)) => {
+                    existing.extend(lines);
+                }
+                None => self.groups.push((heading, lines)),
+            }
+        }
+
+        self.groups.sort_by_key(|(heading, _)| {
+            HEADINGS
+                .iter()
+                .position(|h| h.eq_ignore_ascii_case(heading))
+                .unwrap_or(HEADINGS.len())
+        });
+    }
+}
+
+impl Display for Sections {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let preamble = trim_blank(&self.preamble).join("\n");
+
+        let groups = self.groups.iter().filter_map(|(heading, lines)| {
+            let lines = lines
+                .iter()
+                .map(|line| line.as_str())
+                .filter(|line| !line.trim().is_empty())
+                .collect::<Vec<_>>();
+
+            (!lines.is_empty())
+                .then(|| format!("### {heading}\n\n{}", lines.join("\n")))
+        });
+
+        let blocks = std::iter::once(preamble)
+            .chain(groups)
+            .filter(|block| !block.is_empty())
+            .collect::<Vec<_>>();
+
+        f.write_str(&blocks.join("\n\n"))
+    }
+}
+
+/// `lines` without leading and trailing blank lines.
+fn trim_blank(lines: &[String]) -> &[String] {
+    let start = lines
+        .iter()
+        .position(|line| !line.trim().is_empty())
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map(|i| i + 1)
+        .unwrap_or(start);
+
+    &lines[start..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn links(version: &str, previous: &str) -> ReleaseLinks {
+        ReleaseLinks {
+            version: version.into(),
+            compare_link: format!(
+                "https://example.com/compare/v{previous}...v{version}"
+            ),
+            unreleased_link: format!(
+                "https://example.com/compare/v{version}...HEAD"
+            ),
+        }
+    }
+
+    #[test]
+    fn moves_unreleased_content_into_the_release() {
+        let existing = "\
+# Changelog
+
+## [Unreleased]
+
+Highlights of this release.
+
+### Changed
+
+- Reworded the docs by hand
+
+### Added
+
+- Hand-written feature note
+
+## [1.0.0] - 2026-01-01
+
+### Added
+
+- First release
+
+[Unreleased]: https://example.com/compare/v1.0.0...HEAD
+[1.0.0]: https://example.com/releases/v1.0.0
+";
+
+        let entry = "\
+## [1.1.0] - 2026-02-01
+
+### Fixed
+
+- fix from a commit
+
+### Added
+
+- feature from a commit
+";
+
+        assert_eq!(
+            insert_release(entry, existing, &links("1.1.0", "1.0.0")),
+            "\
+# Changelog
+
+## [Unreleased]
+
+## [1.1.0] - 2026-02-01
+
+Highlights of this release.
+
+### Added
+
+- Hand-written feature note
+- feature from a commit
+
+### Changed
+
+- Reworded the docs by hand
+
+### Fixed
+
+- fix from a commit
+
+## [1.0.0] - 2026-01-01
+
+### Added
+
+- First release
+
+[Unreleased]: https://example.com/compare/v1.1.0...HEAD
+[1.1.0]: https://example.com/compare/v1.0.0...v1.1.0
+[1.0.0]: https://example.com/releases/v1.0.0
+"
+        );
+    }
+
+    #[test]
+    fn creates_a_file_with_the_standard_header() {
+        let content = insert_release(
+            "## [1.0.0] - 2026-01-01\n\n### Added\n\n- one\n",
+            "",
+            &ReleaseLinks {
+                version: "1.0.0".into(),
+                unreleased_link: "https://example.com/compare/v1.0.0...HEAD"
+                    .into(),
+                ..ReleaseLinks::default()
+            },
+        );
+
+        assert_eq!(
+            content,
+            format!(
+                "{HEADER}\n\n## [Unreleased]\n\n## [1.0.0] - 2026-01-01\n\n\
+                 ### Added\n\n- one\n\n\
+                 [Unreleased]: https://example.com/compare/v1.0.0...HEAD\n"
+            )
+        );
+    }
+
+    #[test]
+    fn adds_an_unreleased_section_when_missing() {
+        let existing = "# Changelog\n\n## [1.0.0]\n\n- First release\n";
+
+        assert_eq!(
+            insert_release(
+                "## [1.1.0]\n\n### Fixed\n\n- a fix",
+                existing,
+                &links("1.1.0", "1.0.0")
+            ),
+            "# Changelog\n\n## [Unreleased]\n\n## [1.1.0]\n\n### Fixed\n\n\
+             - a fix\n\n## [1.0.0]\n\n- First release\n\n\
+             [Unreleased]: https://example.com/compare/v1.1.0...HEAD\n\
+             [1.1.0]: https://example.com/compare/v1.0.0...v1.1.0\n"
+        );
+    }
+
+    #[test]
+    fn rebuild_keeps_the_head_and_unreleased_notes() {
+        let existing = "\
+# Changelog
+
+Intro.
+
+## [Unreleased]
+
+### Added
+
+- Not released yet
+
+## [0.1.0]
+
+stale
+
+[0.1.0]: https://example.com/stale
+";
+
+        let releases = [
+            (
+                "## [1.0.0]\n\n### Added\n\n- one".to_string(),
+                ReleaseLinks {
+                    version: "1.0.0".into(),
+                    ..ReleaseLinks::default()
+                },
+            ),
+            (
+                "## [1.1.0]\n\n### Fixed\n\n- two".into(),
+                links("1.1.0", "1.0.0"),
+            ),
+        ];
+
+        assert_eq!(
+            rebuild(existing, &releases),
+            "\
+# Changelog
+
+Intro.
+
+## [Unreleased]
+
+### Added
+
+- Not released yet
+
+## [1.1.0]
+
+### Fixed
+
+- two
+
+## [1.0.0]
+
+### Added
+
+- one
+
+[Unreleased]: https://example.com/compare/v1.1.0...HEAD
+[1.1.0]: https://example.com/compare/v1.0.0...v1.1.0
+"
+        );
+    }
+}
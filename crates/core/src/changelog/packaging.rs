@@ -0,0 +1,149 @@
+//! Debian and RPM changelog entries.
+//!
+//! Both formats render from the release like any other changelog file, with
+//! the resolved `packaging` settings and a version in packaging syntax added
+//! to the template context.
+
+use semver::Version;
+use serde::Serialize;
+
+use crate::{
+    analyzer::release::Release, packages::resolved::ResolvedPackaging,
+    result::Result,
+};
+
+#[derive(Serialize)]
+struct Context<'a> {
+    #[serde(flatten)]
+    settings: &'a ResolvedPackaging,
+    version: String,
+}
+
+/// Renders `template` for `release` with `packaging` in its context.
+pub fn render(
+    release: &Release,
+    template: &str,
+    settings: &ResolvedPackaging,
+) -> Result<String> {
+    let mut context = tera::Context::from_serialize(release)?;
+
+    context.insert(
+        "packaging",
+        &Context {
+            settings,
+            version: version(&release.tag.semver, &settings.revision),
+        },
+    );
+
+    Release::render_context(template, &context)
+}
+
+/// `version` as a Debian or RPM version with `revision` appended, e.g.
+/// `1.2.0~rc.1-1`. A prerelease follows a `~` so it sorts before the
+/// release, and build metadata follows a `+`.
+pub fn version(version: &Version, revision: &str) -> String {
+    let mut formatted =
+        format!("{}.{}.{}", version.major, version.minor, version.patch);
+
+    if !version.pre.is_empty() {
+        formatted = format!("{formatted}~{}", version.pre);
+    }
+
+    if !version.build.is_empty() {
+        formatted = format!("{formatted}+{}", version.build);
+    }
+
+    format!("{formatted}-{revision}")
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+
+    use crate::{
+        analyzer::commit::Commit,
+        config::changelog::{DEFAULT_DEBIAN_BODY, DEFAULT_RPM_BODY},
+        forge::request::Tag,
+    };
+
+    use super::*;
+
+    fn release() -> Release {
+        let timestamp =
+            DateTime::parse_from_rfc2822("Sun, 18 Oct 2026 12:30:00 +0000")
+                .unwrap()
+                .timestamp();
+
+        Release {
+            tag: Tag {
+                name: "v1.2.0".into(),
+                semver: Version::new(1, 2, 0),
+                ..Tag::default()
+            },
+            commits: vec![
+                Commit {
+                    title: "add pagination".into(),
+                    scope: Some("api".into()),
+                    ..Commit::default()
+                },
+                Commit {
+                    title: "drop the v1 api".into(),
+                    breaking: true,
+                    ..Commit::default()
+                },
+                Commit {
+                    title: "Merge branch 'main'".into(),
+                    merge_commit: true,
+                    ..Commit::default()
+                },
+            ],
+            timestamp,
+            ..Release::default()
+        }
+    }
+
+    fn settings() -> ResolvedPackaging {
+        ResolvedPackaging {
+            package: "my-service".into(),
+            maintainer: "Jane Doe <jane@example.com>".into(),
+            distribution: "unstable".into(),
+            urgency: "medium".into(),
+            revision: "1".into(),
+        }
+    }
+
+    #[test]
+    fn formats_versions_for_packaging() {
+        assert_eq!(version(&Version::new(1, 2, 3), "1"), "1.2.3-1");
+        assert_eq!(
+            version(&Version::parse("2.0.0-rc.1").unwrap(), "2"),
+            "2.0.0~rc.1-2"
+        );
+        assert_eq!(
+            version(&Version::parse("1.0.0+build.5").unwrap(), "1"),
+            "1.0.0+build.5-1"
+        );
+    }
+
+    #[test]
+    fn renders_debian_entries() {
+        assert_eq!(
+            render(&release(), DEFAULT_DEBIAN_BODY, &settings()).unwrap(),
+            "my-service (1.2.0-1) unstable; urgency=medium\n\
+             \n  \
+             * api: add pagination\n  \
+             * BREAKING: drop the v1 api\n\
+             \n -- Jane Doe <jane@example.com>  Sun, 18 Oct 2026 12:30:00 +0000"
+        );
+    }
+
+    #[test]
+    fn renders_rpm_entries() {
+        assert_eq!(
+            render(&release(), DEFAULT_RPM_BODY, &settings()).unwrap(),
+            "* Sun Oct 18 2026 Jane Doe <jane@example.com> - 1.2.0-1\n\
+             - api: add pagination\n\
+             - BREAKING: drop the v1 api"
+        );
+    }
+}
@@ -9,12 +9,14 @@
 
 pub mod changelog;
 pub mod defaults;
+pub mod loader;
 pub mod overrides;
 pub mod package;
 pub mod prerelease;
 pub mod release_type;
 pub mod repository;
 mod toml;
+pub mod version_group;
 pub mod versioning;
 
 pub use toml::{
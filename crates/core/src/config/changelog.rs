@@ -2,10 +2,30 @@ use derive_builder::Builder;
 use merge::Merge;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use strum::Display;
+
+use crate::forge::request::FileUpdateType;
 
 pub const DEFAULT_INCLUDE_AUTHOR: bool = false;
 pub const DEFAULT_AGGREGATE_PRERELEASES: bool = false;
 pub const DEFAULT_INCLUDE_PR_LINK: bool = false;
+pub const DEFAULT_INCLUDE_CONTRIBUTORS: bool = false;
+pub const DEFAULT_STRIP_TRAILERS: bool = true;
+pub const DEFAULT_CHANGELOG_ENABLED: bool = true;
+pub const DEFAULT_CHANGELOG_PATH: &str = "CHANGELOG.md";
+pub const DEFAULT_DEBIAN_DISTRIBUTION: &str = "unstable";
+pub const DEFAULT_DEBIAN_URGENCY: &str = "medium";
+pub const DEFAULT_PACKAGE_REVISION: &str = "1";
+/// Line `rpm` entries are inserted below, the spec file's changelog section
+pub const DEFAULT_RPM_INSERT_AFTER: &str = r"^%changelog[ \t]*$";
+/// Section line appended to a spec file that has no changelog section yet
+pub const RPM_CHANGELOG_SECTION: &str = "%changelog";
+/// Pattern for the forge issue references linked when a changelog
+/// configures no `references` of its own, e.g. `#123`
+pub const DEFAULT_ISSUE_REFERENCE_PATTERN: &str = r"#(\d+)\b";
+/// Comment new changelog entries are inserted below when a changelog has
+/// no `insert_after` regex of its own.
+pub const DEFAULT_INSERT_MARKER: &str = "<!-- releasaurus:insert -->";
 
 /// Default changelog body template.
 pub const DEFAULT_BODY: &str = r#"# [{{ version  }}]{% if tag_compare_link %}({{ tag_compare_link }}){% else %}({{ link }}){% endif %} - {{ timestamp | date(format="%Y-%m-%d") }}
@@ -13,7 +33,7 @@ pub const DEFAULT_BODY: &str = r#"# [{{ version  }}]{% if tag_compare_link %}({{
 ### {{ group | striptags | trim }}
 {% for commit in commits %}
 {% if commit.breaking -%}
-{% if commit.scope %}_({{ commit.scope }})_ {% endif -%}[**breaking**]: {{ commit.title }} [_({{ commit.short_id }})_]({{ commit.link }}){% if include_author %} ({{ commit.author_name }}){% endif %}{% if include_pr_link and commit.pr %} ([PR {{ commit.pr.id }}]({{ commit.pr.link }})){% endif %}
+{% if commit.scope %}_({{ commit.scope }})_ {% endif -%}[**breaking**]: {{ commit.title }} [_({{ commit.short_id }})_]({{ commit.link }}){% if include_author %} ({{ commit.author_name }}{% for co_author in commit.co_authors %}, {{ co_author.name }}{% endfor %}){% endif %}{% if include_pr_link and commit.pr %} ([PR {{ commit.pr.id }}]({{ commit.pr.link }})){% endif %}{% if commit.references %} ({% for reference in commit.references %}[{{ reference.id }}]({{ reference.link }}){% if not loop.last %}, {% endif %}{% endfor %}){% endif %}
 {% if commit.body -%}
 {%- set body_lines = commit.body | split(pat="\n") -%}
 {%- for body_line in body_lines %}
@@ -27,12 +47,70 @@ pub const DEFAULT_BODY: &str = r#"# [{{ version  }}]{% if tag_compare_link %}({{
 {%- endfor %}
 {% endif -%}
 {% else -%}
-- {% if commit.scope %}_({{ commit.scope }})_ {% endif %}{{ commit.title }} [_({{ commit.short_id }})_]({{ commit.link }}){% if include_author %} ({{ commit.author_name }}){% endif %}{% if include_pr_link and commit.pr %} ([PR {{ commit.pr.id }}]({{ commit.pr.link }})){% endif %}
+- {% if commit.scope %}_({{ commit.scope }})_ {% endif %}{{ commit.title }} [_({{ commit.short_id }})_]({{ commit.link }}){% if include_author %} ({{ commit.author_name }}{% for co_author in commit.co_authors %}, {{ co_author.name }}{% endfor %}){% endif %}{% if include_pr_link and commit.pr %} ([PR {{ commit.pr.id }}]({{ commit.pr.link }})){% endif %}{% if commit.references %} ({% for reference in commit.references %}[{{ reference.id }}]({{ reference.link }}){% if not loop.last %}, {% endif %}{% endfor %}){% endif %}
 {% endif -%}
 {% endfor %}
 {% endfor %}
+{% if include_contributors and contributors -%}
+### Contributors
+{% for contributor in contributors %}
+- {% if contributor.username %}@{{ contributor.username }}{% else %}{{ contributor.name }}{% endif %}
+{%- endfor %}
+{% if new_contributors %}
+### New Contributors
+{% for contributor in new_contributors %}
+- {% if contributor.username %}@{{ contributor.username }}{% else %}{{ contributor.name }}{% endif %}
+{%- endfor %}
+{% endif -%}
+{% endif -%}
  "#;
 
+/// Default template for an entry in the aggregate changelog.
+pub const DEFAULT_AGGREGATE_BODY: &str = r#"# {{ timestamp | date(format="%Y-%m-%d") }}
+{% for package in packages %}
+## {% if package.tag_compare_link %}[{{ package.tag_name }}]({{ package.tag_compare_link }}){% else %}{{ package.tag_name }}{% endif %}
+{% for commit in package.commits | filter(attribute="merge_commit", value=false) %}
+- {% if commit.scope %}_({{ commit.scope }})_ {% endif %}{% if commit.breaking %}[**breaking**]: {% endif %}{{ commit.title }} [_({{ commit.short_id }})_]({{ commit.link }})
+{%- endfor %}
+{% endfor %}
+"#;
+
+/// Default entry template for changelogs in the `keep-a-changelog` format.
+/// Commits are filed under Keep a Changelog headings by conventional type.
+pub const DEFAULT_KEEP_A_CHANGELOG_BODY: &str = r#"## [{{ version }}] - {{ timestamp | date(format="%Y-%m-%d") }}
+{% for heading in ["Added", "Changed", "Removed", "Fixed"] %}
+{%- set_global items = [] %}
+{%- for commit in commits | filter(attribute="merge_commit", value=false) %}
+{%- if commit.raw_title is starting_with("feat") %}{% set kind = "Added" %}
+{%- elif commit.raw_title is starting_with("fix") %}{% set kind = "Fixed" %}
+{%- elif commit.raw_title is starting_with("revert") %}{% set kind = "Removed" %}
+{%- else %}{% set kind = "Changed" %}{% endif %}
+{%- if kind == heading %}{% set_global items = items | concat(with=commit) %}{% endif %}
+{%- endfor %}
+{%- if items %}
+### {{ heading }}
+{% for commit in items %}
+- {% if commit.breaking %}**BREAKING** {% endif %}{% if commit.scope %}_({{ commit.scope }})_ {% endif %}{{ commit.title }} ([{{ commit.short_id }}]({{ commit.link }})){% if include_author %} ({{ commit.author_name }}{% for co_author in commit.co_authors %}, {{ co_author.name }}{% endfor %}){% endif %}{% if include_pr_link and commit.pr %} ([PR {{ commit.pr.id }}]({{ commit.pr.link }})){% endif %}
+{%- endfor %}
+{% endif %}
+{%- endfor %}
+"#;
+
+/// Default entry template for `debian` changelog files, i.e.
+/// `debian/changelog`.
+pub const DEFAULT_DEBIAN_BODY: &str = r#"{{ packaging.package }} ({{ packaging.version }}) {{ packaging.distribution }}; urgency={{ packaging.urgency }}
+
+{% for commit in commits | filter(attribute="merge_commit", value=false) %}  * {% if commit.breaking %}BREAKING: {% endif %}{% if commit.scope %}{{ commit.scope }}: {% endif %}{{ commit.title }}
+{% endfor %}
+ -- {{ packaging.maintainer }}  {{ timestamp | date(format="%a, %d %b %Y %H:%M:%S +0000") }}
+"#;
+
+/// Default entry template for `rpm` changelog files, i.e. the `%changelog`
+/// section of a spec file.
+pub const DEFAULT_RPM_BODY: &str = r#"* {{ timestamp | date(format="%a %b %d %Y") }} {{ packaging.maintainer }} - {{ packaging.version }}
+{% for commit in commits | filter(attribute="merge_commit", value=false) %}- {% if commit.breaking %}BREAKING: {% endif %}{% if commit.scope %}{{ commit.scope }}: {% endif %}{{ commit.title }}
+{% endfor %}"#;
+
 fn default_body() -> String {
     DEFAULT_BODY.into()
 }
@@ -49,6 +127,179 @@ fn default_include_pr_link() -> bool {
     DEFAULT_INCLUDE_PR_LINK
 }
 
+fn default_include_contributors() -> bool {
+    DEFAULT_INCLUDE_CONTRIBUTORS
+}
+
+fn default_strip_trailers() -> bool {
+    DEFAULT_STRIP_TRAILERS
+}
+
+fn default_changelog_enabled() -> bool {
+    DEFAULT_CHANGELOG_ENABLED
+}
+
+fn default_changelog_path() -> String {
+    DEFAULT_CHANGELOG_PATH.into()
+}
+
+fn default_changelog_format() -> ChangelogFormat {
+    ChangelogFormat::default()
+}
+
+/// How entries are written to a changelog file
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    PartialEq,
+    Eq,
+    Display,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum ChangelogFormat {
+    /// Each entry is inserted below the `insert_after` line
+    #[default]
+    Markdown,
+    /// Follows keepachangelog.com: the hand-written `## [Unreleased]`
+    /// section is merged into each new version section, and compare links
+    /// are kept at the bottom of the file
+    KeepAChangelog,
+    /// Debian `debian/changelog` entries, configured by `packaging`
+    Debian,
+    /// Entries for the `%changelog` section of an RPM spec file, configured
+    /// by `packaging`
+    Rpm,
+}
+
+impl ChangelogFormat {
+    /// Built-in entry template, if the format has its own.
+    pub fn default_body(self) -> Option<&'static str> {
+        match self {
+            Self::Markdown => None,
+            Self::KeepAChangelog => Some(DEFAULT_KEEP_A_CHANGELOG_BODY),
+            Self::Debian => Some(DEFAULT_DEBIAN_BODY),
+            Self::Rpm => Some(DEFAULT_RPM_BODY),
+        }
+    }
+
+    /// How an entry is inserted below the `insert_after` line. A spec file
+    /// missing that line gets a changelog section appended rather than an
+    /// entry above its preamble.
+    pub fn insert_update(self, insert_after: &str) -> FileUpdateType {
+        match self {
+            Self::Rpm => FileUpdateType::InsertInSection {
+                marker: insert_after.into(),
+                section: RPM_CHANGELOG_SECTION.into(),
+            },
+            _ => FileUpdateType::InsertAfter(insert_after.into()),
+        }
+    }
+
+    /// Whether entries carry the `packaging` settings.
+    pub fn is_packaging(self) -> bool {
+        matches!(self, Self::Debian | Self::Rpm)
+    }
+}
+
+/// Package metadata for `debian` and `rpm` changelog files
+#[derive(
+    Debug, Clone, Default, Serialize, Deserialize, JsonSchema, Builder, Merge,
+)]
+#[builder(setter(into, strip_option), default)]
+#[serde(default, deny_unknown_fields)]
+pub struct PackagingConfig {
+    /// Source package name. Defaults to the package name
+    #[merge(strategy = merge::option::overwrite_none)]
+    pub package: Option<String>,
+    /// Maintainer in the trailer of each entry, e.g.
+    /// "Jane Doe <jane@example.com>". Required by both formats
+    #[merge(strategy = merge::option::overwrite_none)]
+    pub maintainer: Option<String>,
+    /// Debian distribution the release is uploaded to
+    #[merge(strategy = merge::option::overwrite_none)]
+    #[schemars(default = "default_debian_distribution")]
+    pub distribution: Option<String>,
+    /// Debian upload urgency
+    #[merge(strategy = merge::option::overwrite_none)]
+    #[schemars(default = "default_debian_urgency")]
+    pub urgency: Option<String>,
+    /// Debian revision or RPM release appended to the version
+    #[merge(strategy = merge::option::overwrite_none)]
+    #[schemars(default = "default_package_revision")]
+    pub revision: Option<String>,
+}
+
+fn default_debian_distribution() -> String {
+    DEFAULT_DEBIAN_DISTRIBUTION.into()
+}
+
+fn default_debian_urgency() -> String {
+    DEFAULT_DEBIAN_URGENCY.into()
+}
+
+fn default_package_revision() -> String {
+    DEFAULT_PACKAGE_REVISION.into()
+}
+
+/// Turns issue or ticket references in commit messages into links
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Builder)]
+#[builder(setter(into))]
+#[serde(deny_unknown_fields)]
+pub struct ReferencePattern {
+    /// Regex matching a reference, e.g. "PLAT-\\d+" for Jira keys
+    pub pattern: String,
+    /// URL the reference links to. Capture groups from `pattern` are
+    /// substituted for `$0` (the whole match), `$1`, or `${name}`, e.g.
+    /// "https://jira.example.com/browse/$0"
+    pub url: String,
+}
+
+/// A file the changelog is written to, rendered with its own template
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Builder)]
+#[builder(setter(into, strip_option))]
+#[serde(deny_unknown_fields)]
+pub struct ChangelogOutput {
+    /// Path of the file, relative to the package path
+    pub path: String,
+    /// Tera template for this file's entries. Defaults to the changelog
+    /// body, i.e. the release notes
+    #[builder(default)]
+    pub body: Option<String>,
+    /// Regex matching the line this file's entries are inserted below.
+    /// Defaults to the changelog's `insert_after`
+    #[builder(default)]
+    pub insert_after: Option<String>,
+    /// Format of this file. Defaults to the changelog's `format`
+    #[builder(default)]
+    pub format: Option<ChangelogFormat>,
+}
+
+/// A changelog at the repository root with one entry per release PR,
+/// covering every package the PR releases
+#[derive(
+    Debug, Clone, Default, Serialize, Deserialize, JsonSchema, Builder,
+)]
+#[builder(setter(into, strip_option), default)]
+#[serde(default, deny_unknown_fields)]
+pub struct AggregateChangelogConfig {
+    /// Path of the file, relative to the repository root
+    #[schemars(default = "default_changelog_path")]
+    pub path: Option<String>,
+    /// Tera template for each entry. Has `timestamp` and `packages`, each
+    /// with `name`, `tag_name`, `version`, `notes`, `tag_compare_link` and
+    /// `commits`, in its context
+    pub body: Option<String>,
+    /// Regex matching the line new entries are inserted below. Defaults to
+    /// a `<!-- releasaurus:insert -->` comment
+    pub insert_after: Option<String>,
+}
+
 /// Changelog configuration (applies to all packages)
 #[derive(
     Debug, Clone, Default, Serialize, Deserialize, JsonSchema, Builder, Merge,
@@ -68,8 +319,52 @@ pub struct ChangelogConfig {
     #[merge(strategy = merge::option::overwrite_none)]
     #[schemars(default = "default_include_pr_link")]
     pub include_pr_link: Option<bool>,
+    /// Includes a section thanking the release's contributors in the default
+    /// body template, and looks up which of them are contributing for the
+    /// first time
+    #[merge(strategy = merge::option::overwrite_none)]
+    #[schemars(default = "default_include_contributors")]
+    pub include_contributors: Option<bool>,
+    /// Removes git trailers, e.g. "Signed-off-by: ...", from commit bodies.
+    /// They remain available to templates as each commit's `trailers`
+    #[merge(strategy = merge::option::overwrite_none)]
+    #[schemars(default = "default_strip_trailers")]
+    pub strip_trailers: Option<bool>,
     /// Aggregates changelogs from prior prereleases when graduating
     #[merge(strategy = merge::option::overwrite_none)]
     #[schemars(default = "default_aggregate_prereleases")]
     pub aggregate_prereleases: Option<bool>,
+    /// Writes the changelog to files in the release PR. When disabled,
+    /// release notes are only published to the forge
+    #[merge(strategy = merge::option::overwrite_none)]
+    #[schemars(default = "default_changelog_enabled")]
+    pub enabled: Option<bool>,
+    /// Path of the changelog file, relative to the package path
+    #[merge(strategy = merge::option::overwrite_none)]
+    #[schemars(default = "default_changelog_path")]
+    pub path: Option<String>,
+    /// Regex matching the line new entries are inserted below, e.g.
+    /// "^# Changelog$". Defaults to a `<!-- releasaurus:insert -->` comment.
+    /// Entries are prepended to files without a matching line
+    #[merge(strategy = merge::option::overwrite_none)]
+    pub insert_after: Option<String>,
+    /// Format of the changelog file. `keep-a-changelog`, `debian` and `rpm`
+    /// each switch the default body to a template of their own
+    #[merge(strategy = merge::option::overwrite_none)]
+    #[schemars(default = "default_changelog_format")]
+    pub format: Option<ChangelogFormat>,
+    /// Package metadata for `debian` and `rpm` files
+    #[merge(strategy = merge::option::recurse)]
+    pub packaging: Option<PackagingConfig>,
+    /// Issue or ticket references in commit messages to link, available to
+    /// templates as each commit's `references`. Defaults to `#123` issue
+    /// references on the forge; an empty list links nothing
+    #[merge(strategy = merge::option::overwrite_none)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub references: Option<Vec<ReferencePattern>>,
+    /// Files to write the changelog to, each with its own template. When
+    /// set, these replace the single file at `path`
+    #[merge(strategy = merge::option::overwrite_none)]
+    #[serde(rename = "output", skip_serializing_if = "Option::is_none")]
+    pub outputs: Option<Vec<ChangelogOutput>>,
 }
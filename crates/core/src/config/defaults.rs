@@ -1,3 +1,5 @@
+use indexmap::map::Entry;
+use merge::Merge;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -43,20 +45,61 @@ fn default_versioning() -> VersioningConfig {
     }
 }
 
+/// Like `merge::option::recurse`, but also carries over the fields that
+/// [`VersioningConfig`] leaves out of its own merge, so prerelease settings
+/// and named parsers are inherited from a base config as well.
+fn merge_versioning(
+    left: &mut Option<VersioningConfig>,
+    right: Option<VersioningConfig>,
+) {
+    let Some(right) = right else {
+        return;
+    };
+
+    let Some(left) = left.as_mut() else {
+        *left = Some(right);
+        return;
+    };
+
+    merge::option::overwrite_none(
+        &mut left.prerelease,
+        right.prerelease.clone(),
+    );
+
+    match (left.named_parsers.as_mut(), right.named_parsers.clone()) {
+        (Some(parsers), Some(base)) => {
+            for (group, parser) in base {
+                match parsers.entry(group) {
+                    Entry::Occupied(mut entry) => entry.get_mut().merge(parser),
+                    Entry::Vacant(entry) => {
+                        entry.insert(parser);
+                    }
+                }
+            }
+        }
+        (None, base) => left.named_parsers = base,
+        (Some(_), None) => {}
+    }
+
+    left.merge(right);
+}
+
 /// Default configuration applied to every package
-#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema, Merge)]
 #[serde(default, deny_unknown_fields)] // Use default for missing fields
 pub struct DefaultsConfig {
     /// Tera template for generating release commit messages when
     /// repository.separate_pull_requests=false and multiple packages
     /// configured. Has the following variables available in the template
     /// context: branch, repo_name
+    #[merge(strategy = merge::option::overwrite_none)]
     #[schemars(default = "default_monorepo_commit_and_pr_title")]
     pub monorepo_commit_message_template: Option<String>,
     /// Tera template for generating release PR titles when
     /// repository.separate_pull_requests=false and multiple packages
     /// configured. Has the following variables available in the template
     /// context: branch, repo_name
+    #[merge(strategy = merge::option::overwrite_none)]
     #[schemars(default = "default_monorepo_commit_and_pr_title")]
     pub monorepo_pr_title_template: Option<String>,
     /// Tera template for generating release commit messages. When
@@ -64,6 +107,7 @@ pub struct DefaultsConfig {
     /// this template will be used for each individual PR commit but can be
     /// overridden at the package level. Has the following variables available
     /// in the template context: branch, repo_name, package_name, tag, semver
+    #[merge(strategy = merge::option::overwrite_none)]
     #[schemars(default = "default_commit_and_pr_title")]
     pub commit_message_template: Option<String>,
     /// Tera template for generating release PR titles. When
@@ -71,12 +115,15 @@ pub struct DefaultsConfig {
     /// this template will be used for each individual PR title but can be
     /// overridden at the package level. Has the following variables available
     /// in the template context: branch, repo_name, package_name, tag, semver
+    #[merge(strategy = merge::option::overwrite_none)]
     #[schemars(default = "default_commit_and_pr_title")]
     pub pr_title_template: Option<String>,
     /// Default versioning config. Packages can override this configuration
+    #[merge(strategy = merge_versioning)]
     #[schemars(default = "default_versioning")]
     pub versioning: Option<VersioningConfig>,
     /// Default changelog generation settings applied to all packages.
     /// Packages can override this configuration
+    #[merge(strategy = merge::option::recurse)]
     pub changelog: Option<ChangelogConfig>,
 }
@@ -0,0 +1,581 @@
+//! Locating and parsing the Releasaurus configuration in a repository.
+//!
+//! Every forge loads its configuration through [`load_repository_config`],
+//! so the lookup order in [`CONFIG_LOCATIONS`] is the same everywhere. Base
+//! configs named in [`Config::extends`] are loaded the same way and merged
+//! beneath the repository's own settings.
+
+use merge::Merge;
+use serde::Deserialize;
+use std::{future::Future, pin::Pin};
+
+use crate::{
+    config::{Config, DEFAULT_CONFIG_FILE},
+    forge::{request::GetFileContentRequest, traits::Forge},
+    result::{ReleasaurusError, Result},
+};
+
+/// How a configuration file stores its settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFileFormat {
+    /// A dedicated TOML file
+    Toml,
+    /// A dedicated YAML file
+    Yaml,
+    /// A dedicated JSON file
+    Json,
+    /// The `[tool.releasaurus]` table of a pyproject.toml
+    Pyproject,
+    /// The `[workspace.metadata.releasaurus]` table of a Cargo.toml
+    CargoMetadata,
+}
+
+/// Locations searched, in order, when no explicit config path is given.
+/// The first one holding a configuration wins.
+pub const CONFIG_LOCATIONS: &[(&str, ConfigFileFormat)] = &[
+    (DEFAULT_CONFIG_FILE, ConfigFileFormat::Toml),
+    (".releasaurus.toml", ConfigFileFormat::Toml),
+    (".github/releasaurus.toml", ConfigFileFormat::Toml),
+    ("releasaurus.yaml", ConfigFileFormat::Yaml),
+    ("releasaurus.yml", ConfigFileFormat::Yaml),
+    ("releasaurus.json", ConfigFileFormat::Json),
+    ("pyproject.toml", ConfigFileFormat::Pyproject),
+    ("Cargo.toml", ConfigFileFormat::CargoMetadata),
+];
+
+impl ConfigFileFormat {
+    /// Format of an explicitly configured path, chosen by its file name.
+    pub fn from_path(path: &str) -> Self {
+        let file_name = path.rsplit('/').next().unwrap_or(path);
+
+        match file_name {
+            "pyproject.toml" => Self::Pyproject,
+            "Cargo.toml" => Self::CargoMetadata,
+            name if name.ends_with(".yaml") || name.ends_with(".yml") => {
+                Self::Yaml
+            }
+            name if name.ends_with(".json") => Self::Json,
+            _ => Self::Toml,
+        }
+    }
+
+    /// Parses `content`, returning `None` when a manifest that can embed a
+    /// configuration does not contain one.
+    pub fn parse(self, content: &str) -> Result<Option<Config>> {
+        let config = match self {
+            Self::Toml => Some(toml::from_str(content)?),
+            Self::Yaml => Some(serde_norway::from_str(content)?),
+            Self::Json => Some(serde_json::from_str(content)?),
+            Self::Pyproject => embedded(content, &["tool", "releasaurus"])?,
+            Self::CargoMetadata => {
+                embedded(content, &["workspace", "metadata", "releasaurus"])?
+            }
+        };
+
+        Ok(config)
+    }
+}
+
+/// Deserializes the table at `keys` inside a TOML manifest, if present.
+fn embedded(content: &str, keys: &[&str]) -> Result<Option<Config>> {
+    let manifest = content.parse::<toml::Table>()?;
+    let mut value =
+        keys.first().and_then(|first| manifest.get(*first)).cloned();
+
+    for key in keys.iter().skip(1) {
+        value = value.and_then(|v| v.get(*key).cloned());
+    }
+
+    value
+        .map(|v| Config::deserialize(v).map_err(ReleasaurusError::from))
+        .transpose()
+}
+
+/// Loads the repository configuration through `forge`.
+///
+/// An explicit `config_path` must exist and is parsed according to its file
+/// name. Otherwise [`CONFIG_LOCATIONS`] are tried in order, falling back to
+/// [`Config::default`] when none holds a configuration.
+pub async fn load_repository_config<F: Forge + ?Sized>(
+    forge: &F,
+    branch: Option<String>,
+    config_path: Option<String>,
+) -> Result<Config> {
+    let config = find_config(forge, branch.clone(), config_path).await?;
+
+    extend_config(forge, branch, config).await
+}
+
+async fn find_config<F: Forge + ?Sized>(
+    forge: &F,
+    branch: Option<String>,
+    config_path: Option<String>,
+) -> Result<Config> {
+    if let Some(path) = config_path {
+        let content = forge
+            .get_file_content(GetFileContentRequest {
+                branch,
+                path: path.clone(),
+            })
+            .await?
+            .ok_or_else(|| {
+                ReleasaurusError::invalid_config(format!(
+                    "configuration file not found at: {path}"
+                ))
+            })?;
+
+        return ConfigFileFormat::from_path(&path)
+            .parse(&content)?
+            .ok_or_else(|| {
+                ReleasaurusError::invalid_config(format!(
+                    "no releasaurus configuration found in: {path}"
+                ))
+            });
+    }
+
+    for (path, format) in CONFIG_LOCATIONS {
+        let Some(content) = forge
+            .get_file_content(GetFileContentRequest {
+                branch: branch.clone(),
+                path: path.to_string(),
+            })
+            .await?
+        else {
+            continue;
+        };
+
+        if let Some(config) = format.parse(&content)? {
+            log::info!("loaded configuration from: {path}");
+            return Ok(config);
+        }
+    }
+
+    log::info!("repository configuration not found: using default");
+    Ok(Config::default())
+}
+
+/// A base configuration named in `extends`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BaseConfig {
+    /// `owner/name` of another repository, or None for this one
+    repo: Option<String>,
+    path: String,
+}
+
+impl BaseConfig {
+    fn parse(entry: &str) -> Result<Self> {
+        let base = match entry.split_once(':') {
+            Some((repo, path)) => Self {
+                repo: Some(repo.trim_matches('/').to_string()),
+                path: path.trim_start_matches('/').to_string(),
+            },
+            None => Self {
+                repo: None,
+                path: entry.trim_start_matches('/').to_string(),
+            },
+        };
+
+        if base.path.is_empty() || base.repo.as_deref() == Some("") {
+            return Err(ReleasaurusError::invalid_config(format!(
+                "extends: invalid entry \"{entry}\": expected a path or \
+                 owner/repo:path"
+            )));
+        }
+
+        Ok(base)
+    }
+}
+
+impl std::fmt::Display for BaseConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.repo.as_ref() {
+            Some(repo) => write!(f, "{repo}:{}", self.path),
+            None => write!(f, "{}", self.path),
+        }
+    }
+}
+
+/// Merges every base in `config.extends` beneath `config`. Each base is
+/// itself extended first, so its own bases sit directly beneath it; only a
+/// base that extends itself, directly or indirectly, is an error.
+async fn extend_config<F: Forge + ?Sized>(
+    forge: &F,
+    branch: Option<String>,
+    config: Config,
+) -> Result<Config> {
+    extend_with_chain(forge, branch, config, None, &mut vec![]).await
+}
+
+fn extend_with_chain<'a, F: Forge + ?Sized>(
+    forge: &'a F,
+    branch: Option<String>,
+    mut config: Config,
+    declared_in: Option<String>,
+    chain: &'a mut Vec<BaseConfig>,
+) -> Pin<Box<dyn Future<Output = Result<Config>> + Send + 'a>> {
+    Box::pin(async move {
+        // later entries win, so the last one is merged first
+        for entry in config.extends.clone().iter().rev() {
+            let mut base = BaseConfig::parse(entry)?;
+
+            // relative entries in a remote base stay in that repository
+            if base.repo.is_none() {
+                base.repo = declared_in.clone();
+            }
+
+            if chain.contains(&base) {
+                return Err(ReleasaurusError::invalid_config(format!(
+                    "extends: {base} extends itself"
+                )));
+            }
+
+            let loaded = load_base(forge, branch.clone(), &base).await?;
+            log::info!("extending configuration from: {base}");
+
+            chain.push(base.clone());
+            let loaded = extend_with_chain(
+                forge,
+                branch.clone(),
+                loaded,
+                base.repo.clone(),
+                chain,
+            )
+            .await?;
+            chain.pop();
+
+            merge_base(&mut config, loaded);
+        }
+
+        Ok(config)
+    })
+}
+
+async fn load_base<F: Forge + ?Sized>(
+    forge: &F,
+    branch: Option<String>,
+    base: &BaseConfig,
+) -> Result<Config> {
+    let req = GetFileContentRequest {
+        path: base.path.clone(),
+        branch: base.repo.is_none().then_some(branch).flatten(),
+    };
+
+    let content = match base.repo.as_ref() {
+        Some(repo) => forge.get_external_file_content(repo, req).await?,
+        None => forge.get_file_content(req).await?,
+    }
+    .ok_or_else(|| {
+        ReleasaurusError::invalid_config(format!(
+            "extends: base configuration not found: {base}"
+        ))
+    })?;
+
+    ConfigFileFormat::from_path(&base.path)
+        .parse(&content)?
+        .ok_or_else(|| {
+            ReleasaurusError::invalid_config(format!(
+                "extends: no releasaurus configuration found in: {base}"
+            ))
+        })
+}
+
+/// Fills in everything `config` leaves unset from `base`. Packages are
+/// specific to each repository and are never inherited.
+fn merge_base(config: &mut Config, base: Config) {
+    config.repository.merge(base.repository);
+    config.defaults.merge(base.defaults);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::forge::traits::MockForge;
+
+    use super::*;
+
+    /// Files in other repositories are keyed as `owner/repo:path`.
+    fn forge(files: HashMap<&'static str, &'static str>) -> MockForge {
+        let mut mock = MockForge::new();
+        let external = files.clone();
+        mock.expect_get_file_content().returning(move |req| {
+            Ok(files.get(req.path.as_str()).map(|c| c.to_string()))
+        });
+        mock.expect_get_external_file_content()
+            .returning(move |repo, req| {
+                let key = format!("{repo}:{}", req.path);
+                Ok(external.get(key.as_str()).map(|c| c.to_string()))
+            });
+        mock
+    }
+
+    #[tokio::test]
+    async fn prefers_locations_in_lookup_order() {
+        let forge = forge(HashMap::from([
+            (
+                ".github/releasaurus.toml",
+                "[repository]\ntag_search_depth = 1",
+            ),
+            ("releasaurus.yaml", "repository:\n  tag_search_depth: 2\n"),
+        ]));
+
+        let config = load_repository_config(&forge, None, None).await.unwrap();
+
+        assert_eq!(config.repository.tag_search_depth(), 1);
+    }
+
+    #[tokio::test]
+    async fn reads_yaml_and_json_files() {
+        let yaml = forge(HashMap::from([(
+            "releasaurus.yml",
+            "package:\n  - path: api\n    tag_prefix: api-v\n",
+        )]));
+        let json = forge(HashMap::from([(
+            "releasaurus.json",
+            r#"{"package": [{"path": "web", "release_type": "node"}]}"#,
+        )]));
+
+        let config = load_repository_config(&yaml, None, None).await.unwrap();
+        assert_eq!(config.packages[0].tag_prefix.as_deref(), Some("api-v"));
+
+        let config = load_repository_config(&json, None, None).await.unwrap();
+        assert_eq!(config.packages[0].path, "web");
+    }
+
+    #[tokio::test]
+    async fn skips_manifests_without_an_embedded_config() {
+        let forge = forge(HashMap::from([
+            ("pyproject.toml", "[project]\nname = \"lib\"\n"),
+            (
+                "Cargo.toml",
+                "[workspace]\nmembers = []\n\n\
+                 [[workspace.metadata.releasaurus.package]]\n\
+                 path = \"crates/core\"\n",
+            ),
+        ]));
+
+        let config = load_repository_config(&forge, None, None).await.unwrap();
+
+        assert_eq!(config.packages[0].path, "crates/core");
+    }
+
+    #[tokio::test]
+    async fn reads_pyproject_tool_table() {
+        let forge = forge(HashMap::from([(
+            "pyproject.toml",
+            "[project]\nname = \"lib\"\n\n\
+             [[tool.releasaurus.package]]\n\
+             release_type = \"python\"\n",
+        )]));
+
+        let config = load_repository_config(&forge, None, None).await.unwrap();
+
+        assert_eq!(
+            config.packages[0].release_type.map(|r| r.to_string()),
+            Some("python".into())
+        );
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_default_config() {
+        let forge = forge(HashMap::new());
+
+        let config = load_repository_config(&forge, None, None).await.unwrap();
+
+        assert_eq!(config.packages.len(), 1);
+        assert_eq!(config.packages[0].path, ".");
+    }
+
+    #[tokio::test]
+    async fn explicit_path_uses_its_extension_and_must_exist() {
+        let forge = forge(HashMap::from([(
+            "ci/release.yaml",
+            "repository:\n  tag_search_depth: 7\n",
+        )]));
+
+        let config = load_repository_config(
+            &forge,
+            None,
+            Some("ci/release.yaml".into()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(config.repository.tag_search_depth(), 7);
+
+        let err =
+            load_repository_config(&forge, None, Some("missing.json".into()))
+                .await
+                .unwrap_err();
+        assert!(matches!(err, ReleasaurusError::InvalidConfig(_)));
+    }
+
+    #[tokio::test]
+    async fn extends_merges_bases_beneath_own_settings() {
+        let forge = forge(HashMap::from([
+            (
+                "releasaurus.toml",
+                "extends = [\"config/base.toml\"]\n\n\
+                 [defaults]\npr_title_template = \"own\"\n\n\
+                 [[package]]\npath = \"api\"\n",
+            ),
+            (
+                "config/base.toml",
+                "[repository]\ntag_search_depth = 5\n\n\
+                 [defaults]\npr_title_template = \"base\"\n\
+                 commit_message_template = \"base commit\"\n\n\
+                 [defaults.changelog]\ninclude_author = true\n\n\
+                 [[package]]\npath = \"base-only\"\n",
+            ),
+        ]));
+
+        let config = load_repository_config(&forge, None, None).await.unwrap();
+
+        assert_eq!(config.repository.tag_search_depth(), 5);
+        assert_eq!(config.defaults.pr_title_template.as_deref(), Some("own"));
+        assert_eq!(
+            config.defaults.commit_message_template.as_deref(),
+            Some("base commit")
+        );
+        assert_eq!(
+            config.defaults.changelog.unwrap().include_author,
+            Some(true)
+        );
+        assert_eq!(config.packages.len(), 1);
+        assert_eq!(config.packages[0].path, "api");
+    }
+
+    #[tokio::test]
+    async fn extends_keeps_own_values_equal_to_the_defaults() {
+        let forge = forge(HashMap::from([
+            (
+                "releasaurus.toml",
+                "extends = [\"base.toml\"]\n\n\
+                 [repository]\nseparate_pull_requests = false\n\
+                 cascade = false\ntag_search_depth = 100\n\
+                 first_release_search_depth = 400\n",
+            ),
+            (
+                "base.toml",
+                "[repository]\nseparate_pull_requests = true\n\
+                 cascade = true\ntag_search_depth = 5\n\
+                 first_release_search_depth = 50\n",
+            ),
+        ]));
+
+        let config = load_repository_config(&forge, None, None).await.unwrap();
+
+        assert!(!config.repository.separate_pull_requests());
+        assert!(!config.repository.cascade());
+        assert_eq!(config.repository.tag_search_depth(), 100);
+        assert_eq!(config.repository.first_release_search_depth(), 400);
+    }
+
+    #[tokio::test]
+    async fn extends_never_inherits_commit_shas() {
+        let forge = forge(HashMap::from([
+            (
+                "releasaurus.toml",
+                "extends = [\"base.toml\"]\n\n\
+                 [repository]\nskip_shas = [\"own123\"]\n",
+            ),
+            (
+                "base.toml",
+                "[repository]\nskip_shas = [\"base456\"]\n\
+                 cascade = true\n\n\
+                 [[repository.reword]]\nsha = \"base789\"\n\
+                 message = \"fix: reworded\"\n",
+            ),
+        ]));
+
+        let config = load_repository_config(&forge, None, None).await.unwrap();
+
+        assert_eq!(config.repository.skip_shas, vec!["own123"]);
+        assert!(config.repository.reword.is_empty());
+        assert!(config.repository.cascade());
+    }
+
+    #[tokio::test]
+    async fn extends_later_bases_win_and_nested_bases_sit_beneath_parent() {
+        let forge = forge(HashMap::from([
+            (
+                "releasaurus.toml",
+                "extends = [\"first.toml\", \"second.toml\"]\n",
+            ),
+            (
+                "first.toml",
+                "[defaults]\npr_title_template = \"first\"\n\
+                 commit_message_template = \"first\"\n",
+            ),
+            (
+                "second.toml",
+                "extends = [\"nested.yaml\"]\n\n\
+                 [defaults]\npr_title_template = \"second\"\n",
+            ),
+            (
+                "nested.yaml",
+                "defaults:\n  commit_message_template: nested\n",
+            ),
+        ]));
+
+        let config = load_repository_config(&forge, None, None).await.unwrap();
+
+        assert_eq!(
+            config.defaults.pr_title_template.as_deref(),
+            Some("second")
+        );
+        assert_eq!(
+            config.defaults.commit_message_template.as_deref(),
+            Some("nested")
+        );
+    }
+
+    #[tokio::test]
+    async fn extends_reads_bases_from_other_repositories() {
+        let forge = forge(HashMap::from([
+            (
+                "releasaurus.toml",
+                "extends = [\"acme/release-config:rust.toml\"]\n",
+            ),
+            (
+                "acme/release-config:rust.toml",
+                "extends = [\"common.toml\"]\n\n\
+                 [defaults]\npr_title_template = \"rust\"\n",
+            ),
+            (
+                "acme/release-config:common.toml",
+                "[defaults.versioning]\nskip_merge_commits = false\n",
+            ),
+        ]));
+
+        let config = load_repository_config(&forge, None, None).await.unwrap();
+
+        assert_eq!(config.defaults.pr_title_template.as_deref(), Some("rust"));
+        assert_eq!(
+            config.defaults.versioning.unwrap().skip_merge_commits,
+            Some(false)
+        );
+    }
+
+    #[tokio::test]
+    async fn extends_rejects_cycles_and_missing_bases() {
+        let cyclic = forge(HashMap::from([
+            ("releasaurus.toml", "extends = [\"a.toml\"]\n"),
+            ("a.toml", "extends = [\"b.toml\"]\n"),
+            ("b.toml", "extends = [\"a.toml\"]\n"),
+        ]));
+        let missing = forge(HashMap::from([(
+            "releasaurus.toml",
+            "extends = [\"acme/config:gone.toml\"]\n",
+        )]));
+
+        let err = load_repository_config(&cyclic, None, None)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("a.toml extends itself"));
+
+        let err = load_repository_config(&missing, None, None)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("acme/config:gone.toml"));
+    }
+}
@@ -25,6 +25,11 @@ pub struct PackageOverrides {
     pub prerelease_strategy: Option<PrereleaseStrategy>,
     #[merge(strategy = merge::option::overwrite_none)]
     pub version_type: Option<VersionType>,
+    /// Version to release the package at instead of the computed one. Meant
+    /// for a single run: once the package is tagged at or past it, the
+    /// override is ignored
+    #[merge(strategy = merge::option::overwrite_none)]
+    pub release_as: Option<String>,
 }
 
 /// Runtime overrides that apply to all packages.
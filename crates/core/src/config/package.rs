@@ -124,18 +124,42 @@ pub struct PackageConfig {
     /// Path to the workspace root directory for this package relative to the
     /// repository root
     pub workspace_root: String,
-    /// Path to package directory relative to workspace_root
+    /// Path to package directory relative to workspace_root. May be a glob
+    /// (e.g. "crates/*") matching every directory that contains a manifest
+    /// for the release_type, in which case this entry expands into one
+    /// package per match, named after each manifest's canonical name
     pub path: String,
+    /// Glob of manifest files relative to workspace_root (e.g.
+    /// "packages/**/package.json"). Expands this entry into one package per
+    /// matched manifest, rooted at the manifest's directory and named after
+    /// its canonical name
+    pub packages: Option<String>,
     /// [`ReleaseType`] type for determining which version files to update
     pub release_type: Option<ReleaseType>,
-    /// Git tag prefix for this package (e.g., "v" or "api-v")
+    /// Git tag prefix for this package (e.g., "v" or "api-v"). For glob
+    /// entries this is a Tera template rendered per package with `name`
+    /// available, e.g. "{{ name }}-v"
     pub tag_prefix: Option<String>,
     /// Groups sub-packages under a single release. Each will share changelog,
     /// tag, and release, but will receive independent manifest version updates
     /// according to their type
     pub sub_packages: Option<Vec<SubPackage>>,
-    /// Additional directory paths to include commits from
+    /// Additional paths to include commits from, relative to the repository
+    /// root. Plain entries match a directory or file and everything under
+    /// it; entries containing `*`, `?`, `[` or `{` are globs (e.g.
+    /// "proto/**/*.proto")
     pub additional_paths: Option<Vec<String>>,
+    /// Paths whose changes never count towards this package, relative to the
+    /// repository root, e.g. "**/*.md" or "packages/api/tests". A commit is
+    /// only included if at least one of its files is inside the package or
+    /// its additional paths and not excluded. Accepts plain paths and globs
+    /// like `additional_paths`
+    pub exclude_paths: Option<Vec<String>>,
+    /// Conventional commit scopes that belong to this package, e.g. "api".
+    /// A commit with one of these scopes is attributed to the package even
+    /// if it touches none of the package's paths. See
+    /// `repository.scope_routing` to also keep it from other packages
+    pub scopes: Option<Vec<String>>,
     /// Additional paths to generic version manifest files to update. Paths must
     /// be relative to the package path. Accepts either simple string paths or
     /// full config objects with custom regex patterns.
@@ -169,10 +193,13 @@ impl Default for PackageConfig {
             name: "".into(),
             path: ".".into(),
             workspace_root: ".".into(),
+            packages: None,
             sub_packages: None,
             release_type: None,
             tag_prefix: None,
             additional_paths: None,
+            exclude_paths: None,
+            scopes: None,
             additional_manifest_files: None,
             changelog: None,
             versioning: None,
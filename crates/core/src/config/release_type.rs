@@ -25,6 +25,9 @@ pub enum ReleaseType {
     Php,
     Ruby,
     Java,
+    /// Detect the release type from the manifests found in the package
+    /// directory
+    Auto,
 }
 
 impl Display for ReleaseType {
@@ -38,6 +41,7 @@ impl Display for ReleaseType {
             ReleaseType::Python => f.write_str("python"),
             ReleaseType::Ruby => f.write_str("ruby"),
             ReleaseType::Rust => f.write_str("rust"),
+            ReleaseType::Auto => f.write_str("auto"),
         }
     }
 }
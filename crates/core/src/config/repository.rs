@@ -1,8 +1,13 @@
 use derive_builder::Builder;
+use merge::Merge;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use strum::Display;
 
-use crate::result::{ReleasaurusError, Result};
+use crate::{
+    config::changelog::AggregateChangelogConfig,
+    result::{ReleasaurusError, Result},
+};
 
 /// Default number of commits to search when processing first release
 pub const DEFAULT_COMMIT_SEARCH_DEPTH: usize = 400;
@@ -23,46 +28,108 @@ pub struct RewordedCommit {
     pub message: String,
 }
 
+/// How a commit's conventional scope routes it to packages with matching
+/// `scopes`
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    PartialEq,
+    Eq,
+    Display,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum ScopeRouting {
+    /// A matching scope attributes the commit to the package in addition to
+    /// any packages whose paths it touches
+    #[default]
+    Attribute,
+    /// A matching scope attributes the commit to the packages naming it and
+    /// to no others, even if the commit touches their paths
+    Restrict,
+}
+
 /// Repository configuration (applies to all packages)
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Builder)]
+#[derive(
+    Debug, Clone, Default, Serialize, Deserialize, JsonSchema, Builder, Merge,
+)]
 #[builder(setter(into, strip_option), default)]
 #[serde(default, deny_unknown_fields)] // Use default for missing fields
 pub struct RepositoryConfig {
     /// The base branch to target for release PRs, tagging, and releases
     /// defaults to default_branch for repository
+    #[merge(strategy = merge::option::overwrite_none)]
     pub base_branch: Option<String>,
     /// Maximum number of commits to search for the first release when no
-    /// tags exist
-    pub first_release_search_depth: usize,
+    /// tags exist. Defaults to 400
+    #[merge(strategy = merge::option::overwrite_none)]
+    pub first_release_search_depth: Option<usize>,
     /// Maximum number of tags to pull when searching for previous releases.
-    /// Set to 0 to search all tags
-    pub tag_search_depth: usize,
-    /// Generates different release PRs for each package defined in config
-    pub separate_pull_requests: bool,
+    /// Set to 0 to search all tags. Defaults to 100
+    #[merge(strategy = merge::option::overwrite_none)]
+    pub tag_search_depth: Option<usize>,
+    /// Generates different release PRs for each package defined in config.
+    /// Defaults to false
+    #[merge(strategy = merge::option::overwrite_none)]
+    pub separate_pull_requests: Option<bool>,
+    /// Releases the dependents of every released package too: packages whose
+    /// manifests depend on a released package get at least a patch bump and
+    /// a changelog entry for the new dependency version. Defaults to false
+    #[merge(strategy = merge::option::overwrite_none)]
+    pub cascade: Option<bool>,
+    /// How commit scopes listed in a package's `scopes` route commits.
+    /// Defaults to "attribute"
+    #[merge(strategy = merge::option::overwrite_none)]
+    pub scope_routing: Option<ScopeRouting>,
+    /// Also writes a changelog at the repository root with one combined
+    /// entry per release PR. Requires separate_pull_requests to be off
+    #[merge(strategy = merge::option::overwrite_none)]
+    pub aggregate_changelog: Option<AggregateChangelogConfig>,
     /// Skips targeted commit shas (or prefixes) when generating next version
     /// and changelog. Each value matches any commit whose SHA starts with the
-    /// provided value
+    /// provided value. Never inherited through `extends`: shas belong to one
+    /// repository
+    #[merge(skip)]
     pub skip_shas: Vec<String>,
     /// Rewords commit messages for targeted shas when generated changelog.
     /// Each SHA can be a prefix - matches any commit whose SHA starts with the
-    /// provided value
+    /// provided value. Never inherited through `extends`
+    #[merge(skip)]
     pub reword: Vec<RewordedCommit>,
+    /// Environment variables that config values may reference as `${VAR}`
+    /// or `${VAR:-default}`. Entries are exact names or a prefix ending in
+    /// `*` (e.g. "CI_*"). Referencing any other variable is an error
+    #[merge(strategy = merge::vec::append)]
+    pub env_allowlist: Vec<String>,
 }
 
-impl Default for RepositoryConfig {
-    fn default() -> Self {
-        Self {
-            base_branch: None,
-            first_release_search_depth: DEFAULT_COMMIT_SEARCH_DEPTH,
-            tag_search_depth: DEFAULT_TAG_SEARCH_DEPTH,
-            separate_pull_requests: false,
-            skip_shas: Vec::new(),
-            reword: Vec::new(),
-        }
+impl RepositoryConfig {
+    /// [`Self::first_release_search_depth`] or its default.
+    pub fn first_release_search_depth(&self) -> usize {
+        self.first_release_search_depth
+            .unwrap_or(DEFAULT_COMMIT_SEARCH_DEPTH)
+    }
+
+    /// [`Self::tag_search_depth`] or its default.
+    pub fn tag_search_depth(&self) -> usize {
+        self.tag_search_depth.unwrap_or(DEFAULT_TAG_SEARCH_DEPTH)
+    }
+
+    /// [`Self::separate_pull_requests`] or its default.
+    pub fn separate_pull_requests(&self) -> bool {
+        self.separate_pull_requests.unwrap_or_default()
+    }
+
+    /// [`Self::cascade`] or its default.
+    pub fn cascade(&self) -> bool {
+        self.cascade.unwrap_or_default()
     }
-}
 
-impl RepositoryConfig {
     pub fn base_branch(&self) -> Result<String> {
         self.base_branch
             .clone()
@@ -78,10 +145,12 @@ mod tests {
     fn loads_defaults() {
         let config = RepositoryConfig::default();
         assert_eq!(
-            config.first_release_search_depth,
+            config.first_release_search_depth(),
             DEFAULT_COMMIT_SEARCH_DEPTH
         );
-        assert_eq!(config.tag_search_depth, DEFAULT_TAG_SEARCH_DEPTH);
+        assert_eq!(config.tag_search_depth(), DEFAULT_TAG_SEARCH_DEPTH);
+        assert!(!config.separate_pull_requests());
+        assert!(!config.cascade());
     }
 
     #[test]
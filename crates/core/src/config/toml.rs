@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::config::{
     defaults::DefaultsConfig, package::PackageConfig,
-    repository::RepositoryConfig,
+    repository::RepositoryConfig, version_group::VersionGroupConfig,
 };
 
 /// Default configuration filename
@@ -19,21 +19,56 @@ pub const DEFAULT_CONFIG_FILE: &str = "releasaurus.toml";
 #[builder(setter(into, strip_option), default)]
 /// Configuration properties for `releasaurus.toml`
 pub struct Config {
+    /// Base configurations merged beneath this one, later entries taking
+    /// precedence over earlier ones. Each entry is a path in this repository
+    /// or `owner/repo:path` for a file in another repository on the same
+    /// forge. Base `[[package]]` entries are not inherited
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extends: Vec<String>,
     /// Repository configuration
     pub repository: RepositoryConfig,
     /// Default configuration applied to every package
     pub defaults: DefaultsConfig,
-    /// Packages to manage in this repository (supports monorepos)
-    #[serde(rename = "package")]
+    /// Packages to manage in this repository (supports monorepos). When
+    /// neither packages nor include are configured, a single package at the
+    /// repository root is managed
+    #[serde(rename = "package", default)]
     pub packages: Vec<PackageConfig>,
+    /// Globs of package fragment files (e.g.
+    /// "packages/*/releasaurus.package.toml"). Each fragment holds the keys
+    /// of one package entry, with paths relative to the fragment's directory
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    /// Groups of packages whose versions move together, either always
+    /// released at one shared version (fixed) or sharing the highest
+    /// version among the members being released (linked)
+    #[serde(rename = "version_group", skip_serializing_if = "Vec::is_empty")]
+    pub version_groups: Vec<VersionGroupConfig>,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
+            extends: vec![],
             repository: RepositoryConfig::default(),
             defaults: DefaultsConfig::default(),
             packages: vec![PackageConfig::default()],
+            include: vec![],
+            version_groups: vec![],
+        }
+    }
+}
+
+impl Config {
+    /// The configured packages, or the single repository-root package when
+    /// neither `packages` nor `include` name any. A config without
+    /// `[[package]]` entries deserializes with none, since its `include`
+    /// fragments may supply them instead.
+    pub fn packages_or_root(&self) -> Vec<PackageConfig> {
+        if self.packages.is_empty() && self.include.is_empty() {
+            vec![PackageConfig::default()]
+        } else {
+            self.packages.clone()
         }
     }
 }
@@ -62,7 +97,7 @@ mod tests {
 
         let config: Config = toml::from_str(raw).unwrap();
 
-        assert_eq!(config.repository.tag_search_depth, 25);
+        assert_eq!(config.repository.tag_search_depth(), 25);
         assert_eq!(config.packages.len(), 1);
 
         let versioning = config.defaults.versioning.unwrap();
@@ -71,6 +106,21 @@ mod tests {
         assert_eq!(named_parsers.len(), 3);
     }
 
+    #[test]
+    fn packages_or_root_restores_the_root_package() {
+        let config: Config = toml::from_str("[repository]\n").unwrap();
+
+        assert!(config.packages.is_empty());
+        assert_eq!(config.packages_or_root().len(), 1);
+        assert_eq!(config.packages_or_root()[0].path, ".");
+
+        let config: Config =
+            toml::from_str("include = [\"*/releasaurus.package.toml\"]\n")
+                .unwrap();
+
+        assert!(config.packages_or_root().is_empty());
+    }
+
     /// Every ```toml example in the book must parse as a real `Config`.
     ///
     /// With `deny_unknown_fields`, this catches documented keys that don't
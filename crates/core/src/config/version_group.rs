@@ -0,0 +1,36 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+/// How the versions of a version group's members relate
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    PartialEq,
+    Eq,
+    Display,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum VersionGroupMode {
+    /// Every member is released whenever any member is, all at the highest
+    /// version computed across the group
+    Fixed,
+    /// Only members with changes are released, but they share the highest
+    /// version computed among them
+    Linked,
+}
+
+/// Packages whose versions move together
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VersionGroupConfig {
+    /// How the members' versions are kept together
+    pub mode: VersionGroupMode,
+    /// Names of the member packages
+    pub packages: Vec<String>,
+}
@@ -116,6 +116,13 @@ pub struct Parser {
     #[serde(default, with = "serde_regex")]
     #[merge(strategy = merge::option::overwrite_none)]
     pub pattern: Option<Regex>,
+    /// Matched against the commit author, formatted as `Name <email>` after
+    /// any `.mailmap` rewrite. When both this and `pattern` are set, a
+    /// commit must match both.
+    #[schemars(with = "String")]
+    #[serde(default, with = "serde_regex")]
+    #[merge(strategy = merge::option::overwrite_none)]
+    pub author: Option<Regex>,
     #[merge(strategy = merge::option::overwrite_none)]
     pub title: Option<String>,
     #[merge(strategy = merge::option::overwrite_none)]
@@ -136,14 +143,23 @@ impl Parser {
     ) -> Self {
         Self {
             pattern,
+            author: None,
             title: Some(title),
             skip: Some(skip),
             order: Some(order),
         }
     }
 
-    pub fn is_match(&self, msg: &str) -> bool {
-        self.pattern.as_ref().is_some_and(|p| p.is_match(msg))
+    /// Whether a commit with message `msg` by `author` (`Name <email>`)
+    /// belongs to this parser. Every matcher that is set must match, and a
+    /// parser with neither `pattern` nor `author` matches nothing.
+    pub fn is_match(&self, msg: &str, author: &str) -> bool {
+        if self.pattern.is_none() && self.author.is_none() {
+            return false;
+        }
+
+        self.pattern.as_ref().is_none_or(|p| p.is_match(msg))
+            && self.author.as_ref().is_none_or(|a| a.is_match(author))
     }
 
     /// The value stored on [`Commit::group`][crate::analyzer::commit::Commit],
@@ -273,6 +289,11 @@ pub static NAMED_PARSERS: LazyLock<IndexMap<Group, Parser>> =
         ])
     });
 
+/// Group for the entries `repository.cascade` generates when a package is
+/// released only because one of its dependencies was.
+pub static DEPENDENCIES_PARSER: LazyLock<Parser> =
+    LazyLock::new(|| Parser::new(None, "📦 Dependencies".into(), false, 2));
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Merge, JsonSchema)]
 pub struct ParserList(#[merge(strategy = merge::vec::append)] pub Vec<Parser>);
 
@@ -24,14 +24,15 @@ use url::Url;
 
 use crate::{
     config::{
-        Config, DEFAULT_CONFIG_FILE,
+        Config,
+        loader::load_repository_config,
         repository::{DEFAULT_COMMIT_SEARCH_DEPTH, DEFAULT_TAG_SEARCH_DEPTH},
     },
     forge::{
         azure_devops::types::{
-            AzureCommit, AzureCommitChanges, AzureList, AzurePullRequest,
-            AzureRef, AzureRepo, Change, ChangeItem, CreateLabel,
-            CreatePullRequest, NewContent, PullRequestQuery,
+            AzureCommit, AzureCommitChanges, AzureItem, AzureList,
+            AzurePullRequest, AzureRef, AzureRepo, Change, ChangeItem,
+            CreateLabel, CreatePullRequest, NewContent, PullRequestQuery,
             PullRequestQueryInput, PullRequestQueryResponse, Push, PushCommit,
             PushResponse, RefUpdate, UpdatePullRequest,
         },
@@ -41,7 +42,7 @@ use crate::{
         },
         request::{
             Commit, CreateCommitRequest, CreatePrRequest,
-            CreateReleaseBranchRequest, FileUpdateType, ForgeCommit,
+            CreateReleaseBranchRequest, ForgeCommit, ForgeCommitAuthor,
             ForgeCommitPR, GetFileContentRequest, GetPrRequest,
             PrLabelsRequest, PrMetadataBlock, PullRequest,
             ReleaseByTagResponse, Tag, UpdatePrRequest,
@@ -81,6 +82,7 @@ pub struct AzureDevops {
     default_branch: String,
     release_link_base_url: Url,
     compare_link_base_url: Url,
+    issue_link_base_url: Url,
 }
 
 impl AzureDevops {
@@ -119,6 +121,13 @@ impl AzureDevops {
             "{}/branchCompare?baseVersion=GT",
             web_repo_url
         ))?;
+        // Issues are work items, which belong to the project rather than
+        // the repo: https://dev.azure.com/{org}/{project}/_workitems/edit/
+        let project_url = web_repo_url
+            .split_once("/_git/")
+            .map_or(web_repo_url.as_str(), |(project, _)| project);
+        let issue_link_base_url =
+            Url::parse(&format!("{}/_workitems/edit/", project_url))?;
 
         let mut headers = HeaderMap::new();
 
@@ -171,6 +180,7 @@ impl AzureDevops {
             web_repo_url,
             release_link_base_url,
             compare_link_base_url,
+            issue_link_base_url,
             default_branch,
         })
     }
@@ -307,9 +317,7 @@ impl AzureDevops {
             let mut content = change.content.clone();
             let (change_type, existed) = match existing.as_deref() {
                 Some(prev) => {
-                    if matches!(change.update_type, FileUpdateType::Prepend) {
-                        content = format!("{content}\n{prev}");
-                    }
+                    content = change.update_type.apply(&content, prev, "\n");
                     ("edit", Some(prev.to_string()))
                 }
                 None => ("add", None),
@@ -499,6 +507,38 @@ impl AzureDevops {
             })
             .collect())
     }
+
+    /// Fetches a file relative to a repository API url, returning None if
+    /// it doesn't exist.
+    async fn fetch_file_content(
+        &self,
+        repo_url: &Url,
+        req: GetFileContentRequest,
+    ) -> Result<Option<String>> {
+        let mut url = repo_url.join("items")?;
+        url.query_pairs_mut()
+            .append_pair("api-version", API_VERSION)
+            .append_pair("path", &normalize_path(&req.path))
+            .append_pair("includeContent", "true")
+            .append_pair("$format", "text");
+        if let Some(branch) = req.branch.as_ref() {
+            url.query_pairs_mut()
+                .append_pair("versionDescriptor.version", branch)
+                .append_pair("versionDescriptor.versionType", "branch");
+        }
+        let response = self
+            .client
+            .get(url)
+            .header("Accept", "text/plain")
+            .send()
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let result = response.error_for_status()?;
+        let content = result.text().await?;
+        Ok(Some(content))
+    }
 }
 
 fn strip_refs_heads(name: &str) -> &str {
@@ -580,6 +620,10 @@ impl Forge for AzureDevops {
         self.compare_link_base_url.clone()
     }
 
+    fn issue_link_base_url(&self) -> Option<Url> {
+        Some(self.issue_link_base_url.clone())
+    }
+
     fn default_branch(&self) -> String {
         self.default_branch.clone()
     }
@@ -596,29 +640,46 @@ impl Forge for AzureDevops {
         &self,
         req: GetFileContentRequest,
     ) -> Result<Option<String>> {
+        self.fetch_file_content(&self.base_url, req).await
+    }
+
+    /// `repo` is a repository name in the same project, or
+    /// `{project}/{repo}` for another project in the same organization.
+    async fn get_external_file_content(
+        &self,
+        repo: &str,
+        req: GetFileContentRequest,
+    ) -> Result<Option<String>> {
+        let org = self.url.owner.split('/').next().unwrap_or_default();
+        let (project, name) = match repo.trim_matches('/').rsplit_once('/') {
+            Some((project, name)) => (format!("{org}/{project}"), name),
+            None => (self.url.owner.clone(), repo.trim_matches('/')),
+        };
+        let repo_url = Url::parse(&format!(
+            "{}/{project}/_apis/git/repositories/{name}/",
+            self.url.link_base_url()
+        ))?;
+
+        self.fetch_file_content(&repo_url, req).await
+    }
+
+    async fn list_files(&self, branch: Option<String>) -> Result<Vec<String>> {
+        let r#ref = branch.unwrap_or_else(|| self.default_branch.clone());
         let mut url = self.base_url.join("items")?;
         url.query_pairs_mut()
             .append_pair("api-version", API_VERSION)
-            .append_pair("path", &normalize_path(&req.path))
-            .append_pair("includeContent", "true")
-            .append_pair("$format", "text");
-        if let Some(branch) = req.branch.as_ref() {
-            url.query_pairs_mut()
-                .append_pair("versionDescriptor.version", branch)
-                .append_pair("versionDescriptor.versionType", "branch");
-        }
-        let response = self
-            .client
-            .get(url)
-            .header("Accept", "text/plain")
-            .send()
-            .await?;
-        if response.status() == StatusCode::NOT_FOUND {
-            return Ok(None);
-        }
-        let result = response.error_for_status()?;
-        let content = result.text().await?;
-        Ok(Some(content))
+            .append_pair("scopePath", "/")
+            .append_pair("recursionLevel", "Full")
+            .append_pair("versionDescriptor.version", &r#ref)
+            .append_pair("versionDescriptor.versionType", "branch");
+        let response = self.client.get(url).send().await?;
+        let items: AzureList<AzureItem> = read_json(response).await?;
+        Ok(items
+            .value
+            .into_iter()
+            .filter(|item| !item.is_folder)
+            .map(|item| item.path.trim_start_matches('/').to_string())
+            .collect())
     }
 
     async fn load_config(
@@ -626,25 +687,7 @@ impl Forge for AzureDevops {
         branch: Option<String>,
         config_path: Option<String>,
     ) -> Result<Config> {
-        let is_custom = config_path.is_some();
-        let path =
-            config_path.unwrap_or_else(|| DEFAULT_CONFIG_FILE.to_string());
-        if let Some(content) = self
-            .get_file_content(GetFileContentRequest {
-                branch,
-                path: path.clone(),
-            })
-            .await?
-        {
-            let config: Config = toml::from_str(&content)?;
-            Ok(config)
-        } else if is_custom {
-            Err(ReleasaurusError::invalid_config(format!(
-                "configuration file not found at: {path}"
-            )))
-        } else {
-            Ok(Config::default())
-        }
+        load_repository_config(self, branch, config_path).await
     }
 
     async fn get_release_by_tag(
@@ -780,6 +823,7 @@ impl Forge for AzureDevops {
                 commits.push(ForgeCommit {
                     author_email: c.author.email,
                     author_name: c.author.name,
+                    author_username: None,
                     id: c.commit_id.clone(),
                     short_id: c.commit_id.chars().take(8).collect(),
                     link: c.remote_url,
@@ -808,6 +852,46 @@ impl Forge for AzureDevops {
         Ok(commits)
     }
 
+    async fn get_commit_authors(
+        &self,
+        sha: &str,
+    ) -> Result<Vec<ForgeCommitAuthor>> {
+        let page_size = u64::from(DEFAULT_PAGE_SIZE);
+        let mut skip: u64 = 0;
+        let mut authors = vec![];
+
+        loop {
+            let mut url = self.endpoint("commits")?;
+            url.query_pairs_mut()
+                .append_pair("$top", &page_size.to_string())
+                .append_pair("$skip", &skip.to_string())
+                .append_pair("searchCriteria.includeWorkItems", "false")
+                .append_pair("searchCriteria.itemVersion.version", sha)
+                .append_pair(
+                    "searchCriteria.itemVersion.versionType",
+                    "commit",
+                );
+
+            let response = self.client.get(url).send().await?;
+            let list: AzureList<AzureCommit> = read_json(response).await?;
+            let returned = list.value.len() as u64;
+
+            authors.extend(list.value.into_iter().map(|c| ForgeCommitAuthor {
+                sha: c.commit_id,
+                name: c.author.name,
+                email: c.author.email,
+                username: None,
+            }));
+
+            if returned < page_size {
+                break;
+            }
+            skip += page_size;
+        }
+
+        Ok(authors)
+    }
+
     async fn get_merged_pull_request_for_commit(
         &self,
         commit_sha: &str,
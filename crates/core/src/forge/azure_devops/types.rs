@@ -72,6 +72,16 @@ pub struct AzureCommit {
     pub remote_url: String,
 }
 
+/// A file or folder entry returned by the Azure DevOps Items API.
+#[derive(Debug, Deserialize)]
+pub struct AzureItem {
+    /// Repository-relative path of the entry (e.g. `/src/main.rs`).
+    pub path: String,
+    /// Whether the entry is a folder rather than a file.
+    #[serde(rename = "isFolder", default)]
+    pub is_folder: bool,
+}
+
 /// Path metadata for a single file affected by a commit change.
 #[derive(Debug, Default, Deserialize)]
 pub struct AzureChangeItem {
@@ -18,7 +18,7 @@ use crate::{
         gitea::Gitea,
         request::{
             Commit, CreateCommitRequest, CreatePrRequest,
-            CreateReleaseBranchRequest, FileUpdateType, ForgeCommit,
+            CreateReleaseBranchRequest, ForgeCommit, ForgeCommitAuthor,
             ForgeCommitPR, GetFileContentRequest, GetPrRequest,
             PrLabelsRequest, PullRequest, ReleaseByTagResponse, Tag,
             UpdatePrRequest,
@@ -107,6 +107,10 @@ impl Forge for Forgejo {
         self.gitea.compare_link_base_url()
     }
 
+    fn issue_link_base_url(&self) -> Option<Url> {
+        self.gitea.issue_link_base_url()
+    }
+
     fn default_branch(&self) -> String {
         self.gitea.default_branch()
     }
@@ -126,6 +130,18 @@ impl Forge for Forgejo {
         self.gitea.get_file_content(req).await
     }
 
+    async fn get_external_file_content(
+        &self,
+        repo: &str,
+        req: GetFileContentRequest,
+    ) -> Result<Option<String>> {
+        self.gitea.get_external_file_content(repo, req).await
+    }
+
+    async fn list_files(&self, branch: Option<String>) -> Result<Vec<String>> {
+        self.gitea.list_files(branch).await
+    }
+
     async fn load_config(
         &self,
         branch: Option<String>,
@@ -162,6 +178,13 @@ impl Forge for Forgejo {
         self.gitea.get_commits(branch, sha).await
     }
 
+    async fn get_commit_authors(
+        &self,
+        sha: &str,
+    ) -> Result<Vec<ForgeCommitAuthor>> {
+        self.gitea.get_commit_authors(sha).await
+    }
+
     async fn create_release_branch(
         &self,
         req: CreateReleaseBranchRequest,
@@ -184,9 +207,7 @@ impl Forge for Forgejo {
                         .get_file_sha(&req.base_branch, &change.path)
                         .await?,
                 );
-                if matches!(change.update_type, FileUpdateType::Prepend) {
-                    content = format!("{content}\n{ec}");
-                }
+                content = change.update_type.apply(&content, ec, "\n");
             } else {
                 op = ForgejoFileChangeOperation::Create;
             }
@@ -245,9 +266,7 @@ impl Forge for Forgejo {
                         .get_file_sha(&req.target_branch, &change.path)
                         .await?,
                 );
-                if matches!(change.update_type, FileUpdateType::Prepend) {
-                    content = format!("{content}{ec}");
-                }
+                content = change.update_type.apply(&content, ec, "");
             } else {
                 op = ForgejoFileChangeOperation::Create;
             }
@@ -14,7 +14,8 @@ use url::Url;
 
 use crate::{
     config::{
-        Config, DEFAULT_CONFIG_FILE,
+        Config,
+        loader::load_repository_config,
         repository::{DEFAULT_COMMIT_SEARCH_DEPTH, DEFAULT_TAG_SEARCH_DEPTH},
     },
     forge::{
@@ -23,15 +24,15 @@ use crate::{
             TokenVar, USER_AGENT, resolve_token,
         },
         gitea::types::{
-            CreateLabel, CreatePull, CreateRelease, GiteaCommitPR,
-            GiteaCommitQueryObject, GiteaCreatedCommit, GiteaFileChange,
-            GiteaFileChangeOperation, GiteaIssue, GiteaLabel, GiteaModifyFiles,
-            GiteaPullRequest, GiteaRelease, GiteaTag, UpdatePullBody,
-            UpdatePullLabels,
+            CreateLabel, CreatePull, CreateRelease, GiteaCommitAuthorObject,
+            GiteaCommitPR, GiteaCommitQueryObject, GiteaCreatedCommit,
+            GiteaFileChange, GiteaFileChangeOperation, GiteaIssue, GiteaLabel,
+            GiteaModifyFiles, GiteaPullRequest, GiteaRelease, GiteaTag,
+            GiteaTree, UpdatePullBody, UpdatePullLabels,
         },
         request::{
             Commit, CreateCommitRequest, CreatePrRequest,
-            CreateReleaseBranchRequest, FileUpdateType, ForgeCommit,
+            CreateReleaseBranchRequest, ForgeCommit, ForgeCommitAuthor,
             ForgeCommitPR, GetFileContentRequest, GetPrRequest,
             PrLabelsRequest, PullRequest, ReleaseByTagResponse, Tag,
             UpdatePrRequest,
@@ -54,6 +55,7 @@ pub struct Gitea {
     default_branch: String,
     release_link_base_url: Url,
     compare_link_base_url: Url,
+    issue_link_base_url: Url,
 }
 
 impl Gitea {
@@ -87,6 +89,11 @@ impl Gitea {
             link_base_url, url.owner, url.name
         ))?;
 
+        let issue_link_base_url = Url::parse(&format!(
+            "{}/{}/{}/issues/",
+            link_base_url, url.owner, url.name
+        ))?;
+
         let mut headers = HeaderMap::new();
 
         let token_value = HeaderValue::from_str(
@@ -130,6 +137,7 @@ impl Gitea {
             base_url,
             release_link_base_url,
             compare_link_base_url,
+            issue_link_base_url,
             default_branch: default_branch.into(),
         })
     }
@@ -241,6 +249,28 @@ impl Gitea {
             response.error_for_status()?.json().await?;
         Ok(result["total_commits"].as_u64() == Some(0))
     }
+
+    /// Fetches a raw file relative to a repository API url, returning None
+    /// if it doesn't exist.
+    async fn fetch_file_content(
+        &self,
+        repo_url: &Url,
+        req: GetFileContentRequest,
+    ) -> Result<Option<String>> {
+        let mut raw_url = repo_url.join(&format!("raw/{}", req.path))?;
+        if let Some(branch) = req.branch {
+            raw_url =
+                repo_url.join(&format!("raw/{}?ref={branch}", req.path))?;
+        }
+        let request = self.client.get(raw_url).build()?;
+        let response = self.client.execute(request).await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let result = response.error_for_status()?;
+        let content = result.text().await?;
+        Ok(Some(content))
+    }
 }
 
 #[async_trait]
@@ -257,6 +287,10 @@ impl Forge for Gitea {
         self.compare_link_base_url.clone()
     }
 
+    fn issue_link_base_url(&self) -> Option<Url> {
+        Some(self.issue_link_base_url.clone())
+    }
+
     fn default_branch(&self) -> String {
         self.default_branch.clone()
     }
@@ -273,20 +307,56 @@ impl Forge for Gitea {
         &self,
         req: GetFileContentRequest,
     ) -> Result<Option<String>> {
-        let mut raw_url = self.base_url.join(&format!("raw/{}", req.path))?;
-        if let Some(branch) = req.branch {
-            raw_url = self
-                .base_url
-                .join(&format!("raw/{}?ref={branch}", req.path))?;
-        }
-        let request = self.client.get(raw_url).build()?;
-        let response = self.client.execute(request).await?;
-        if response.status() == StatusCode::NOT_FOUND {
-            return Ok(None);
+        self.fetch_file_content(&self.base_url, req).await
+    }
+
+    async fn get_external_file_content(
+        &self,
+        repo: &str,
+        req: GetFileContentRequest,
+    ) -> Result<Option<String>> {
+        // base_url ends in /repos/{owner}/{name}/
+        let repo_url = self
+            .base_url
+            .join(&format!("../../{}/", repo.trim_matches('/')))?;
+
+        self.fetch_file_content(&repo_url, req).await
+    }
+
+    async fn list_files(&self, branch: Option<String>) -> Result<Vec<String>> {
+        let r#ref = branch.unwrap_or_else(|| self.default_branch.clone());
+        let mut page = 1;
+        let mut files = vec![];
+
+        loop {
+            let mut tree_url =
+                self.base_url.join(&format!("git/trees/{}", r#ref))?;
+
+            tree_url
+                .query_pairs_mut()
+                .append_pair("recursive", "true")
+                .append_pair("page", &page.to_string());
+
+            let request = self.client.get(tree_url).build()?;
+            let response = self.client.execute(request).await?;
+            let result = response.error_for_status()?;
+            let tree: GiteaTree = result.json().await?;
+
+            files.extend(
+                tree.tree
+                    .into_iter()
+                    .filter(|entry| entry.kind == "blob")
+                    .map(|entry| entry.path),
+            );
+
+            if !tree.truncated {
+                break;
+            }
+
+            page += 1;
         }
-        let result = response.error_for_status()?;
-        let content = result.text().await?;
-        Ok(Some(content))
+
+        Ok(files)
     }
 
     async fn load_config(
@@ -294,26 +364,7 @@ impl Forge for Gitea {
         branch: Option<String>,
         config_path: Option<String>,
     ) -> Result<Config> {
-        let is_custom = config_path.is_some();
-        let path =
-            config_path.unwrap_or_else(|| DEFAULT_CONFIG_FILE.to_string());
-        if let Some(content) = self
-            .get_file_content(GetFileContentRequest {
-                branch,
-                path: path.clone(),
-            })
-            .await?
-        {
-            let config: Config = toml::from_str(&content)?;
-
-            Ok(config)
-        } else if is_custom {
-            Err(ReleasaurusError::invalid_config(format!(
-                "configuration file not found at: {path}"
-            )))
-        } else {
-            Ok(Config::default())
-        }
+        load_repository_config(self, branch, config_path).await
     }
 
     async fn get_release_by_tag(
@@ -505,6 +556,10 @@ impl Forge for Gitea {
                 let forge_commit = ForgeCommit {
                     author_email: result.commit.author.email.clone(),
                     author_name: result.commit.author.name.clone(),
+                    author_username: result
+                        .author
+                        .as_ref()
+                        .map(|a| a.login.clone()),
                     id: result.sha.clone(),
                     short_id: result.sha.chars().take(8).collect::<String>(),
                     link: result.html_url.clone(),
@@ -529,6 +584,53 @@ impl Forge for Gitea {
         Ok(commits)
     }
 
+    async fn get_commit_authors(
+        &self,
+        sha: &str,
+    ) -> Result<Vec<ForgeCommitAuthor>> {
+        let mut page = 1;
+        let mut has_more = true;
+        let mut authors = vec![];
+
+        while has_more {
+            let mut commits_url = self.base_url.join("commits")?;
+
+            commits_url
+                .query_pairs_mut()
+                .append_pair("sha", sha)
+                .append_pair("limit", &DEFAULT_PAGE_SIZE.to_string())
+                .append_pair("page", &page.to_string())
+                .append_pair("stat", "false")
+                .append_pair("verification", "false")
+                .append_pair("files", "false");
+
+            let request = self.client.get(commits_url).build()?;
+            let response = self.client.execute(request).await?;
+
+            has_more = response
+                .headers()
+                .get("x-hasmore")
+                .map(|h| h.to_str().unwrap_or_default() == "true")
+                .unwrap_or(false);
+
+            let result = response.error_for_status()?;
+            let results: Vec<GiteaCommitAuthorObject> = result.json().await?;
+
+            authors.extend(results.into_iter().map(|result| {
+                ForgeCommitAuthor {
+                    sha: result.sha,
+                    name: result.commit.author.name,
+                    email: result.commit.author.email,
+                    username: result.author.map(|a| a.login),
+                }
+            }));
+
+            page += 1;
+        }
+
+        Ok(authors)
+    }
+
     async fn get_merged_pull_request_for_commit(
         &self,
         commit_sha: &str,
@@ -572,9 +674,7 @@ impl Forge for Gitea {
                 sha = Some(
                     self.get_file_sha(&req.base_branch, &change.path).await?,
                 );
-                if matches!(change.update_type, FileUpdateType::Prepend) {
-                    content = format!("{content}\n{ec}");
-                }
+                content = change.update_type.apply(&content, ec, "\n");
             } else {
                 op = GiteaFileChangeOperation::Create;
             }
@@ -620,9 +720,7 @@ impl Forge for Gitea {
                 sha = Some(
                     self.get_file_sha(&req.target_branch, &change.path).await?,
                 );
-                if matches!(change.update_type, FileUpdateType::Prepend) {
-                    content = format!("{content}{ec}");
-                }
+                content = change.update_type.apply(&content, ec, "");
             } else {
                 op = GiteaFileChangeOperation::Create;
             }
@@ -938,14 +1036,16 @@ impl Forge for Gitea {
 #[cfg(test)]
 mod tests {
     use secrecy::SecretString;
-    use wiremock::matchers::{method, path};
+    use wiremock::matchers::{method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::forge::config::{PENDING_LABEL, RepoUrl, Scheme};
     use crate::forge::traits::Forge;
 
     use super::Gitea;
-    use crate::forge::request::GetPrRequest;
+    use crate::forge::request::{
+        ForgeCommitAuthor, GetFileContentRequest, GetPrRequest,
+    };
 
     const COMMIT_SHA: &str = "abc123def456";
 
@@ -1015,6 +1115,138 @@ mod tests {
             .unwrap()
     }
 
+    #[tokio::test]
+    async fn get_commit_authors_pages_through_history() {
+        let server = MockServer::start().await;
+        let gitea = make_gitea(&server, "foo", "bar").await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/repos/foo/bar/commits"))
+            .and(query_param("sha", COMMIT_SHA))
+            .and(query_param("files", "false"))
+            .and(query_param("page", "1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-hasmore", "true")
+                    .set_body_json(serde_json::json!([{
+                        "sha": "bbb2222",
+                        "commit": {
+                            "author": {"name": "Jane", "email": "jane@example.com"},
+                            "message": "fix: a bug",
+                        },
+                        "author": {"login": "jane"},
+                    }])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/repos/foo/bar/commits"))
+            .and(query_param("sha", COMMIT_SHA))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                serde_json::json!([{
+                    "sha": "aaa1111",
+                    "commit": {
+                        "author": {"name": "Bob", "email": "bob@example.com"},
+                        "message": "initial commit",
+                    },
+                    "author": null,
+                }]),
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let authors = gitea.get_commit_authors(COMMIT_SHA).await.unwrap();
+
+        assert_eq!(
+            authors,
+            vec![
+                ForgeCommitAuthor {
+                    sha: "bbb2222".into(),
+                    name: "Jane".into(),
+                    email: "jane@example.com".into(),
+                    username: Some("jane".into()),
+                },
+                ForgeCommitAuthor {
+                    sha: "aaa1111".into(),
+                    name: "Bob".into(),
+                    email: "bob@example.com".into(),
+                    username: None,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn list_files_follows_truncated_tree_pages() {
+        let server = MockServer::start().await;
+        let gitea = make_gitea(&server, "foo", "bar").await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/repos/foo/bar/git/trees/main"))
+            .and(query_param("page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({
+                    "tree": [
+                        {"path": "crates", "type": "tree"},
+                        {"path": "Cargo.toml", "type": "blob"}
+                    ],
+                    "truncated": true
+                }),
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/repos/foo/bar/git/trees/main"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({
+                    "tree": [{"path": "crates/core/Cargo.toml", "type": "blob"}],
+                    "truncated": false
+                }),
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let files = gitea.list_files(None).await.unwrap();
+
+        assert_eq!(files, vec!["Cargo.toml", "crates/core/Cargo.toml"]);
+    }
+
+    #[tokio::test]
+    async fn get_external_file_content_reads_from_another_repo() {
+        let server = MockServer::start().await;
+        let gitea = make_gitea(&server, "foo", "bar").await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/repos/acme/release-config/raw/base.toml"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string("[repository]\n"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let content = gitea
+            .get_external_file_content(
+                "acme/release-config",
+                GetFileContentRequest {
+                    branch: None,
+                    path: "base.toml".into(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(content.as_deref(), Some("[repository]\n"));
+    }
+
     #[tokio::test]
     async fn pending_label_exists_returns_false_when_no_pending_labels_exist() {
         let server = MockServer::start().await;
@@ -18,6 +18,21 @@ pub struct GiteaLabel {
     pub is_archived: bool,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct GiteaTreeEntry {
+    pub path: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaTree {
+    #[serde(default)]
+    pub tree: Vec<GiteaTreeEntry>,
+    #[serde(default)]
+    pub truncated: bool,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct PullRequestBranch {
     pub label: String,
@@ -95,16 +110,31 @@ pub struct GiteaCommitFile {
     pub filename: String,
 }
 
+/// Forge account a commit is linked to
+#[derive(Debug, Deserialize)]
+pub struct GiteaUser {
+    pub login: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct GiteaCommitQueryObject {
     pub sha: String,
     pub created: String,
     pub commit: GiteaCommit,
+    pub author: Option<GiteaUser>,
     pub files: Vec<GiteaCommitFile>,
     pub parents: Vec<GiteaCommitParent>,
     pub html_url: String,
 }
 
+/// A commit listed without its stats or files
+#[derive(Debug, Deserialize)]
+pub struct GiteaCommitAuthorObject {
+    pub sha: String,
+    pub commit: GiteaCommit,
+    pub author: Option<GiteaUser>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct GiteaTagCommit {
     pub created: String,
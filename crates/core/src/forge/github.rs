@@ -19,7 +19,8 @@ mod types;
 
 use crate::{
     config::{
-        Config, DEFAULT_CONFIG_FILE,
+        Config,
+        loader::load_repository_config,
         repository::{DEFAULT_COMMIT_SEARCH_DEPTH, DEFAULT_TAG_SEARCH_DEPTH},
     },
     forge::{
@@ -33,16 +34,16 @@ use crate::{
                 TAG_SEARCH_QUERY, TagSearchQueryVariables, TagSearchResult,
             },
             types::{
-                GithubCommitPR, GithubTree, GithubTreeEntry, TREE_BLOB_MODE,
-                TREE_BLOB_TYPE, Tree,
+                GithubCommitPR, GithubTree, GithubTreeEntry, GithubTreeListing,
+                TREE_BLOB_MODE, TREE_BLOB_TYPE, Tree,
             },
         },
         request::{
             Commit, CreateCommitRequest, CreatePrRequest,
-            CreateReleaseBranchRequest, FileChange, FileUpdateType,
-            ForgeCommit, ForgeCommitPR, GetFileContentRequest, GetPrRequest,
-            PrLabelsRequest, PullRequest, ReleaseByTagResponse, Tag,
-            UpdatePrRequest,
+            CreateReleaseBranchRequest, FileChange, ForgeCommit,
+            ForgeCommitAuthor, ForgeCommitPR, GetFileContentRequest,
+            GetPrRequest, PrLabelsRequest, PullRequest, ReleaseByTagResponse,
+            Tag, UpdatePrRequest,
         },
         traits::Forge,
     },
@@ -60,6 +61,7 @@ pub struct Github {
     default_branch: String,
     release_link_base_url: Url,
     compare_link_base_url: Url,
+    issue_link_base_url: Url,
 }
 
 impl Github {
@@ -91,6 +93,11 @@ impl Github {
             link_base_url, url.owner, url.name
         ))?;
 
+        let issue_link_base_url = Url::parse(&format!(
+            "{}/{}/{}/issues/",
+            link_base_url, url.owner, url.name
+        ))?;
+
         let base_uri = format!("{}://api.{}", url.scheme, url.host);
 
         let builder = Octocrab::builder()
@@ -115,9 +122,25 @@ impl Github {
             default_branch,
             release_link_base_url,
             compare_link_base_url,
+            issue_link_base_url,
         })
     }
 
+    async fn get_tree_listing(
+        &self,
+        tree: &str,
+        recursive: bool,
+    ) -> Result<GithubTreeListing> {
+        let recursive = if recursive { "?recursive=1" } else { "" };
+
+        let endpoint = format!(
+            "{}/repos/{}/{}/git/trees/{tree}{recursive}",
+            self.base_uri, self.url.owner, self.url.name
+        );
+
+        Ok(self.instance.get(endpoint, None::<&()>).await?)
+    }
+
     async fn create_tree(&self, tree: GithubTree) -> Result<Tree> {
         let endpoint = format!(
             "{}/repos/{}/{}/git/trees",
@@ -152,10 +175,9 @@ impl Github {
                 })
                 .await?;
 
-            if matches!(change.update_type, FileUpdateType::Prepend)
-                && let Some(existing_content) = existing_content.clone()
-            {
-                content = format!("{content}\n{existing_content}");
+            if let Some(existing_content) = existing_content.as_deref() {
+                content =
+                    change.update_type.apply(&content, existing_content, "\n");
             }
 
             if content == existing_content.unwrap_or_default() {
@@ -227,68 +249,18 @@ impl Github {
             self.instance.get(endpoint, None::<&()>).await?;
         Ok(result["ahead_by"].as_u64() == Some(0))
     }
-}
-
-#[async_trait]
-impl Forge for Github {
-    fn repo_name(&self) -> String {
-        self.url.name.clone()
-    }
-
-    fn release_link_base_url(&self) -> Url {
-        self.release_link_base_url.clone()
-    }
-
-    fn compare_link_base_url(&self) -> Url {
-        self.compare_link_base_url.clone()
-    }
-
-    fn default_branch(&self) -> String {
-        self.default_branch.clone()
-    }
-
-    fn set_commit_search_depth(&mut self, depth: usize) {
-        self.commit_search_depth = if depth == 0 { usize::MAX } else { depth }
-    }
-
-    fn set_tag_search_depth(&mut self, depth: usize) {
-        self.tag_search_depth = if depth == 0 { usize::MAX } else { depth }
-    }
-
-    async fn load_config(
-        &self,
-        branch: Option<String>,
-        config_path: Option<String>,
-    ) -> Result<Config> {
-        let is_custom = config_path.is_some();
-        let path =
-            config_path.unwrap_or_else(|| DEFAULT_CONFIG_FILE.to_string());
-        if let Some(content) = self
-            .get_file_content(GetFileContentRequest {
-                branch,
-                path: path.clone(),
-            })
-            .await?
-        {
-            let config: Config = toml::from_str(&content)?;
-
-            Ok(config)
-        } else if is_custom {
-            Err(ReleasaurusError::invalid_config(format!(
-                "configuration file not found at: {path}"
-            )))
-        } else {
-            Ok(Config::default())
-        }
-    }
 
-    async fn get_file_content(
+    /// Fetches a file from any repository the client can read, returning
+    /// None if it doesn't exist.
+    async fn fetch_file_content(
         &self,
+        owner: &str,
+        name: &str,
         req: GetFileContentRequest,
     ) -> Result<Option<String>> {
         let result = if let Some(branch) = req.branch {
             self.instance
-                .repos(&self.url.owner, &self.url.name)
+                .repos(owner, name)
                 .get_content()
                 .path(&req.path)
                 .r#ref(branch)
@@ -296,7 +268,7 @@ impl Forge for Github {
                 .await
         } else {
             self.instance
-                .repos(&self.url.owner, &self.url.name)
+                .repos(owner, name)
                 .get_content()
                 .path(&req.path)
                 .send()
@@ -340,6 +312,119 @@ impl Forge for Github {
             }
         }
     }
+}
+
+#[async_trait]
+impl Forge for Github {
+    fn repo_name(&self) -> String {
+        self.url.name.clone()
+    }
+
+    fn release_link_base_url(&self) -> Url {
+        self.release_link_base_url.clone()
+    }
+
+    fn compare_link_base_url(&self) -> Url {
+        self.compare_link_base_url.clone()
+    }
+
+    fn issue_link_base_url(&self) -> Option<Url> {
+        Some(self.issue_link_base_url.clone())
+    }
+
+    fn default_branch(&self) -> String {
+        self.default_branch.clone()
+    }
+
+    fn set_commit_search_depth(&mut self, depth: usize) {
+        self.commit_search_depth = if depth == 0 { usize::MAX } else { depth }
+    }
+
+    fn set_tag_search_depth(&mut self, depth: usize) {
+        self.tag_search_depth = if depth == 0 { usize::MAX } else { depth }
+    }
+
+    async fn load_config(
+        &self,
+        branch: Option<String>,
+        config_path: Option<String>,
+    ) -> Result<Config> {
+        load_repository_config(self, branch, config_path).await
+    }
+
+    async fn get_file_content(
+        &self,
+        req: GetFileContentRequest,
+    ) -> Result<Option<String>> {
+        self.fetch_file_content(&self.url.owner, &self.url.name, req)
+            .await
+    }
+
+    async fn get_external_file_content(
+        &self,
+        repo: &str,
+        req: GetFileContentRequest,
+    ) -> Result<Option<String>> {
+        let (owner, name) = repo.split_once('/').ok_or_else(|| {
+            ReleasaurusError::invalid_config(format!(
+                "invalid repository {repo}: expected owner/name"
+            ))
+        })?;
+
+        self.fetch_file_content(owner, name, req).await
+    }
+
+    async fn list_files(&self, branch: Option<String>) -> Result<Vec<String>> {
+        let r#ref = branch.unwrap_or_else(|| self.default_branch.clone());
+
+        let mut files = vec![];
+        // trees still to list, with the directory each one is rooted at
+        let mut pending = vec![(r#ref, String::new())];
+
+        while let Some((tree, prefix)) = pending.pop() {
+            let listing = self.get_tree_listing(&tree, true).await?;
+
+            if !listing.truncated {
+                files.extend(
+                    listing
+                        .tree
+                        .into_iter()
+                        .filter(|entry| entry.kind == TREE_BLOB_TYPE)
+                        .map(|entry| format!("{prefix}{}", entry.path)),
+                );
+                continue;
+            }
+
+            // too large for one recursive listing: list this level alone and
+            // descend into each subdirectory separately
+            log::debug!(
+                "recursive tree for '{prefix}' was truncated, listing its subtrees"
+            );
+
+            let level = self.get_tree_listing(&tree, false).await?;
+
+            if level.truncated {
+                return Err(ReleasaurusError::forge(format!(
+                    "github truncated the file listing of '{prefix}' in {}/{}: too many entries in one directory",
+                    self.url.owner, self.url.name
+                )));
+            }
+
+            for entry in level.tree {
+                let path = format!("{prefix}{}", entry.path);
+
+                if entry.kind == TREE_BLOB_TYPE {
+                    files.push(path);
+                } else if entry.kind == "tree" {
+                    pending.push((entry.sha, format!("{path}/")));
+                }
+            }
+        }
+
+        files.sort();
+
+        Ok(files)
+    }
 
     async fn get_release_by_tag(
         &self,
@@ -584,6 +669,7 @@ impl Forge for Github {
                 pr: None,
                 author_name,
                 author_email,
+                author_username: commit.author.map(|a| a.login),
                 merge_commit: commit.parents.len() > 1,
                 message: commit.commit.message,
                 timestamp,
@@ -594,6 +680,42 @@ impl Forge for Github {
         Ok(commits)
     }
 
+    async fn get_commit_authors(
+        &self,
+        sha: &str,
+    ) -> Result<Vec<ForgeCommitAuthor>> {
+        let stream = self
+            .instance
+            .repos(&self.url.owner, &self.url.name)
+            .list_commits()
+            .sha(sha)
+            .per_page(DEFAULT_PAGE_SIZE)
+            .send()
+            .await?
+            .into_stream(&self.instance);
+
+        pin!(stream);
+
+        let mut authors = vec![];
+
+        while let Some(commit) = stream.try_next().await? {
+            let (name, email) = commit
+                .commit
+                .author
+                .map(|a| (a.name, a.email.unwrap_or_default()))
+                .unwrap_or_default();
+
+            authors.push(ForgeCommitAuthor {
+                sha: commit.sha,
+                name,
+                email,
+                username: commit.author.map(|a| a.login),
+            });
+        }
+
+        Ok(authors)
+    }
+
     async fn get_merged_pull_request_for_commit(
         &self,
         commit_sha: &str,
@@ -20,6 +20,21 @@ pub struct Tree {
     pub sha: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct GithubTreeListingEntry {
+    pub path: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub sha: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubTreeListing {
+    pub tree: Vec<GithubTreeListingEntry>,
+    #[serde(default)]
+    pub truncated: bool,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct GithubCommitPRBase {
     #[serde(rename = "ref")]
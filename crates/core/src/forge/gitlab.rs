@@ -19,6 +19,7 @@ use gitlab::{
             },
             releases::{CreateRelease, ProjectReleaseByTag},
             repository::{
+                Tree,
                 commits::CompareCommits,
                 commits::{
                     CommitAction, CommitActionType, Commits, CommitsOrder,
@@ -43,7 +44,8 @@ pub use types::GitlabCommitMergeRequestsBuilderError;
 
 use crate::{
     config::{
-        Config, DEFAULT_CONFIG_FILE,
+        Config,
+        loader::load_repository_config,
         repository::{DEFAULT_COMMIT_SEARCH_DEPTH, DEFAULT_TAG_SEARCH_DEPTH},
     },
     forge::{
@@ -57,11 +59,12 @@ use crate::{
                 CreatedCommit, FileInfo, GitlabCommit,
                 GitlabCommitMergeRequest, GitlabCommitMergeRequests,
                 GitlabRelease, GitlabTag, LabelInfo, MergeRequestInfo,
+                TreeEntry,
             },
         },
         request::{
             Commit, CreateCommitRequest, CreatePrRequest,
-            CreateReleaseBranchRequest, FileUpdateType, ForgeCommit,
+            CreateReleaseBranchRequest, ForgeCommit, ForgeCommitAuthor,
             ForgeCommitPR, GetFileContentRequest, GetPrRequest,
             PrLabelsRequest, PullRequest, ReleaseByTagResponse, Tag,
             UpdatePrRequest,
@@ -82,6 +85,7 @@ pub struct Gitlab {
     default_branch: String,
     release_link_base_url: Url,
     compare_link_base_url: Url,
+    issue_link_base_url: Url,
 }
 
 impl Gitlab {
@@ -110,6 +114,9 @@ impl Gitlab {
         let compare_link_base_url =
             Url::parse(&format!("{}/{}/-/compare/", link_base_url, path))?;
 
+        let issue_link_base_url =
+            Url::parse(&format!("{}/{}/-/issues/", link_base_url, path))?;
+
         let project_id = path.to_string();
 
         let gl =
@@ -134,6 +141,7 @@ impl Gitlab {
             default_branch,
             release_link_base_url,
             compare_link_base_url,
+            issue_link_base_url,
         })
     }
 
@@ -183,69 +191,18 @@ impl Gitlab {
         let commits = result["commits"].as_array();
         Ok(commits.map(|c| c.is_empty()).unwrap_or(false))
     }
-}
-
-#[async_trait]
-impl Forge for Gitlab {
-    fn repo_name(&self) -> String {
-        self.url.name.clone()
-    }
 
-    fn release_link_base_url(&self) -> Url {
-        self.release_link_base_url.clone()
-    }
-
-    fn compare_link_base_url(&self) -> Url {
-        self.compare_link_base_url.clone()
-    }
-
-    fn default_branch(&self) -> String {
-        self.default_branch.clone()
-    }
-
-    fn set_commit_search_depth(&mut self, depth: usize) {
-        self.commit_search_depth = if depth == 0 { usize::MAX } else { depth }
-    }
-
-    fn set_tag_search_depth(&mut self, depth: usize) {
-        self.tag_search_depth = if depth == 0 { usize::MAX } else { depth }
-    }
-
-    async fn load_config(
-        &self,
-        branch: Option<String>,
-        config_path: Option<String>,
-    ) -> Result<Config> {
-        let is_custom = config_path.is_some();
-        let path =
-            config_path.unwrap_or_else(|| DEFAULT_CONFIG_FILE.to_string());
-        if let Some(content) = self
-            .get_file_content(GetFileContentRequest {
-                branch,
-                path: path.clone(),
-            })
-            .await?
-        {
-            let config: Config = toml::from_str(&content)?;
-
-            Ok(config)
-        } else if is_custom {
-            Err(ReleasaurusError::invalid_config(format!(
-                "configuration file not found at: {path}"
-            )))
-        } else {
-            Ok(Config::default())
-        }
-    }
-
-    async fn get_file_content(
+    /// Fetches a file from any project the client can read, returning None
+    /// if it doesn't exist.
+    async fn fetch_file_content(
         &self,
+        project: &str,
         req: GetFileContentRequest,
     ) -> Result<Option<String>> {
         let r#ref = req.branch.unwrap_or("HEAD".into());
 
         let endpoint = File::builder()
-            .project(&self.project_id)
+            .project(project)
             .file_path(&req.path)
             .ref_(&r#ref)
             .build()?;
@@ -259,7 +216,7 @@ impl Forge for Gitlab {
             Ok(file_info) => {
                 let decoded = BASE64_STANDARD.decode(file_info.content)?;
                 let content = String::from_utf8(decoded)?;
-                return Ok(Some(content));
+                Ok(Some(content))
             }
             Err(gitlab::api::ApiError::GitlabService { status, data }) => {
                 if status == StatusCode::NOT_FOUND {
@@ -286,6 +243,83 @@ impl Forge for Gitlab {
             ))),
         }
     }
+}
+
+#[async_trait]
+impl Forge for Gitlab {
+    fn repo_name(&self) -> String {
+        self.url.name.clone()
+    }
+
+    fn release_link_base_url(&self) -> Url {
+        self.release_link_base_url.clone()
+    }
+
+    fn compare_link_base_url(&self) -> Url {
+        self.compare_link_base_url.clone()
+    }
+
+    fn issue_link_base_url(&self) -> Option<Url> {
+        Some(self.issue_link_base_url.clone())
+    }
+
+    fn default_branch(&self) -> String {
+        self.default_branch.clone()
+    }
+
+    fn set_commit_search_depth(&mut self, depth: usize) {
+        self.commit_search_depth = if depth == 0 { usize::MAX } else { depth }
+    }
+
+    fn set_tag_search_depth(&mut self, depth: usize) {
+        self.tag_search_depth = if depth == 0 { usize::MAX } else { depth }
+    }
+
+    async fn list_files(&self, branch: Option<String>) -> Result<Vec<String>> {
+        let r#ref = branch.unwrap_or_else(|| self.default_branch.clone());
+
+        let endpoint = Tree::builder()
+            .project(&self.project_id)
+            .ref_(r#ref.as_str())
+            .recursive(true)
+            .build()?;
+
+        let entries: Vec<TreeEntry> = paged(
+            endpoint,
+            Pagination::AllPerPageLimit(DEFAULT_PAGE_SIZE.into()),
+        )
+        .query_async(&self.gl)
+        .await?;
+
+        Ok(entries
+            .into_iter()
+            .filter(|entry| entry.kind == "blob")
+            .map(|entry| entry.path)
+            .collect())
+    }
+
+    async fn load_config(
+        &self,
+        branch: Option<String>,
+        config_path: Option<String>,
+    ) -> Result<Config> {
+        load_repository_config(self, branch, config_path).await
+    }
+
+    async fn get_file_content(
+        &self,
+        req: GetFileContentRequest,
+    ) -> Result<Option<String>> {
+        self.fetch_file_content(&self.project_id, req).await
+    }
+
+    async fn get_external_file_content(
+        &self,
+        repo: &str,
+        req: GetFileContentRequest,
+    ) -> Result<Option<String>> {
+        self.fetch_file_content(repo.trim_matches('/'), req).await
+    }
 
     async fn get_release_by_tag(
         &self,
@@ -490,12 +524,38 @@ impl Forge for Gitlab {
                 message: commit.message.clone().trim().into(),
                 timestamp,
                 files,
+                author_username: None,
             })
         }
 
         Ok(forge_commits)
     }
 
+    async fn get_commit_authors(
+        &self,
+        sha: &str,
+    ) -> Result<Vec<ForgeCommitAuthor>> {
+        let endpoint = Commits::builder()
+            .project(&self.project_id)
+            .ref_name(sha)
+            .order(CommitsOrder::Default)
+            .build()?;
+
+        let result: Vec<GitlabCommit> = paged(endpoint, Pagination::All)
+            .query_async(&self.gl)
+            .await?;
+
+        Ok(result
+            .into_iter()
+            .map(|commit| ForgeCommitAuthor {
+                sha: commit.id,
+                name: commit.author_name,
+                email: commit.author_email,
+                username: None,
+            })
+            .collect())
+    }
+
     async fn get_merged_pull_request_for_commit(
         &self,
         commit_sha: &str,
@@ -543,10 +603,9 @@ impl Forge for Gitlab {
                 update_type = CommitActionType::Create;
             }
 
-            if matches!(change.update_type, FileUpdateType::Prepend)
-                && let Some(existing_content) = existing_content
-            {
-                content = format!("{content}\n{existing_content}");
+            if let Some(existing_content) = existing_content {
+                content =
+                    change.update_type.apply(&content, &existing_content, "\n");
             }
 
             let action = CommitAction::builder()
@@ -591,10 +650,9 @@ impl Forge for Gitlab {
                 update_type = CommitActionType::Create;
             }
 
-            if matches!(change.update_type, FileUpdateType::Prepend)
-                && let Some(existing_content) = existing_content.clone()
-            {
-                content = format!("{content}{existing_content}");
+            if let Some(existing_content) = existing_content.as_deref() {
+                content =
+                    change.update_type.apply(&content, existing_content, "");
             }
 
             if content == existing_content.unwrap_or_default() {
@@ -9,6 +9,13 @@ pub struct FileInfo {
     pub content: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct TreeEntry {
+    pub path: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct MergeRequestInfo {
     pub iid: u64,
@@ -16,7 +16,8 @@ use url::Url;
 
 use crate::{
     config::{
-        Config, DEFAULT_CONFIG_FILE,
+        Config,
+        loader::load_repository_config,
         repository::{DEFAULT_COMMIT_SEARCH_DEPTH, DEFAULT_TAG_SEARCH_DEPTH},
     },
     forge::{
@@ -24,9 +25,9 @@ use crate::{
         request::{
             Commit, CreateCommitRequest, CreatePrRequest,
             CreateReleaseBranchRequest, FileChange, FileUpdateType,
-            ForgeCommit, ForgeCommitPR, GetFileContentRequest, GetPrRequest,
-            PrLabelsRequest, PullRequest, ReleaseByTagResponse, Tag,
-            UpdatePrRequest,
+            ForgeCommit, ForgeCommitAuthor, ForgeCommitPR,
+            GetFileContentRequest, GetPrRequest, PrLabelsRequest, PullRequest,
+            ReleaseByTagResponse, Tag, UpdatePrRequest,
         },
         traits::Forge,
     },
@@ -214,7 +215,7 @@ impl LocalRepo {
                 change.update_type
             );
             let mut content = change.content.clone();
-            if change.update_type == FileUpdateType::Prepend {
+            if change.update_type != FileUpdateType::Replace {
                 if let Ok(existing_content) =
                     fs::read_to_string(&full_path).await
                 {
@@ -223,7 +224,11 @@ impl LocalRepo {
                         full_path.display(),
                         existing_content.len()
                     );
-                    content = format!("{content}\n{existing_content}");
+                    content = change.update_type.apply(
+                        &content,
+                        &existing_content,
+                        "\n",
+                    );
                 } else {
                     log::debug!(
                         "local_commit: no existing file at {}, creating new",
@@ -379,6 +384,13 @@ impl Forge for LocalRepo {
         }
     }
 
+    fn issue_link_base_url(&self) -> Option<Url> {
+        // without a remote there is no issue tracker to link to
+        self.remote
+            .as_ref()
+            .and_then(|remote| remote.forge.issue_link_base_url())
+    }
+
     fn set_commit_search_depth(&mut self, depth: usize) {
         self.commit_search_depth = if depth == 0 { usize::MAX } else { depth }
     }
@@ -399,31 +411,53 @@ impl Forge for LocalRepo {
         Ok(Some(content))
     }
 
+    async fn get_external_file_content(
+        &self,
+        repo: &str,
+        req: GetFileContentRequest,
+    ) -> Result<Option<String>> {
+        if let Some(remote) = self.remote.as_ref() {
+            remote.forge.get_external_file_content(repo, req).await
+        } else {
+            Err(ReleasaurusError::forge(format!(
+                "cannot read {} from {repo}: no remote forge configured",
+                req.path
+            )))
+        }
+    }
+
+    async fn list_files(&self, branch: Option<String>) -> Result<Vec<String>> {
+        let repo = self.repo.lock().await;
+
+        let tree = match branch {
+            Some(branch) => repo
+                .find_branch(&branch, BranchType::Local)?
+                .get()
+                .peel_to_tree()?,
+            None => repo.head()?.peel_to_tree()?,
+        };
+
+        let mut files = vec![];
+
+        tree.walk(TreeWalkMode::PreOrder, |dir, entry| {
+            if entry.kind() == Some(git2::ObjectType::Blob)
+                && let Ok(name) = entry.name()
+            {
+                // `dir` is empty at the root and ends with "/" otherwise
+                files.push(format!("{dir}{name}"));
+            }
+            0
+        })?;
+
+        Ok(files)
+    }
+
     async fn load_config(
         &self,
         branch: Option<String>,
         config_path: Option<String>,
     ) -> Result<Config> {
-        let is_custom = config_path.is_some();
-        let path =
-            config_path.unwrap_or_else(|| DEFAULT_CONFIG_FILE.to_string());
-        if let Some(content) = self
-            .get_file_content(GetFileContentRequest {
-                branch,
-                path: path.clone(),
-            })
-            .await?
-        {
-            let config: Config = toml::from_str(&content)?;
-            Ok(config)
-        } else if is_custom {
-            Err(ReleasaurusError::invalid_config(format!(
-                "configuration file not found at: {path}"
-            )))
-        } else {
-            log::info!("repository configuration not found: using default");
-            Ok(Config::default())
-        }
+        load_repository_config(self, branch, config_path).await
     }
 
     async fn get_release_by_tag(
@@ -614,6 +648,7 @@ impl Forge for LocalRepo {
             forge_commits.push(ForgeCommit {
                 author_email: commit.author().email().unwrap_or("").to_string(),
                 author_name: commit.author().name().unwrap_or("").to_string(),
+                author_username: None,
                 files,
                 id: commit.id().to_string(),
                 link: "".into(),
@@ -634,6 +669,30 @@ impl Forge for LocalRepo {
         Ok(forge_commits)
     }
 
+    async fn get_commit_authors(
+        &self,
+        sha: &str,
+    ) -> Result<Vec<ForgeCommitAuthor>> {
+        let repo = self.repo.lock().await;
+
+        let mut revwalk = repo.revwalk()?;
+        revwalk.set_sorting(Sort::TIME)?;
+        revwalk.push(repo.revparse_single(sha)?.id())?;
+
+        let authors = revwalk
+            .filter_map(|id| id.ok())
+            .filter_map(|id| repo.find_commit(id).ok())
+            .map(|commit| ForgeCommitAuthor {
+                sha: commit.id().to_string(),
+                name: commit.author().name().unwrap_or("").to_string(),
+                email: commit.author().email().unwrap_or("").to_string(),
+                username: None,
+            })
+            .collect();
+
+        Ok(authors)
+    }
+
     async fn get_merged_pull_request_for_commit(
         &self,
         commit_sha: &str,
@@ -1053,6 +1112,39 @@ mod tests {
         assert_eq!(written, "# 1.0.0\n\n- first release\n");
     }
 
+    /// `local_commit` with `InsertAfter` must keep the file's title and
+    /// intro above the new entry.
+    #[tokio::test]
+    async fn local_commit_with_insert_after_keeps_header() {
+        let dir = TempDir::new().unwrap();
+        let repo = git2::Repository::init(dir.path()).unwrap();
+        configure_git_user(&repo);
+
+        let file_path = dir.path().join("CHANGELOG.md");
+        std::fs::write(&file_path, "# Changelog\n\n# 1.0.0\n").unwrap();
+        let mut index = repo.index().unwrap();
+        index
+            .add_path(std::path::Path::new("CHANGELOG.md"))
+            .unwrap();
+        index.write().unwrap();
+        add_commit(&repo, "initial commit");
+
+        let forge = LocalRepo::new(dir.path(), None).await.unwrap();
+        let change = FileChange {
+            path: "CHANGELOG.md".to_string(),
+            content: "# 1.1.0\n".to_string(),
+            update_type: FileUpdateType::InsertAfter("^# Changelog$".into()),
+        };
+
+        forge
+            .local_commit("chore: update changelog", &[change])
+            .await
+            .unwrap();
+
+        let written = std::fs::read_to_string(&file_path).unwrap();
+        assert_eq!(written, "# Changelog\n\n# 1.1.0\n\n# 1.0.0\n");
+    }
+
     /// `local_commit` must resolve relative `FileChange` paths against
     /// `repo_path` so that file I/O works regardless of the process CWD.
     #[tokio::test]
@@ -1128,8 +1220,8 @@ tag_search_depth = 10
             .unwrap();
 
         assert_eq!(config.repository.base_branch, Some("develop".to_string()));
-        assert_eq!(config.repository.first_release_search_depth, 50);
-        assert_eq!(config.repository.tag_search_depth, 10);
+        assert_eq!(config.repository.first_release_search_depth(), 50);
+        assert_eq!(config.repository.tag_search_depth(), 10);
     }
 
     /// `load_config` with no config path reads the default
@@ -1157,8 +1249,33 @@ tag_search_depth = 10
         let config = forge.load_config(None, None).await.unwrap();
 
         assert_eq!(config.repository.base_branch, Some("develop".to_string()));
-        assert_eq!(config.repository.first_release_search_depth, 50);
-        assert_eq!(config.repository.tag_search_depth, 10);
+        assert_eq!(config.repository.first_release_search_depth(), 50);
+        assert_eq!(config.repository.tag_search_depth(), 10);
+    }
+
+    /// `list_files` returns every committed file relative to the repository
+    /// root, including nested ones.
+    #[tokio::test]
+    async fn list_files_walks_the_committed_tree() {
+        let dir = TempDir::new().unwrap();
+        let repo = git2::Repository::init(dir.path()).unwrap();
+        configure_git_user(&repo);
+
+        std::fs::create_dir_all(dir.path().join("crates/core")).unwrap();
+        std::fs::write(dir.path().join("Cargo.toml"), "").unwrap();
+        std::fs::write(dir.path().join("crates/core/Cargo.toml"), "").unwrap();
+        let mut index = repo.index().unwrap();
+        for path in ["Cargo.toml", "crates/core/Cargo.toml"] {
+            index.add_path(std::path::Path::new(path)).unwrap();
+        }
+        index.write().unwrap();
+        add_commit(&repo, "initial commit");
+
+        let forge = LocalRepo::new(dir.path(), None).await.unwrap();
+        let mut files = forge.list_files(None).await.unwrap();
+        files.sort();
+
+        assert_eq!(files, vec!["Cargo.toml", "crates/core/Cargo.toml"]);
     }
 
     /// `load_config` with a non-existent custom path returns an error.
@@ -1200,12 +1317,12 @@ tag_search_depth = 10
             default.repository.base_branch
         );
         assert_eq!(
-            config.repository.first_release_search_depth,
-            default.repository.first_release_search_depth
+            config.repository.first_release_search_depth(),
+            default.repository.first_release_search_depth()
         );
         assert_eq!(
-            config.repository.tag_search_depth,
-            default.repository.tag_search_depth
+            config.repository.tag_search_depth(),
+            default.repository.tag_search_depth()
         );
         assert_eq!(
             config.repository.separate_pull_requests,
@@ -1261,6 +1378,18 @@ tag_search_depth = 10
         forge.push_tag("v1.0.0").await.unwrap();
     }
 
+    /// Without a remote there is no issue tracker, so `#123` references
+    /// must stay unlinked rather than point at the local repository.
+    #[tokio::test]
+    async fn issue_link_base_url_without_remote_is_none() {
+        let dir = TempDir::new().unwrap();
+        let repo = git2::Repository::init(dir.path()).unwrap();
+        add_commit(&repo, "initial commit");
+
+        let forge = LocalRepo::new(dir.path(), None).await.unwrap();
+        assert!(forge.issue_link_base_url().is_none());
+    }
+
     /// A tag that lives on a divergent branch must NOT be returned
     /// when querying a branch that does not have that commit in its
     /// history.
@@ -8,10 +8,10 @@ use crate::{
     forge::{
         request::{
             Commit, CreateCommitRequest, CreatePrRequest,
-            CreateReleaseBranchRequest, ForgeCommit, ForgeCommitPR,
-            GetFileContentRequest, GetPrRequest, PrLabelsRequest,
-            PrMetadataBlock, PullRequest, ReleaseByTagResponse, Tag,
-            UpdatePrRequest,
+            CreateReleaseBranchRequest, ForgeCommit, ForgeCommitAuthor,
+            ForgeCommitPR, GetFileContentRequest, GetPrRequest,
+            PrLabelsRequest, PrMetadataBlock, PullRequest,
+            ReleaseByTagResponse, Tag, UpdatePrRequest,
         },
         traits::{FileLoader, Forge},
     },
@@ -36,6 +36,7 @@ pub struct ForgeManager {
     default_branch: OnceLock<String>,
     release_link_base_url: OnceLock<Url>,
     compare_link_base_url: OnceLock<Url>,
+    issue_link_base_url: OnceLock<Option<Url>>,
     options: ForgeOptions,
 }
 
@@ -49,6 +50,7 @@ impl ForgeManager {
             default_branch: OnceLock::new(),
             release_link_base_url: OnceLock::new(),
             compare_link_base_url: OnceLock::new(),
+            issue_link_base_url: OnceLock::new(),
             options,
         }
     }
@@ -67,6 +69,12 @@ impl ForgeManager {
             .get_or_init(|| self.forge.compare_link_base_url())
     }
 
+    pub fn issue_link_base_url(&self) -> Option<&Url> {
+        self.issue_link_base_url
+            .get_or_init(|| self.forge.issue_link_base_url())
+            .as_ref()
+    }
+
     pub fn default_branch(&self) -> &str {
         self.default_branch
             .get_or_init(|| self.forge.default_branch())
@@ -87,6 +95,21 @@ impl ForgeManager {
         result
     }
 
+    pub async fn list_files(
+        &self,
+        branch: Option<String>,
+    ) -> Result<Vec<String>> {
+        log::debug!("Listing repository files (branch: {:?})", branch);
+
+        let result = self.forge.list_files(branch).await;
+
+        if let Err(e) = &result {
+            log::error!("Failed to list repository files: {}", e);
+        }
+
+        result
+    }
+
     pub async fn load_config(
         &self,
         branch: Option<String>,
@@ -136,6 +159,17 @@ impl ForgeManager {
         Ok(tags.into_iter().next())
     }
 
+    /// All tags matching `prefix` on `branch`, in no guaranteed order.
+    pub async fn get_tags_for_prefix(
+        &self,
+        prefix: &str,
+        branch: &str,
+    ) -> Result<Vec<Tag>> {
+        self.forge
+            .get_latest_tags_for_prefix(prefix, branch, None)
+            .await
+    }
+
     pub async fn get_tags_for_prefix_since(
         &self,
         prefix: &str,
@@ -199,6 +233,14 @@ impl ForgeManager {
         self.forge.get_commits(branch.clone(), sha).await
     }
 
+    pub async fn get_commit_authors(
+        &self,
+        sha: &str,
+    ) -> Result<Vec<ForgeCommitAuthor>> {
+        log::debug!("getting commit authors up to sha: {sha}");
+        self.forge.get_commit_authors(sha).await
+    }
+
     pub async fn get_merged_pull_request_for_commit(
         &self,
         commit_sha: &str,
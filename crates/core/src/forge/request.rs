@@ -1,7 +1,10 @@
 use derive_builder::Builder;
+use regex::Regex;
 use serde::{Deserialize, Serialize, ser::SerializeStruct};
 use std::{fmt::Display, hash::Hash};
 
+use crate::changelog::keep_a_changelog::{self, ReleaseLinks};
+
 /// Forge-specific encoding of PR metadata: content to embed inside the div
 /// and an optional extra HTML attribute on the div tag.
 #[derive(Debug)]
@@ -71,10 +74,83 @@ pub struct PrLabelsRequest {
 
 /// How to apply file content changes during branch creation.
 #[allow(unused)]
-#[derive(Debug, Copy, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq)]
 pub enum FileUpdateType {
     Replace,
     Prepend,
+    /// Insert after the line matching a regex, e.g. a changelog's
+    /// `<!-- releasaurus:insert -->` marker. Prepends when nothing matches
+    InsertAfter(String),
+    /// Insert after the line matching `marker` like `InsertAfter`, but
+    /// append a `section` line and the content when nothing matches, e.g.
+    /// the `%changelog` section an RPM spec is missing
+    InsertInSection {
+        marker: String,
+        section: String,
+    },
+    /// Merge `entry` into a Keep a Changelog file, moving the content of its
+    /// Unreleased section into the new version section. The change's own
+    /// content is the file created when none exists yet
+    KeepAChangelog {
+        entry: String,
+        links: ReleaseLinks,
+    },
+}
+
+impl FileUpdateType {
+    /// Combines new `content` with a file's `existing` content. `separator`
+    /// goes between the new content and the existing content following it.
+    pub fn apply(
+        &self,
+        content: &str,
+        existing: &str,
+        separator: &str,
+    ) -> String {
+        match self {
+            Self::Replace => content.to_string(),
+            Self::Prepend => format!("{content}{separator}{existing}"),
+            Self::InsertAfter(marker) => {
+                let Some(end) = Regex::new(&format!("(?m){marker}"))
+                    .ok()
+                    .and_then(|re| re.find(existing))
+                    .map(|m| m.end())
+                else {
+                    return Self::Prepend.apply(content, existing, separator);
+                };
+
+                // insert below the whole line the marker ends on
+                let line_end = existing[end..]
+                    .find('\n')
+                    .map(|i| end + i + 1)
+                    .unwrap_or(existing.len());
+
+                let (head, rest) = existing.split_at(line_end);
+                let newline = if head.ends_with('\n') { "" } else { "\n" };
+
+                format!(
+                    "{head}{newline}\n{content}{separator}{}",
+                    rest.trim_start_matches('\n')
+                )
+            }
+            Self::InsertInSection { marker, section } => {
+                let found = Regex::new(&format!("(?m){marker}"))
+                    .is_ok_and(|re| re.is_match(existing));
+
+                if found {
+                    return Self::InsertAfter(marker.clone())
+                        .apply(content, existing, separator);
+                }
+
+                let existing = existing.trim_end();
+                let gap = if existing.is_empty() { "" } else { "\n\n" };
+
+                format!("{existing}{gap}{section}\n\n{content}")
+            }
+            Self::KeepAChangelog { entry, links } => {
+                keep_a_changelog::insert_release(entry, existing, links)
+            }
+        }
+    }
 }
 
 /// File modification for branch creation, supporting updates and new files.
@@ -169,6 +245,8 @@ pub struct ForgeCommit {
     pub link: String,
     pub author_name: String,
     pub author_email: String,
+    /// Forge account of the author, on forges that link commits to one
+    pub author_username: Option<String>,
     pub merge_commit: bool,
     pub message: String,
     pub timestamp: i64,
@@ -176,6 +254,18 @@ pub struct ForgeCommit {
     pub pr: Option<ForgeCommitPR>,
 }
 
+/// Author of a commit as the forge reports it, without the rest of the
+/// commit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ForgeCommitAuthor {
+    /// Sha of the authored commit
+    pub sha: String,
+    pub name: String,
+    pub email: String,
+    /// Forge account of the author, on forges that link commits to one
+    pub username: Option<String>,
+}
+
 impl PartialEq for ForgeCommit {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -187,3 +277,68 @@ impl Hash for ForgeCommit {
         self.id.hash(state);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MARKER: &str = "<!-- releasaurus:insert -->";
+
+    #[test]
+    fn inserts_below_the_marker_line() {
+        let update = FileUpdateType::InsertAfter(regex::escape(MARKER));
+        let existing = format!("# Changelog\n\nIntro.\n{MARKER}\n\n# 1.0.0\n");
+
+        let once = update.apply("# 1.1.0\n", &existing, "\n");
+        assert_eq!(
+            once,
+            format!("# Changelog\n\nIntro.\n{MARKER}\n\n# 1.1.0\n\n# 1.0.0\n")
+        );
+
+        // spacing stays the same release after release
+        assert_eq!(
+            update.apply("# 1.2.0\n", &once, "\n"),
+            format!(
+                "# Changelog\n\nIntro.\n{MARKER}\n\n# 1.2.0\n\n# 1.1.0\n\n\
+                 # 1.0.0\n"
+            )
+        );
+    }
+
+    #[test]
+    fn insert_after_prepends_without_a_match() {
+        let update = FileUpdateType::InsertAfter("^## Releases$".into());
+
+        assert_eq!(
+            update.apply("# 1.1.0\n", "# 1.0.0\n", "\n"),
+            "# 1.1.0\n\n# 1.0.0\n"
+        );
+        assert_eq!(
+            update.apply("# 1.1.0\n", "intro\n## Releases", "\n"),
+            "intro\n## Releases\n\n# 1.1.0\n\n"
+        );
+    }
+
+    #[test]
+    fn insert_in_section_appends_a_missing_section() {
+        let update = FileUpdateType::InsertInSection {
+            marker: r"^%changelog[ \t]*$".into(),
+            section: "%changelog".into(),
+        };
+        let spec = "Name: app\nVersion: 1.0.0\n\n%files\n/usr/bin/app\n";
+
+        let once = update.apply("* entry 1.1.0\n", spec, "\n");
+        assert_eq!(
+            once,
+            "Name: app\nVersion: 1.0.0\n\n%files\n/usr/bin/app\n\n\
+             %changelog\n\n* entry 1.1.0\n"
+        );
+
+        // the appended section takes the next entry like any other
+        assert_eq!(
+            update.apply("* entry 1.2.0\n", &once, "\n"),
+            "Name: app\nVersion: 1.0.0\n\n%files\n/usr/bin/app\n\n\
+             %changelog\n\n* entry 1.2.0\n\n* entry 1.1.0\n"
+        );
+    }
+}
@@ -478,4 +478,14 @@ path = "test-package"
         1,
         "expected exactly one commit after the release tag"
     );
+
+    ////////////////////////////////////////////////////////////////////////////
+    // get_commit_authors(sha) -> an author for the tag and each earlier commit
+    ////////////////////////////////////////////////////////////////////////////
+    log::info!("getting commit authors up to release tag");
+    let authors = forge.get_commit_authors(&current_tag.sha).await.unwrap();
+    assert!(
+        !authors.is_empty(),
+        "expected an author for at least the tagged commit"
+    );
 }
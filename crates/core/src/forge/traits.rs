@@ -10,9 +10,10 @@ use crate::{
     config::Config,
     forge::request::{
         Commit, CreateCommitRequest, CreatePrRequest,
-        CreateReleaseBranchRequest, ForgeCommit, ForgeCommitPR,
-        GetFileContentRequest, GetPrRequest, PrLabelsRequest, PrMetadataBlock,
-        PullRequest, ReleaseByTagResponse, Tag, UpdatePrRequest,
+        CreateReleaseBranchRequest, ForgeCommit, ForgeCommitAuthor,
+        ForgeCommitPR, GetFileContentRequest, GetPrRequest, PrLabelsRequest,
+        PrMetadataBlock, PullRequest, ReleaseByTagResponse, Tag,
+        UpdatePrRequest,
     },
     result::Result,
 };
@@ -28,6 +29,9 @@ pub trait Forge: Any + Send + Sync {
     fn release_link_base_url(&self) -> Url;
     /// Get the base URL for comparing releases and showing diffs
     fn compare_link_base_url(&self) -> Url;
+    /// Get the base URL issue numbers are appended to for issue links, if
+    /// the repository has an issue tracker to link to
+    fn issue_link_base_url(&self) -> Option<Url>;
     /// Fetch the default branch name (e.g., "main" or "master").
     fn default_branch(&self) -> String;
     /// Sets the commit search depth when fetching commit for the first release.
@@ -48,6 +52,17 @@ pub trait Forge: Any + Send + Sync {
         &self,
         req: GetFileContentRequest,
     ) -> Result<Option<String>>;
+    /// Fetch file content from another repository on the same forge host,
+    /// identified by its `owner/name` path, returning None if the file
+    /// doesn't exist.
+    async fn get_external_file_content(
+        &self,
+        repo: &str,
+        req: GetFileContentRequest,
+    ) -> Result<Option<String>>;
+    /// List the path of every file in the repository tree at `branch` (or
+    /// the default branch), relative to the repository root.
+    async fn list_files(&self, branch: Option<String>) -> Result<Vec<String>>;
     /// Retrieves the release notes for a specified tag
     async fn get_release_by_tag(
         &self,
@@ -79,6 +94,13 @@ pub trait Forge: Any + Send + Sync {
         branch: Option<String>,
         sha: Option<String>,
     ) -> Result<Vec<ForgeCommit>>;
+    /// Lists the author of `sha` and of every commit before it, newest
+    /// first. Unlike [`Self::get_commits`] the search depth doesn't apply
+    /// and no file lists are fetched, since only the identities are needed.
+    async fn get_commit_authors(
+        &self,
+        sha: &str,
+    ) -> Result<Vec<ForgeCommitAuthor>>;
     /// Retrieves the merged PR that introduced `commit_sha`, if any.
     ///
     /// `branch` scopes the search to PRs that targeted it, defaulting to the
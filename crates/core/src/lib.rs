@@ -53,8 +53,9 @@
 //!         forge_manager.load_config(None, None).await?,
 //!     );
 //!
-//!     // 3. Resolve packages and build config.
-//!     let resolved_config = Resolver::builder()
+//!     // 3. Resolve packages and build config, normalizing commit
+//!     //    authors with the repository's .mailmap (if any).
+//!     let mut resolver = Resolver::builder()
 //!         .toml_config(Rc::clone(&config))
 //!         .repo_name(forge_manager.repo_name())
 //!         .repo_default_branch(forge_manager.default_branch())
@@ -64,11 +65,16 @@
 //!         .compare_link_base_url(
 //!             forge_manager.compare_link_base_url().clone(),
 //!         )
+//!         .issue_link_base_url(forge_manager.issue_link_base_url().cloned())
 //!         .global_overrides(GlobalOverrides::default())
 //!         .package_overrides(HashMap::new())
 //!         .commit_modifiers(CommitModifiers::default())
-//!         .build()?
-//!         .resolve(config.packages.clone())?;
+//!         .build()?;
+//!     resolver.load_mailmap(&forge_manager).await?;
+//!
+//!     // Expand glob package entries, then resolve.
+//!     let packages = resolver.discover_packages(&forge_manager).await?;
+//!     let resolved_config = resolver.resolve(packages)?;
 //!
 //!     // 4. Build the orchestrator and run the pipeline.
 //!     let fm = Rc::new(forge_manager);
@@ -85,6 +91,7 @@
 //!
 //! - [`analyzer`] — conventional commit parsing and version
 //!   calculation
+//! - [`changelog`] — changelog file formats such as Keep a Changelog
 //! - [`config`] — TOML configuration types and deserialization;
 //!   [`config::overrides`] holds the runtime override types callers
 //!   supply from CLI flags
@@ -100,15 +107,18 @@
 //! - [`packages`] — package lifecycle types
 //!   (`ResolvedPackage` → `PreparedPackage` → `AnalyzedPackage` →
 //!   `ReleasablePackage` → `ReleasePRPackage`)
+//! - [`scaffold`] — package detection behind `releasaurus init`
 //! - [`updater`] — language-specific version file updaters
 //!
 //! [Releasaurus]: https://releasaurus.rgon.io
 
 pub mod analyzer;
+pub mod changelog;
 pub mod config;
 pub mod forge;
 pub mod orchestrator;
 pub mod packages;
 pub mod resolver;
 pub mod result;
+pub mod scaffold;
 pub mod updater;
@@ -18,6 +18,7 @@ use crate::{
         },
     },
     orchestrator::{
+        changelog_regenerator::ChangelogRegenerator,
         package_processor::{PackageProcessor, PrBranchResult},
         pr_body::parse_pr_body,
     },
@@ -28,6 +29,7 @@ use crate::{
     result::{ReleasaurusError, Result},
 };
 
+pub mod changelog_regenerator;
 pub mod commit_fetcher;
 pub mod package_processor;
 pub mod pr_body;
@@ -166,7 +168,10 @@ impl Orchestrator {
             .prepare_packages(target.as_deref())
             .await?;
 
-        let analyzed = self.package_processor.analyze_packages(prepared)?;
+        let analyzed = self
+            .package_processor
+            .analyze_related_packages(prepared)
+            .await?;
 
         let releasable =
             self.package_processor.releasable_packages(analyzed).await?;
@@ -327,6 +332,52 @@ impl Orchestrator {
         Ok(())
     }
 
+    /// Rebuild changelog files from every tag and commit them to the
+    /// base branch.
+    ///
+    /// Each package's history is split at its tags and every range is
+    /// rendered with the current configuration, replacing the existing
+    /// entries. If `target` is `Some`, only that package is processed.
+    pub async fn regenerate_changelogs(
+        &self,
+        target: Option<String>,
+    ) -> Result<()> {
+        if let Some(target_name) = target.as_ref()
+            && !self.config.package_configs.hash().contains_key(target_name)
+        {
+            return Err(ReleasaurusError::InvalidArgs(format!(
+                "unknown package: {target_name}"
+            )));
+        }
+
+        let file_changes = ChangelogRegenerator::new(
+            Rc::clone(&self.config),
+            Rc::clone(&self.forge),
+        )
+        .file_changes(target.as_deref())
+        .await?;
+
+        if file_changes.is_empty() {
+            log::info!("no tagged releases found: nothing to regenerate");
+            return Ok(());
+        }
+
+        let req = CreateCommitRequest {
+            target_branch: self.config.base_branch.to_string(),
+            file_changes,
+            message: format!(
+                "chore({}): regenerate changelog",
+                self.config.base_branch
+            ),
+        };
+
+        let commit = self.forge.create_commit(req).await?;
+
+        log::info!("created commit: {}", commit.sha);
+
+        Ok(())
+    }
+
     /// Fetches the most recent release for each package
     /// Packages without releases are omitted
     pub async fn get_current_releases(
@@ -372,7 +423,10 @@ impl Orchestrator {
     ) -> Result<Vec<SerializableReleasablePackage>> {
         let prepared = self.package_processor.prepare_packages(package).await?;
 
-        let analyzed = self.package_processor.analyze_packages(prepared)?;
+        let analyzed = self
+            .package_processor
+            .analyze_related_packages(prepared)
+            .await?;
 
         let mut releasable = self
             .package_processor
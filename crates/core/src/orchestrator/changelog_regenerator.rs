@@ -0,0 +1,544 @@
+//! Rebuilds changelog files from a package's full tag history.
+
+use std::rc::Rc;
+
+use crate::{
+    analyzer::{AnalyzeOptions, Analyzer, release::ReleaseAnchor},
+    changelog::keep_a_changelog::{self, ReleaseLinks},
+    config::changelog::ChangelogFormat,
+    forge::{
+        manager::ForgeManager,
+        request::{
+            FileChange, FileUpdateType, ForgeCommit, ForgeCommitAuthor,
+            GetFileContentRequest, Tag,
+        },
+    },
+    orchestrator::commit_fetcher::CommitFetcher,
+    packages::resolved::{ChangelogFile, ResolvedPackage},
+    resolver::ResolvedConfig,
+    result::Result,
+};
+
+pub struct ChangelogRegenerator {
+    config: Rc<ResolvedConfig>,
+    forge: Rc<ForgeManager>,
+    commit_fetcher: CommitFetcher,
+}
+
+impl ChangelogRegenerator {
+    pub fn new(config: Rc<ResolvedConfig>, forge: Rc<ForgeManager>) -> Self {
+        Self {
+            commit_fetcher: CommitFetcher::new(
+                Rc::clone(&config),
+                Rc::clone(&forge),
+            ),
+            config,
+            forge,
+        }
+    }
+
+    /// Renders the complete changelog files of every package, or just
+    /// `target`, from each tag matching the package's `tag_prefix`. Packages
+    /// without tags or with changelogs disabled produce no changes.
+    pub async fn file_changes(
+        &self,
+        target: Option<&str>,
+    ) -> Result<Vec<FileChange>> {
+        let mut packages = self
+            .config
+            .package_configs
+            .hash()
+            .values()
+            .filter(|p| target.is_none_or(|t| p.name == t))
+            .filter(|p| !p.changelog_files.is_empty())
+            .collect::<Vec<_>>();
+
+        if packages.is_empty() {
+            return Ok(vec![]);
+        }
+
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // every range ends at a tag on the base branch, so one walk of its
+        // history serves all packages
+        let commits = self
+            .forge
+            .get_commits(Some(self.config.base_branch.clone()), None)
+            .await?;
+
+        let mut changes = vec![];
+
+        for package in packages {
+            changes.extend(self.package_file_changes(package, &commits).await?);
+        }
+
+        Ok(changes)
+    }
+
+    /// The authors of every commit up to and including `tag`, cut from
+    /// `history` when it reaches the tag and fetched on their own otherwise.
+    async fn previous_authors(
+        &self,
+        package: &ResolvedPackage,
+        history: Option<&[ForgeCommitAuthor]>,
+        tag: Option<&Tag>,
+    ) -> Option<Vec<ForgeCommitAuthor>> {
+        if let (Some(history), Some(tag)) = (history, tag)
+            && let Some(index) = history.iter().position(|a| a.sha == tag.sha)
+        {
+            return Some(history[index..].to_vec());
+        }
+
+        self.commit_fetcher
+            .fetch_previous_authors(package, tag)
+            .await
+    }
+
+    async fn package_file_changes(
+        &self,
+        package: &ResolvedPackage,
+        commits: &[ForgeCommit],
+    ) -> Result<Vec<FileChange>> {
+        let mut tags = self
+            .forge
+            .get_tags_for_prefix(&package.tag_prefix, &self.config.base_branch)
+            .await?;
+
+        if tags.is_empty() {
+            log::warn!(
+                "{}: no tags found for prefix {}, skipping changelog",
+                package.name,
+                package.tag_prefix
+            );
+            return Ok(vec![]);
+        }
+
+        tags.sort_by(|a, b| a.semver.cmp(&b.semver));
+
+        let analyzer = Analyzer::new(&package.analyzer_config)?;
+
+        // authors up to the newest tag, newest first: each older tag's
+        // authors are a suffix of it, so history is walked once
+        let history = self
+            .commit_fetcher
+            .fetch_previous_authors(package, tags.last())
+            .await;
+
+        // newest first, the order entries appear in the file
+        let mut entries: Vec<(Vec<String>, ReleaseLinks)> = vec![];
+        let mut previous: Option<Tag> = None;
+
+        for tag in tags {
+            let mut range = self.commit_fetcher.filter_commits_for_package(
+                package,
+                previous.as_ref(),
+                commits,
+            );
+
+            if let Some(timestamp) = tag.timestamp {
+                range.retain(|c| c.timestamp <= timestamp);
+            }
+
+            self.commit_fetcher
+                .fetch_merged_commit_prs(package, &mut range)
+                .await;
+
+            let previous_authors = self
+                .previous_authors(
+                    package,
+                    history.as_deref(),
+                    previous.as_ref(),
+                )
+                .await;
+
+            let release = analyzer.analyze_with(
+                range,
+                previous.clone(),
+                AnalyzeOptions {
+                    version: Some(tag.semver.clone()),
+                    anchor: Some(ReleaseAnchor {
+                        sha: tag.sha.clone(),
+                        short_sha: tag.sha.chars().take(8).collect(),
+                        timestamp: tag.timestamp.unwrap_or_default(),
+                    }),
+                    previous_authors,
+                    ..AnalyzeOptions::default()
+                },
+            )?;
+
+            if let Some(mut release) = release {
+                release.tag = tag.clone();
+
+                // only Keep a Changelog files link their versions
+                let links = package
+                    .changelog_files
+                    .iter()
+                    .any(|f| f.format == ChangelogFormat::KeepAChangelog)
+                    .then(|| {
+                        ReleaseLinks::new(
+                            &tag,
+                            &release.tag_compare_link,
+                            self.forge.release_link_base_url(),
+                            self.forge.compare_link_base_url(),
+                        )
+                    })
+                    .transpose()?
+                    .unwrap_or_default();
+
+                entries.insert(
+                    0,
+                    (
+                        package
+                            .changelog_files
+                            .iter()
+                            .map(|file| file.render(&release))
+                            .collect::<Result<Vec<_>>>()?,
+                        links,
+                    ),
+                );
+            }
+
+            previous = Some(tag);
+        }
+
+        if entries.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut changes = vec![];
+
+        for (index, file) in package.changelog_files.iter().enumerate() {
+            let content = match file.format {
+                ChangelogFormat::KeepAChangelog => {
+                    let releases = entries
+                        .iter()
+                        .rev()
+                        .map(|(entry, links)| {
+                            (entry[index].clone(), links.clone())
+                        })
+                        .collect::<Vec<_>>();
+
+                    keep_a_changelog::rebuild(
+                        &self.existing_content(file).await?,
+                        &releases,
+                    )
+                }
+                ChangelogFormat::Markdown
+                | ChangelogFormat::Debian
+                | ChangelogFormat::Rpm => {
+                    let content = entries
+                        .iter()
+                        .map(|(entry, _)| entry[index].as_str())
+                        .collect::<Vec<_>>()
+                        .join("\n\n");
+
+                    self.with_header(file, &format!("{content}\n")).await?
+                }
+            };
+
+            changes.push(FileChange {
+                path: file.path.to_string_lossy().to_string(),
+                content,
+                update_type: FileUpdateType::Replace,
+            });
+        }
+
+        Ok(changes)
+    }
+
+    /// Keeps whatever precedes the insert marker in the existing file, so a
+    /// title or intro survives regeneration. Without a marker the entries
+    /// make up the whole file, except in a spec file, which keeps all of its
+    /// content and gets a changelog section appended.
+    async fn with_header(
+        &self,
+        file: &ChangelogFile,
+        entries: &str,
+    ) -> Result<String> {
+        let existing = self.existing_content(file).await?;
+
+        let update = file.format.insert_update(&file.insert_after);
+
+        Ok(match header(&existing, &file.insert_after) {
+            Some(head) => update.apply(entries, head, ""),
+            None if file.format == ChangelogFormat::Rpm => {
+                update.apply(entries, &existing, "")
+            }
+            None => entries.to_string(),
+        })
+    }
+
+    /// The file's content on the base branch, empty if it doesn't exist.
+    async fn existing_content(&self, file: &ChangelogFile) -> Result<String> {
+        Ok(self
+            .forge
+            .get_file_content(GetFileContentRequest {
+                branch: Some(self.config.base_branch.clone()),
+                path: file.path.to_string_lossy().to_string(),
+            })
+            .await?
+            .unwrap_or_default())
+    }
+}
+
+/// The part of `existing` up to and including the line `insert_after`
+/// matches, if any.
+fn header<'a>(existing: &'a str, insert_after: &str) -> Option<&'a str> {
+    let found = regex::Regex::new(&format!("(?m){insert_after}"))
+        .ok()?
+        .find(existing)?;
+
+    let line_end = existing[found.end()..]
+        .find('\n')
+        .map(|i| found.end() + i + 1)
+        .unwrap_or(existing.len());
+
+    Some(&existing[..line_end])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use semver::Version;
+    use url::Url;
+
+    use crate::{
+        config::{
+            Config,
+            changelog::{ChangelogConfig, PackagingConfig},
+            defaults::DefaultsConfig,
+            overrides::{CommitModifiers, GlobalOverrides},
+            package::PackageConfigBuilder,
+        },
+        forge::{manager::ForgeOptions, traits::MockForge},
+        resolver::Resolver,
+    };
+
+    use super::*;
+
+    fn commit(id: &str, message: &str, timestamp: i64) -> ForgeCommit {
+        ForgeCommit {
+            id: id.into(),
+            short_id: id.into(),
+            message: message.into(),
+            timestamp,
+            files: vec!["src/lib.rs".into()],
+            ..ForgeCommit::default()
+        }
+    }
+
+    fn tag(name: &str, sha: &str, timestamp: i64) -> Tag {
+        Tag {
+            sha: sha.into(),
+            name: name.into(),
+            semver: Version::parse(name.trim_start_matches('v')).unwrap(),
+            timestamp: Some(timestamp),
+        }
+    }
+
+    /// A single package tagged `v1.0.0` at `c1` and `v1.1.0` at `c2`, with
+    /// an unreleased `c3` on top, whose changelog currently holds
+    /// `existing`.
+    fn regenerator(
+        tags: Vec<Tag>,
+        existing: Option<&'static str>,
+    ) -> ChangelogRegenerator {
+        regenerator_with(
+            tags,
+            existing,
+            ChangelogConfig {
+                body: Some(
+                    "{{ tag_name }}:{% for c in commits %} {{ c.title }}\
+                     {% endfor %}"
+                        .into(),
+                ),
+                ..ChangelogConfig::default()
+            },
+            MockForge::new(),
+        )
+    }
+
+    /// Like [`regenerator`], with its own changelog config and a mock
+    /// carrying any further expectations.
+    fn regenerator_with(
+        tags: Vec<Tag>,
+        existing: Option<&'static str>,
+        changelog: ChangelogConfig,
+        mut mock: MockForge,
+    ) -> ChangelogRegenerator {
+        mock.expect_get_commits().times(1).returning(|_, _| {
+            Ok(vec![
+                ForgeCommit {
+                    author_name: "ann".into(),
+                    ..commit("c3", "fix: three", 300)
+                },
+                ForgeCommit {
+                    author_name: "bob".into(),
+                    ..commit("c2", "feat: two", 200)
+                },
+                ForgeCommit {
+                    author_name: "ann".into(),
+                    ..commit("c1", "feat: one", 100)
+                },
+            ])
+        });
+        mock.expect_get_latest_tags_for_prefix()
+            .returning(move |_, _, _| Ok(tags.clone()));
+        mock.expect_get_file_content()
+            .returning(move |_| Ok(existing.map(String::from)));
+
+        let config = Config {
+            defaults: DefaultsConfig {
+                changelog: Some(changelog),
+                ..DefaultsConfig::default()
+            },
+            ..Config::default()
+        };
+
+        let resolved = Resolver::builder()
+            .toml_config(Rc::new(config))
+            .repo_name("test-repo")
+            .repo_default_branch("main")
+            .release_link_base_url(Url::parse("https://example.com/").unwrap())
+            .compare_link_base_url(
+                Url::parse("https://example.com/compare/").unwrap(),
+            )
+            .package_overrides(HashMap::new())
+            .global_overrides(GlobalOverrides::default())
+            .commit_modifiers(CommitModifiers::default())
+            .build()
+            .unwrap()
+            .resolve(vec![
+                PackageConfigBuilder::default()
+                    .name("pkg")
+                    .path(".")
+                    .tag_prefix("v")
+                    .build()
+                    .unwrap(),
+            ])
+            .unwrap();
+
+        ChangelogRegenerator::new(
+            resolved,
+            Rc::new(ForgeManager::new(
+                Box::new(mock),
+                ForgeOptions { dry_run: false },
+            )),
+        )
+    }
+
+    #[tokio::test]
+    async fn renders_one_entry_per_tag_below_the_existing_header() {
+        // tags arrive unordered, as the forges return them
+        let regenerator = regenerator(
+            vec![tag("v1.1.0", "c2", 200), tag("v1.0.0", "c1", 100)],
+            Some("# Changelog\n\n<!-- releasaurus:insert -->\n\nstale\n"),
+        );
+
+        let changes = regenerator.file_changes(None).await.unwrap();
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "CHANGELOG.md");
+        assert_eq!(changes[0].update_type, FileUpdateType::Replace);
+        assert_eq!(
+            changes[0].content,
+            "# Changelog\n\n<!-- releasaurus:insert -->\n\n\
+             v1.1.0: two\n\nv1.0.0: one\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn replaces_the_whole_file_without_a_marker() {
+        let regenerator = regenerator(
+            vec![tag("v1.0.0", "c1", 100), tag("v1.1.0", "c2", 200)],
+            Some("# Changelog\n\nstale\n"),
+        );
+
+        let changes = regenerator.file_changes(None).await.unwrap();
+
+        assert_eq!(changes[0].content, "v1.1.0: two\n\nv1.0.0: one\n");
+    }
+
+    #[tokio::test]
+    async fn appends_a_changelog_section_to_a_spec_without_one() {
+        let regenerator = regenerator_with(
+            vec![tag("v1.0.0", "c1", 100), tag("v1.1.0", "c2", 200)],
+            Some("Name: pkg\nVersion: 1.1.0\n\n%files\n/usr/bin/pkg\n"),
+            ChangelogConfig {
+                path: Some("pkg.spec".into()),
+                format: Some(ChangelogFormat::Rpm),
+                packaging: Some(PackagingConfig {
+                    maintainer: Some("Ann <ann@example.com>".into()),
+                    ..PackagingConfig::default()
+                }),
+                ..ChangelogConfig::default()
+            },
+            MockForge::new(),
+        );
+
+        let changes = regenerator.file_changes(None).await.unwrap();
+
+        assert_eq!(
+            changes[0].content,
+            "Name: pkg\nVersion: 1.1.0\n\n%files\n/usr/bin/pkg\n\n\
+             %changelog\n\n\
+             * Thu Jan 01 1970 Ann <ann@example.com> - 1.1.0-1\n- two\n\n\
+             * Thu Jan 01 1970 Ann <ann@example.com> - 1.0.0-1\n- one\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn fetches_commit_authors_once_for_every_tag() {
+        let mut mock = MockForge::new();
+        mock.expect_get_commit_authors()
+            .withf(|sha| sha == "c3")
+            .times(1)
+            .returning(|_| {
+                Ok(["c3", "c2", "c1"]
+                    .into_iter()
+                    .zip(["ann", "bob", "ann"])
+                    .map(|(sha, name)| ForgeCommitAuthor {
+                        sha: sha.into(),
+                        name: name.into(),
+                        ..ForgeCommitAuthor::default()
+                    })
+                    .collect())
+            });
+
+        let regenerator = regenerator_with(
+            vec![
+                tag("v1.0.0", "c1", 100),
+                tag("v1.1.0", "c2", 200),
+                tag("v1.2.0", "c3", 300),
+            ],
+            None,
+            ChangelogConfig {
+                body: Some(
+                    "{{ tag_name }}:{% for c in new_contributors %} \
+                     {{ c.name }}{% endfor %}"
+                        .into(),
+                ),
+                include_contributors: Some(true),
+                ..ChangelogConfig::default()
+            },
+            mock,
+        );
+
+        let changes = regenerator.file_changes(None).await.unwrap();
+
+        assert_eq!(
+            changes[0].content,
+            "v1.2.0:\n\nv1.1.0: bob\n\nv1.0.0: ann\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn skips_packages_without_tags() {
+        let regenerator = regenerator(vec![], None);
+
+        let changes = regenerator.file_changes(Some("pkg")).await.unwrap();
+
+        assert!(changes.is_empty());
+    }
+}
@@ -5,10 +5,13 @@ use std::{
     rc::Rc,
 };
 
+use git_conventional::Commit as ConventionalCommit;
+
 use crate::{
+    config::repository::ScopeRouting,
     forge::{
         manager::ForgeManager,
-        request::{ForgeCommit, ForgeCommitPR, Tag},
+        request::{ForgeCommit, ForgeCommitAuthor, ForgeCommitPR, Tag},
     },
     packages::resolved::ResolvedPackage,
     resolver::ResolvedConfig,
@@ -33,6 +36,9 @@ pub struct CommitFetcher {
     /// `commit_prs` - this is what lets the per-package summary count only
     /// the links lost to failure, including on cache hits.
     failed_pr_lookups: RefCell<HashSet<String>>,
+    /// Memoized author histories keyed by the tag sha they end at. `None`
+    /// records a failed lookup so it isn't retried for every package.
+    previous_authors: RefCell<HashMap<String, Option<Vec<ForgeCommitAuthor>>>>,
 }
 
 impl CommitFetcher {
@@ -42,6 +48,7 @@ impl CommitFetcher {
             forge,
             commit_prs: RefCell::new(HashMap::new()),
             failed_pr_lookups: RefCell::new(HashSet::new()),
+            previous_authors: RefCell::new(HashMap::new()),
         }
     }
 
@@ -86,6 +93,18 @@ impl CommitFetcher {
                 .chain(package.normalized_additional_paths.iter())
                 .collect();
 
+        let own_scopes = package.scopes.iter().collect::<Vec<_>>();
+        let restrict = self.config.scope_routing == ScopeRouting::Restrict;
+
+        // every scope some package claims, for restrict routing
+        let claimed_scopes = self
+            .config
+            .package_configs
+            .hash()
+            .values()
+            .flat_map(|p| p.scopes.iter())
+            .collect::<Vec<_>>();
+
         let mut package_commits: Vec<ForgeCommit> = vec![];
 
         for commit in commits.iter() {
@@ -102,29 +121,58 @@ impl CommitFetcher {
                     continue;
                 }
             }
-            'file_loop: for file in commit.files.iter() {
+
+            let scopes = commit_scopes(&commit.message);
+            let has_scope = |claimed: &[&String]| {
+                scopes.iter().any(|scope| {
+                    claimed.iter().any(|c| c.eq_ignore_ascii_case(scope))
+                })
+            };
+
+            if has_scope(&own_scopes) {
+                log::debug!(
+                    "{}: including commit for analysis by scope : {}",
+                    package.name,
+                    commit.short_id,
+                );
+                package_commits.push(commit.clone());
+                continue;
+            }
+
+            if restrict && has_scope(&claimed_scopes) {
+                // omit: scope routes the commit to other packages only
+                continue;
+            }
+
+            for file in commit.files.iter() {
                 let file_path = Path::new(file);
-                for package_path in package_paths.iter() {
-                    if file_path.starts_with(package_path) {
-                        log::debug!(
-                            "{}: including commit for analysis : {} : {}",
-                            package.name,
-                            commit.short_id,
-                            // Subject line only. Built inside the macro so
-                            // nothing is computed at the default `Info`
-                            // level, and borrowed rather than allocated.
-                            commit
-                                .message
-                                .lines()
-                                .next()
-                                .unwrap_or_default()
-                                .trim()
-                        );
-
-                        package_commits.push(commit.clone());
-                        break 'file_loop;
-                    }
+
+                // excluded files never count, even inside the package path
+                if package.path_filter.is_excluded(file_path) {
+                    continue;
+                }
+
+                let included = package_paths
+                    .iter()
+                    .any(|path| file_path.starts_with(path))
+                    || package.path_filter.include_globs.is_match(file_path);
+
+                if !included {
+                    continue;
                 }
+
+                log::debug!(
+                    "{}: including commit for analysis : {} : {}",
+                    package.name,
+                    commit.short_id,
+                    // Subject line only. Built inside the macro so
+                    // nothing is computed at the default `Info`
+                    // level, and borrowed rather than allocated.
+                    commit.message.lines().next().unwrap_or_default().trim()
+                );
+
+                package_commits.push(commit.clone());
+                break;
             }
         }
 
@@ -223,6 +271,62 @@ impl CommitFetcher {
         }
     }
 
+    /// Lists the authors of every commit up to and including `tag`, which
+    /// sets apart the first-time contributors in `package`'s next release.
+    ///
+    /// `None` unless `package` includes contributors, so nothing is fetched
+    /// otherwise. Without a tag there is no earlier history, and everyone in
+    /// the first release counts as new.
+    ///
+    /// Like PR links, contributors are cosmetic: a failed lookup is logged
+    /// and leaves new contributors unknown rather than failing the release.
+    pub async fn fetch_previous_authors(
+        &self,
+        package: &ResolvedPackage,
+        tag: Option<&Tag>,
+    ) -> Option<Vec<ForgeCommitAuthor>> {
+        if !package.analyzer_config.include_contributors {
+            log::debug!(
+                "contributors are not enabled for {}: skipping fetch",
+                package.name
+            );
+            return None;
+        }
+
+        let Some(tag) = tag else {
+            return Some(vec![]);
+        };
+
+        // Scoped so the borrow is released before the await below.
+        {
+            if let Some(cached) = self.previous_authors.borrow().get(&tag.sha) {
+                log::debug!("using cached commit authors for tag {}", tag.name);
+                return cached.clone();
+            }
+        }
+
+        log::debug!("fetching commit authors up to tag {}", tag.name);
+
+        let authors = match self.forge.get_commit_authors(&tag.sha).await {
+            Ok(authors) => Some(authors),
+            Err(err) => {
+                log::warn!(
+                    "{}: failed to fetch commit authors up to tag {}: {err}: \
+                     new contributors will not be listed",
+                    package.name,
+                    tag.name
+                );
+                None
+            }
+        };
+
+        self.previous_authors
+            .borrow_mut()
+            .insert(tag.sha.clone(), authors.clone());
+
+        authors
+    }
+
     /// Looks up the PR that introduced `sha`, memoized per sha.
     ///
     /// A sha always has the same answer within a run, and packages share
@@ -384,6 +488,18 @@ impl CommitFetcher {
     }
 }
 
+/// Scopes of a conventional commit subject, e.g. `api` and `web` for
+/// `fix(api,web): ...`. Empty for commits without a scope.
+fn commit_scopes(message: &str) -> Vec<&str> {
+    let subject = message.lines().next().unwrap_or_default().trim();
+
+    ConventionalCommit::parse(subject)
+        .ok()
+        .and_then(|cc| cc.scope())
+        .map(|scope| scope.as_str().split(',').map(str::trim).collect())
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -397,13 +513,16 @@ mod tests {
             package::{PackageConfig, PackageConfigBuilder},
             prerelease::{PrereleaseConfig, PrereleaseStrategy},
             release_type::ReleaseType,
+            repository::RepositoryConfig,
             versioning::VersioningConfig,
         },
         forge::{
             manager::ForgeOptions, request::ForgeCommitBuilder,
             traits::MockForge,
         },
-        resolver::ResolverBuilder,
+        resolver::{
+            ResolverBuilder, resolvers::path_utils::compile_path_filter,
+        },
     };
 
     use super::*;
@@ -491,6 +610,29 @@ mod tests {
     /// routing a real value through resolution, and whose forge is `mock`.
     /// Pair it with [`pr_link_package`] to get the package argument.
     fn create_pr_link_fetcher(enabled: bool, mock: MockForge) -> CommitFetcher {
+        let fetcher = create_changelog_fetcher(
+            ChangelogConfig {
+                include_pr_link: Some(enabled),
+                ..ChangelogConfig::default()
+            },
+            mock,
+        );
+        assert_eq!(
+            pr_link_package(&fetcher.config)
+                .analyzer_config
+                .include_pr_link,
+            enabled,
+            "test setup did not produce the intended include_pr_link"
+        );
+        fetcher
+    }
+
+    /// Builds a fetcher whose sole package resolves `changelog`, and whose
+    /// forge is `mock`.
+    fn create_changelog_fetcher(
+        changelog: ChangelogConfig,
+        mock: MockForge,
+    ) -> CommitFetcher {
         let resolver = ResolverBuilder::default()
             .commit_modifiers(CommitModifiers::default())
             .compare_link_base_url(
@@ -511,21 +653,11 @@ mod tests {
             .name("test-pkg")
             .path("packages/pkg-a")
             .release_type(ReleaseType::Node)
-            .changelog(ChangelogConfig {
-                include_pr_link: Some(enabled),
-                ..ChangelogConfig::default()
-            })
+            .changelog(changelog)
             .build()
             .unwrap();
 
         let resolved_config = resolver.resolve(vec![pkg_config]).unwrap();
-        assert_eq!(
-            pr_link_package(&resolved_config)
-                .analyzer_config
-                .include_pr_link,
-            enabled,
-            "test setup did not produce the intended include_pr_link"
-        );
 
         let forge = Rc::new(ForgeManager::new(
             Box::new(mock),
@@ -820,6 +952,143 @@ mod tests {
         assert_eq!(filtered[1].id, "additional-path-commit");
     }
 
+    fn resolve_scoped_packages(routing: ScopeRouting) -> Rc<ResolvedConfig> {
+        let config = Config {
+            repository: RepositoryConfig {
+                scope_routing: Some(routing),
+                ..RepositoryConfig::default()
+            },
+            ..Config::default()
+        };
+
+        let package = |name: &str, scope: &str| {
+            PackageConfigBuilder::default()
+                .name(name)
+                .path(format!("packages/{name}"))
+                .scopes(vec![scope.to_string()])
+                .build()
+                .unwrap()
+        };
+
+        ResolverBuilder::default()
+            .commit_modifiers(CommitModifiers::default())
+            .compare_link_base_url(
+                Url::parse("http://compare-link-base").unwrap(),
+            )
+            .global_overrides(GlobalOverrides::default())
+            .package_overrides(HashMap::default())
+            .release_link_base_url(
+                Url::parse("http://release-link-base").unwrap(),
+            )
+            .repo_default_branch("main")
+            .repo_name("test-repo")
+            .toml_config(Rc::new(config))
+            .build()
+            .unwrap()
+            .resolve(vec![package("api", "api"), package("web", "web")])
+            .unwrap()
+    }
+
+    fn scoped_commits() -> Vec<ForgeCommit> {
+        let commit = |id: &str, message: &str, files: &[&str]| {
+            ForgeCommitBuilder::default()
+                .id(id)
+                .short_id(id)
+                .message(message)
+                .timestamp(1000)
+                .files(files.iter().map(|f| f.to_string()).collect::<Vec<_>>())
+                .build()
+                .unwrap()
+        };
+
+        vec![
+            commit("lockfile", "fix(API): bump lockfile", &["Cargo.lock"]),
+            commit(
+                "both",
+                "feat(web): share types",
+                &["packages/api/types.ts", "packages/web/types.ts"],
+            ),
+            commit("multi", "fix(ci, api): retry", &[".github/ci.yml"]),
+            commit("unscoped", "chore: tidy", &["packages/api/lib.ts"]),
+        ]
+    }
+
+    fn filtered_ids(config: &Rc<ResolvedConfig>, name: &str) -> Vec<String> {
+        let package = config.package_configs.get(name).unwrap();
+
+        create_test_commit_fetcher(Rc::clone(config))
+            .filter_commits_for_package(package, None, &scoped_commits())
+            .into_iter()
+            .map(|c| c.id)
+            .collect()
+    }
+
+    #[test]
+    fn attributes_commits_by_scope() {
+        let config = resolve_scoped_packages(ScopeRouting::Attribute);
+
+        assert_eq!(
+            filtered_ids(&config, "api"),
+            ["lockfile", "both", "multi", "unscoped"]
+        );
+        assert_eq!(filtered_ids(&config, "web"), ["both"]);
+    }
+
+    #[test]
+    fn restricts_scoped_commits_to_their_packages() {
+        let config = resolve_scoped_packages(ScopeRouting::Restrict);
+
+        assert_eq!(
+            filtered_ids(&config, "api"),
+            ["lockfile", "multi", "unscoped"]
+        );
+        assert_eq!(filtered_ids(&config, "web"), ["both"]);
+    }
+
+    #[test]
+    fn excludes_commits_whose_files_are_all_filtered_out() {
+        let commit = |id: &str, files: &[&str]| {
+            ForgeCommitBuilder::default()
+                .id(id)
+                .short_id(id)
+                .message("fix: change")
+                .timestamp(1000)
+                .files(files.iter().map(|f| f.to_string()).collect::<Vec<_>>())
+                .build()
+                .unwrap()
+        };
+
+        let commits = vec![
+            commit("docs-only", &["packages/pkg-a/README.md"]),
+            commit("tests-only", &["packages/pkg-a/tests/it.rs"]),
+            commit("mixed", &["packages/pkg-a/CHANGES.md", "proto/v1/a.proto"]),
+            commit("proto-readme", &["proto/README.md"]),
+            commit("source", &["packages/pkg-a/src/lib.rs"]),
+        ];
+
+        let (config, mut package) =
+            create_test_package("pkg-a", "packages/pkg-a");
+        package.path_filter = compile_path_filter(&PackageConfig {
+            additional_paths: Some(vec!["proto/**/*.proto".into()]),
+            exclude_paths: Some(vec![
+                "**/*.md".into(),
+                "packages/pkg-a/tests".into(),
+            ]),
+            ..PackageConfig::default()
+        })
+        .unwrap();
+
+        let core = create_test_commit_fetcher(config);
+
+        let filtered = core
+            .filter_commits_for_package(&package, None, &commits)
+            .into_iter()
+            .map(|c| c.id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(filtered, ["mixed", "source"]);
+    }
+
     #[tokio::test]
     async fn get_commits_uses_oldest_tag_when_all_packages_tagged() {
         let config = Rc::new(Config::default());
@@ -1521,4 +1790,101 @@ mod tests {
             fetcher.fetch_merged_commit_prs(pkg, &mut commits).await;
         }
     }
+
+    fn contributors_fetcher(enabled: bool, mock: MockForge) -> CommitFetcher {
+        create_changelog_fetcher(
+            ChangelogConfig {
+                include_contributors: Some(enabled),
+                ..ChangelogConfig::default()
+            },
+            mock,
+        )
+    }
+
+    fn author(name: &str) -> ForgeCommitAuthor {
+        ForgeCommitAuthor {
+            name: name.to_string(),
+            email: format!("{name}@example.com"),
+            ..ForgeCommitAuthor::default()
+        }
+    }
+
+    fn contributors_tag(sha: &str) -> Tag {
+        Tag {
+            name: format!("v-{sha}"),
+            sha: sha.to_string(),
+            ..Tag::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn fetch_previous_authors_skips_lookup_when_disabled() {
+        let mut mock = MockForge::new();
+        mock.expect_get_commit_authors().times(0);
+
+        let fetcher = contributors_fetcher(false, mock);
+        let pkg = pr_link_package(&fetcher.config);
+
+        let authors = fetcher
+            .fetch_previous_authors(pkg, Some(&contributors_tag("aaa")))
+            .await;
+
+        assert!(authors.is_none());
+    }
+
+    #[tokio::test]
+    async fn fetch_previous_authors_is_empty_without_a_tag() {
+        let mut mock = MockForge::new();
+        mock.expect_get_commit_authors().times(0);
+
+        let fetcher = contributors_fetcher(true, mock);
+        let pkg = pr_link_package(&fetcher.config);
+
+        assert_eq!(
+            fetcher.fetch_previous_authors(pkg, None).await,
+            Some(vec![])
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_previous_authors_memoizes_per_tag() {
+        let mut mock = MockForge::new();
+        mock.expect_get_commit_authors()
+            .times(1)
+            .returning(|_| Ok(vec![author("ann")]));
+
+        let fetcher = contributors_fetcher(true, mock);
+        let pkg = pr_link_package(&fetcher.config);
+        let tag = contributors_tag("aaa");
+
+        for _ in 0..2 {
+            assert_eq!(
+                fetcher.fetch_previous_authors(pkg, Some(&tag)).await,
+                Some(vec![author("ann")])
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn fetch_previous_authors_survives_a_failed_lookup() {
+        let mut mock = MockForge::new();
+        mock.expect_get_commit_authors().times(1).returning(|_| {
+            Err(crate::result::ReleasaurusError::RateLimitExceeded)
+        });
+
+        let fetcher = contributors_fetcher(true, mock);
+        let pkg = pr_link_package(&fetcher.config);
+        let tag = contributors_tag("aaa");
+
+        // unknown rather than empty, so no one is taken for a newcomer, and
+        // not retried
+        for _ in 0..2 {
+            assert!(
+                fetcher
+                    .fetch_previous_authors(pkg, Some(&tag))
+                    .await
+                    .is_none()
+            );
+        }
+    }
 }
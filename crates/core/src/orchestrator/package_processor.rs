@@ -1,13 +1,21 @@
 use chrono::Utc;
 use color_eyre::eyre::eyre;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::{
+    borrow::Cow,
     collections::{HashMap, HashSet},
     rc::Rc,
 };
 
 use crate::{
-    analyzer::Analyzer,
+    analyzer::{AnalyzeOptions, Analyzer, release::Release},
+    changelog::keep_a_changelog::{self, ReleaseLinks},
+    config::{
+        changelog::{ChangelogFormat, DEFAULT_AGGREGATE_BODY},
+        release_type::ReleaseType,
+        version_group::VersionGroupMode,
+    },
     forge::{
         config::DEFAULT_PR_BRANCH_PREFIX,
         manager::ForgeManager,
@@ -22,6 +30,7 @@ use crate::{
     },
     packages::{
         analyzed::AnalyzedPackage,
+        dependency_graph::DependencyGraph,
         prepared::PreparedPackage,
         releasable::{
             ReleasablePackage, ReleasableSubPackage,
@@ -123,12 +132,14 @@ impl PackageProcessor {
                     name: name.clone(),
                     current_tag,
                     commits: vec![pkg_commit],
+                    previous_authors: None,
                 });
             } else if targets.is_none() {
                 prepared.push(PreparedPackage {
                     name: name.clone(),
                     current_tag,
                     commits: vec![pkg_commit],
+                    previous_authors: None,
                 });
             }
         }
@@ -188,10 +199,16 @@ impl PackageProcessor {
                 .fetch_merged_commit_prs(package, &mut commits)
                 .await;
 
+            let previous_authors = self
+                .commit_fetcher
+                .fetch_previous_authors(package, current_tag.as_ref())
+                .await;
+
             prepared_packages.push(PreparedPackage {
                 name: name.clone(),
                 current_tag,
                 commits,
+                previous_authors,
             })
         }
 
@@ -202,20 +219,104 @@ impl PackageProcessor {
         &self,
         packages: Vec<PreparedPackage>,
     ) -> Result<Vec<AnalyzedPackage>> {
-        let mut analyzed_packages = vec![];
+        packages
+            .into_iter()
+            .map(|pkg| self.analyze_package(pkg, AnalyzeOptions::default()))
+            .collect()
+    }
 
-        for pkg in packages.into_iter() {
-            let config = self.config.package_configs.get(&pkg.name)?;
-            let analyzer = Analyzer::new(&config.analyzer_config)?;
-            let release = analyzer.analyze(pkg.commits, pkg.current_tag)?;
-            let analyzed = AnalyzedPackage {
-                name: pkg.name.clone(),
-                release,
-            };
-            analyzed_packages.push(analyzed);
+    /// Analyzes packages like [`Self::analyze_packages`], then applies the
+    /// relationships between them: with `repository.cascade` on, dependents
+    /// of released packages are re-analyzed with a generated bump entry per
+    /// released dependency, and members of version groups are aligned on
+    /// their group's version. Both repeat until nothing changes, since each
+    /// can release a package the other then has to account for.
+    pub async fn analyze_related_packages(
+        &self,
+        packages: Vec<PreparedPackage>,
+    ) -> Result<Vec<AnalyzedPackage>> {
+        if !self.config.cascade && self.config.version_groups.is_empty() {
+            return self.analyze_packages(packages);
         }
 
-        Ok(analyzed_packages)
+        let graph = if self.config.cascade {
+            self.dependency_graph(&packages).await?
+        } else {
+            DependencyGraph::default()
+        };
+
+        // analysis before version groups are applied, with each package's
+        // current dependency bumps
+        let mut natural = self.analyze_packages(packages.clone())?;
+        let mut options = vec![AnalyzeOptions::default(); packages.len()];
+
+        loop {
+            let analyzed =
+                self.apply_version_groups(&packages, &options, &natural)?;
+
+            let releases: HashMap<&str, &Release> = analyzed
+                .iter()
+                .filter_map(|a| {
+                    a.release.as_ref().map(|r| (a.name.as_str(), r))
+                })
+                .collect();
+
+            let mut changed = vec![];
+
+            for (index, pkg) in packages.iter().enumerate() {
+                let released = graph
+                    .dependencies_of(&pkg.name)
+                    .filter_map(|dep| releases.get(dep).map(|r| (dep, *r)))
+                    .collect::<Vec<_>>();
+
+                let bumps = released
+                    .iter()
+                    .map(|(dep, release)| dependency_bump(dep, release))
+                    .collect::<Vec<_>>();
+
+                let applied = &options[index].dependency_bumps;
+
+                if bumps
+                    .iter()
+                    .map(|b| &b.message)
+                    .ne(applied.iter().map(|b| &b.message))
+                {
+                    changed.push((
+                        index,
+                        AnalyzeOptions {
+                            dependency_bumps: bumps,
+                            anchor: released
+                                .first()
+                                .map(|(_, release)| (*release).into()),
+                            version: None,
+                            previous_authors: None,
+                        },
+                    ));
+                }
+            }
+
+            if changed.is_empty() {
+                return Ok(analyzed);
+            }
+
+            for (index, package_options) in changed {
+                log::info!(
+                    "package {}: cascading {}",
+                    packages[index].name,
+                    package_options
+                        .dependency_bumps
+                        .iter()
+                        .map(|b| b.message.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                natural[index] = self.analyze_package(
+                    packages[index].clone(),
+                    package_options.clone(),
+                )?;
+                options[index] = package_options;
+            }
+        }
     }
 
     pub async fn releasable_packages(
@@ -271,8 +372,15 @@ impl PackageProcessor {
                     &releasable_refs,
                 )?;
 
-            file_changes
-                .push(self.changelog_file_change(target, target_config));
+            self.report_manifest_problems(
+                UpdateManager::verify_package_manifest_file_changes(
+                    target,
+                    &target_config.compiled_additional_manifests,
+                    &file_changes,
+                ),
+            )?;
+
+            file_changes.extend(self.changelog_file_changes(target)?);
 
             finalized.push(ReleasePRPackage {
                 name: target.name.clone(),
@@ -280,6 +388,8 @@ impl PackageProcessor {
                 notes: target.notes.clone(),
                 tag_compare_link: target.tag_compare_link.clone(),
                 sha_compare_link: target.sha_compare_link.clone(),
+                commits: target.commits.clone(),
+                timestamp: target.timestamp,
                 file_changes,
                 release_branch,
                 commit_message_template: target_config
@@ -354,12 +464,14 @@ impl PackageProcessor {
                 ));
             }
 
-            let file_changes: Vec<FileChange> = bundle
+            let mut file_changes: Vec<FileChange> = bundle
                 .packages
                 .iter()
                 .flat_map(|p| p.file_changes.clone())
                 .collect();
 
+            file_changes.extend(self.aggregate_changelog_change(&bundle)?);
+
             let commit_message =
                 self.release_commit_message_for_pr_package_list(&bundle)?;
 
@@ -400,6 +512,219 @@ impl PackageProcessor {
     ////////////////////////////////////////////////////////////////////////////
     //// Private
     ////////////////////////////////////////////////////////////////////////////
+    fn analyze_package(
+        &self,
+        pkg: PreparedPackage,
+        options: AnalyzeOptions,
+    ) -> Result<AnalyzedPackage> {
+        let config = self.config.package_configs.get(&pkg.name)?;
+        let analyzer = Analyzer::new(&config.analyzer_config)?;
+
+        // a release_as override stands in for the computed version, unless
+        // something else (e.g. a version group) already set one. It needs a
+        // commit to release from, and like a Release-As footer it can't move
+        // the package backwards, so one left in place after its release
+        // falls back to the computed version
+        let release_as = config
+            .release_as
+            .as_ref()
+            .filter(|_| options.version.is_none() && !pkg.commits.is_empty())
+            .filter(|version| {
+                let current = pkg.current_tag.as_ref().map(|t| &t.semver);
+                let advances = current.is_none_or(|c| *version > c);
+
+                if !advances {
+                    log::warn!(
+                        "package {}: ignoring release_as {version}: does not \
+                         advance past current tag {}",
+                        pkg.name,
+                        current.map(ToString::to_string).unwrap_or_default()
+                    );
+                }
+
+                advances
+            })
+            .cloned();
+
+        let options = AnalyzeOptions {
+            version: options.version.or_else(|| release_as.clone()),
+            previous_authors: pkg.previous_authors,
+            ..options
+        };
+
+        let mut release =
+            analyzer.analyze_with(pkg.commits, pkg.current_tag, options)?;
+
+        if let Some(release) = release.as_mut()
+            && release_as.is_some()
+        {
+            release.release_as = release_as;
+        }
+
+        Ok(AnalyzedPackage {
+            name: pkg.name,
+            release,
+        })
+    }
+
+    /// Aligns the members of each version group on the highest version
+    /// computed among them: every member of a fixed group is released at
+    /// it, while in a linked group only the members already being released
+    /// are. Members released only for their group take the sha of the
+    /// member that set the version. A member whose tag is already at or
+    /// past that version is an error, since the group can't share one.
+    fn apply_version_groups(
+        &self,
+        packages: &[PreparedPackage],
+        options: &[AnalyzeOptions],
+        natural: &[AnalyzedPackage],
+    ) -> Result<Vec<AnalyzedPackage>> {
+        let mut analyzed = natural.to_vec();
+
+        for group in self.config.version_groups.iter() {
+            let members = packages
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| group.packages.contains(&p.name))
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>();
+
+            let Some(leader) = members
+                .iter()
+                .filter_map(|&index| natural[index].release.as_ref())
+                .max_by(|a, b| a.tag.semver.cmp(&b.tag.semver))
+            else {
+                continue;
+            };
+
+            let version = &leader.tag.semver;
+
+            for &index in members.iter() {
+                let current =
+                    natural[index].release.as_ref().map(|r| &r.tag.semver);
+
+                let align = match group.mode {
+                    VersionGroupMode::Fixed => current != Some(version),
+                    VersionGroupMode::Linked => {
+                        current.is_some_and(|v| v != version)
+                    }
+                };
+
+                if !align {
+                    continue;
+                }
+
+                // releasing it at the group's version would move it
+                // backwards, and skipping it would split the group
+                if let Some(tag) = packages[index].current_tag.as_ref()
+                    && tag.semver >= *version
+                {
+                    return Err(ReleasaurusError::invalid_config(format!(
+                        "package {}: current tag {} is already at or past \
+                         version {version} shared by its {} version group",
+                        packages[index].name, tag.name, group.mode
+                    )));
+                }
+
+                log::info!(
+                    "package {}: releasing at {version} with its {} version \
+                     group",
+                    packages[index].name,
+                    group.mode
+                );
+
+                analyzed[index] = self.analyze_package(
+                    packages[index].clone(),
+                    AnalyzeOptions {
+                        version: Some(version.clone()),
+                        anchor: Some(leader.into()),
+                        ..options[index].clone()
+                    },
+                )?;
+            }
+        }
+
+        Ok(analyzed)
+    }
+
+    /// Builds the dependency graph between the given packages from their
+    /// manifests on the base branch.
+    async fn dependency_graph(
+        &self,
+        packages: &[PreparedPackage],
+    ) -> Result<DependencyGraph> {
+        let mut manifests = vec![];
+
+        for pkg in packages {
+            let config = self
+                .with_detected_release_type(
+                    self.config.package_configs.get(&pkg.name)?,
+                )
+                .await?;
+
+            let files = UpdateManager::load_manifests_for_package(
+                config.as_ref(),
+                self.forge.as_ref(),
+                &self.config.base_branch,
+            )
+            .await?
+            .unwrap_or_default();
+
+            manifests.push((pkg.name.clone(), files));
+        }
+
+        Ok(DependencyGraph::new(&manifests))
+    }
+
+    /// Renders the aggregate changelog entry covering every package in a
+    /// release PR, if `repository.aggregate_changelog` is enabled.
+    fn aggregate_changelog_change(
+        &self,
+        pr_bundle: &PRBundle,
+    ) -> Result<Option<FileChange>> {
+        let Some(file) = self.config.aggregate_changelog.as_ref() else {
+            return Ok(None);
+        };
+
+        let mut packages = pr_bundle.packages.iter().collect::<Vec<_>>();
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut context = tera::Context::new();
+        context.insert(
+            "timestamp",
+            &packages
+                .iter()
+                .map(|p| p.timestamp)
+                .max()
+                .unwrap_or_default(),
+        );
+        context.insert(
+            "packages",
+            &packages
+                .iter()
+                .map(|p| {
+                    json!({
+                        "name": p.name,
+                        "tag_name": p.tag.name,
+                        "version": p.tag.semver.to_string(),
+                        "notes": p.notes,
+                        "tag_compare_link": p.tag_compare_link,
+                        "commits": p.commits,
+                    })
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let body = file.body.as_deref().unwrap_or(DEFAULT_AGGREGATE_BODY);
+        let entry = tera::Tera::one_off(body, &context, false)?;
+
+        Ok(Some(FileChange {
+            path: file.path.to_string_lossy().to_string(),
+            content: format!("{}\n", entry.trim()),
+            update_type: FileUpdateType::InsertAfter(file.insert_after.clone()),
+        }))
+    }
+
     fn release_commit_message_for_pr_package_list(
         &self,
         pr_bundle: &PRBundle,
@@ -553,7 +878,12 @@ impl PackageProcessor {
 
         for pkg in packages.into_iter() {
             if let Some(release) = pkg.release {
-                let pkg_config = self.config.package_configs.get(&pkg.name)?;
+                let pkg_config = self
+                    .with_detected_release_type(
+                        self.config.package_configs.get(&pkg.name)?,
+                    )
+                    .await?;
+                let pkg_config = pkg_config.as_ref();
 
                 let manifest_files = UpdateManager::load_manifests_for_package(
                     pkg_config,
@@ -573,6 +903,9 @@ impl PackageProcessor {
                 let mut sub_packages = vec![];
 
                 for sub in pkg_config.sub_packages.iter() {
+                    let sub = self.with_detected_release_type(sub).await?;
+                    let sub = sub.as_ref();
+
                     let manifest_files =
                         UpdateManager::load_manifests_for_package(
                             sub,
@@ -595,13 +928,37 @@ impl PackageProcessor {
                     manifest_files,
                     additional_manifest_files,
                     sub_packages,
-                ));
+                )?);
             }
         }
 
         Ok(releasable)
     }
 
+    /// Swaps `release_type = "auto"` for the release type detected from
+    /// the package's manifests, leaving every other package borrowed
+    /// as-is.
+    async fn with_detected_release_type<'a>(
+        &self,
+        package: &'a ResolvedPackage,
+    ) -> Result<Cow<'a, ResolvedPackage>> {
+        if package.release_type != ReleaseType::Auto {
+            return Ok(Cow::Borrowed(package));
+        }
+
+        let release_type = UpdateManager::detect_release_type(
+            package,
+            self.forge.as_ref(),
+            &self.config.base_branch,
+        )
+        .await?;
+
+        Ok(Cow::Owned(ResolvedPackage {
+            release_type,
+            ..package.clone()
+        }))
+    }
+
     fn related_packages<'a>(
         &self,
         target: &ReleasablePackage,
@@ -615,7 +972,7 @@ impl PackageProcessor {
             if p.name != target.name
                 && p_config.normalized_workspace_root
                     == target_config.normalized_workspace_root
-                && p_config.release_type == target_config.release_type
+                && p.release_type == target.release_type
             {
                 workspace_packages.push(p);
             }
@@ -624,20 +981,81 @@ impl PackageProcessor {
         Ok(workspace_packages)
     }
 
-    fn changelog_file_change(
+    /// Surfaces manifest targets that a release left untouched: logged as
+    /// warnings by default, or a hard error in strict mode so a release PR
+    /// is never opened with a stale version file.
+    fn report_manifest_problems(&self, problems: Vec<String>) -> Result<()> {
+        if problems.is_empty() {
+            return Ok(());
+        }
+
+        if self.config.strict_manifest_updates {
+            return Err(ReleasaurusError::ManifestVerification(
+                problems.join("; "),
+            ));
+        }
+
+        for problem in problems {
+            log::warn!("manifest verification: {problem}");
+        }
+
+        Ok(())
+    }
+
+    fn changelog_file_changes(
         &self,
         target: &ReleasablePackage,
-        target_config: &ResolvedPackage,
-    ) -> FileChange {
-        FileChange {
-            path: target_config
-                .normalized_full_path
-                .join("CHANGELOG.md")
-                .to_string_lossy()
-                .to_string(),
-            content: format!("{}\n", target.notes),
-            update_type: FileUpdateType::Prepend,
-        }
+    ) -> Result<Vec<FileChange>> {
+        target
+            .changelogs
+            .iter()
+            .map(|changelog| {
+                let path = changelog.path.to_string_lossy().to_string();
+
+                let ChangelogFormat::KeepAChangelog = changelog.format else {
+                    return Ok(FileChange {
+                        path,
+                        content: format!("{}\n", changelog.content),
+                        update_type: changelog
+                            .format
+                            .insert_update(&changelog.insert_after),
+                    });
+                };
+
+                let links = ReleaseLinks::new(
+                    &target.tag,
+                    &target.tag_compare_link,
+                    self.forge.release_link_base_url(),
+                    self.forge.compare_link_base_url(),
+                )?;
+
+                Ok(FileChange {
+                    path,
+                    content: keep_a_changelog::insert_release(
+                        &changelog.content,
+                        "",
+                        &links,
+                    ),
+                    update_type: FileUpdateType::KeepAChangelog {
+                        entry: changelog.content.clone(),
+                        links,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// The generated commit behind a cascaded release's changelog entry. It
+/// points at the dependency's release so the entry links somewhere useful.
+fn dependency_bump(name: &str, release: &Release) -> ForgeCommit {
+    ForgeCommit {
+        id: release.sha.clone(),
+        short_id: release.short_sha.clone(),
+        link: release.link.clone(),
+        message: format!("deps: bump {name} to {}", release.tag.semver),
+        timestamp: release.timestamp,
+        ..ForgeCommit::default()
     }
 }
 
@@ -2,17 +2,29 @@
 //!
 //! Test organization:
 //! - `common`: Shared test utilities and helper functions
+//! - `aggregate_changelog`: Repository-level changelog combining every
+//!   package in a release PR
 //! - `analyze`: Package analysis tests (analyzing commits, version bumping)
+//! - `cascade`: Releasing dependents of released packages
 //! - `prepare`: Package preparation tests (dummy commits, target filtering)
+//! - `manifest_verification`: Warn vs. strict handling of manifest targets
+//!   left unchanged by a release
 //! - `pr_grouping`: PR grouping and branch logic tests (separate vs grouped)
 //! - `pr_requests`: PR request generation and branch creation tests
 //!   (metadata, file changes, branch creation order)
 //! - `pr_templates`: Commit message and PR title template tests
 //!   (per-package vs. monorepo selection, render context)
+//! - `release_type_detection`: `release_type = "auto"` manifest probing
+//! - `version_groups`: Fixed and linked version groups
 
+mod aggregate_changelog;
 mod analyze;
+mod cascade;
 mod common;
+mod manifest_verification;
 mod pr_grouping;
 mod pr_requests;
 mod pr_templates;
 mod prepare;
+mod release_type_detection;
+mod version_groups;
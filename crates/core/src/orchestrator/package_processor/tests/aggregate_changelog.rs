@@ -0,0 +1,128 @@
+//! Tests for the repository-level aggregate changelog written alongside
+//! each release PR.
+
+use std::sync::{Arc, Mutex};
+
+use super::common::*;
+
+use crate::{
+    config::{
+        Config,
+        changelog::AggregateChangelogConfig,
+        package::{PackageConfig, PackageConfigBuilder},
+        repository::RepositoryConfig,
+    },
+    forge::{
+        request::{
+            Commit, CreateReleaseBranchRequest, FileChange, FileUpdateType,
+        },
+        traits::MockForge,
+    },
+};
+
+fn package_configs() -> Vec<PackageConfig> {
+    vec![
+        PackageConfigBuilder::default()
+            .name("pkg-b")
+            .path("packages/b")
+            .build()
+            .unwrap(),
+        PackageConfigBuilder::default()
+            .name("pkg-a")
+            .path("packages/a")
+            .build()
+            .unwrap(),
+    ]
+}
+
+/// Runs both packages through `create_pr_branches` and returns the file
+/// changes committed to the release branch.
+async fn branch_file_changes(
+    aggregate_changelog: Option<AggregateChangelogConfig>,
+) -> Vec<FileChange> {
+    let mut mock_forge = MockForge::new();
+
+    mock_forge
+        .expect_get_merged_release_pr()
+        .returning(|_| Ok(None));
+    mock_forge
+        .expect_get_open_release_pr()
+        .returning(|_| Ok(None));
+
+    let captured: Arc<Mutex<Vec<FileChange>>> = Arc::new(Mutex::new(vec![]));
+    let sink = Arc::clone(&captured);
+
+    mock_forge
+        .expect_create_release_branch()
+        .times(1)
+        .returning(move |req: CreateReleaseBranchRequest| {
+            sink.lock().unwrap().extend(req.file_changes);
+            Ok(Commit {
+                sha: "abc123".to_string(),
+            })
+        });
+
+    expect_html_comment_encoding(&mut mock_forge);
+
+    let config = Config {
+        repository: RepositoryConfig {
+            separate_pull_requests: Some(false),
+            aggregate_changelog,
+            ..RepositoryConfig::default()
+        },
+        ..Config::default()
+    };
+
+    let processor = create_package_processor(
+        mock_forge,
+        Some(package_configs()),
+        Some(config),
+    );
+
+    let grouped = processor
+        .release_pr_packages_by_branch(vec![
+            releasable("pkg-b", "v2.0.0"),
+            releasable("pkg-a", "v1.0.0"),
+        ])
+        .await
+        .unwrap();
+
+    processor.create_pr_branches(grouped).await.unwrap();
+
+    captured.lock().unwrap().clone()
+}
+
+#[tokio::test]
+async fn writes_one_combined_entry_for_all_packages() {
+    let changes = branch_file_changes(Some(AggregateChangelogConfig {
+        path: Some("CHANGES.md".into()),
+        body: Some(
+            "{% for p in packages %}{{ p.name }} {{ p.tag_name }}\n\
+             {% endfor %}"
+                .into(),
+        ),
+        ..Default::default()
+    }))
+    .await;
+
+    let aggregate = changes
+        .iter()
+        .filter(|c| c.path == "CHANGES.md")
+        .collect::<Vec<_>>();
+
+    assert_eq!(aggregate.len(), 1);
+    // Packages render in name order regardless of release order
+    assert_eq!(aggregate[0].content, "pkg-a v1.0.0\npkg-b v2.0.0\n");
+    assert!(matches!(
+        aggregate[0].update_type,
+        FileUpdateType::InsertAfter(_)
+    ));
+}
+
+#[tokio::test]
+async fn omits_aggregate_changelog_when_not_configured() {
+    let changes = branch_file_changes(None).await;
+
+    assert!(!changes.is_empty());
+    assert!(changes.iter().all(|c| c.path != "CHANGES.md"));
+}
@@ -4,10 +4,13 @@
 //! - Analyzing packages with commits
 //! - Analyzing packages with existing tags
 //! - Handling packages with no commits
+//! - `release_as` overrides replacing the computed version
 
 use semver::Version;
+use std::collections::HashMap;
 
 use crate::{
+    config::overrides::PackageOverrides,
     forge::{
         request::{ForgeCommitBuilder, Tag},
         traits::MockForge,
@@ -45,6 +48,7 @@ fn analyze_packages_produces_analyzed_packages() {
         name: "test-pkg".to_string(),
         current_tag: None,
         commits,
+        previous_authors: None,
     }];
 
     let analyzed = processor.analyze_packages(prepared).unwrap();
@@ -79,6 +83,7 @@ fn analyze_packages_with_existing_tag() {
         name: "test-pkg".to_string(),
         current_tag,
         commits,
+        previous_authors: None,
     }];
 
     let analyzed = processor.analyze_packages(prepared).unwrap();
@@ -101,9 +106,94 @@ fn analyze_packages_returns_none_when_no_commits() {
         name: "test-pkg".to_string(),
         current_tag: None,
         commits: vec![],
+        previous_authors: None,
     }];
 
     let analyzed = processor.analyze_packages(prepared).unwrap();
     assert_eq!(analyzed.len(), 1);
     assert!(analyzed[0].release.is_none());
 }
+
+fn release_as_overrides(version: &str) -> HashMap<String, PackageOverrides> {
+    HashMap::from([(
+        "test-pkg".to_string(),
+        PackageOverrides {
+            tag_prefix: None,
+            prerelease_suffix: None,
+            prerelease_strategy: None,
+            version_type: None,
+            release_as: Some(version.to_string()),
+        },
+    )])
+}
+
+#[test]
+fn analyze_packages_release_as_override_replaces_computed_version() {
+    let processor = create_package_processor_with_overrides(
+        MockForge::new(),
+        release_as_overrides("3.0.0"),
+    );
+
+    let prepared = vec![PreparedPackage {
+        name: "test-pkg".to_string(),
+        current_tag: Some(Tag {
+            semver: Version::parse("1.0.0").unwrap(),
+            timestamp: Some(1000),
+            ..Default::default()
+        }),
+        commits: vec![
+            ForgeCommitBuilder::default()
+                .id("commit1")
+                .short_id("c1")
+                .message("fix: bug fix")
+                .timestamp(2000)
+                .build()
+                .unwrap(),
+        ],
+        previous_authors: None,
+    }];
+
+    let analyzed = processor.analyze_packages(prepared).unwrap();
+    let release = analyzed[0].release.as_ref().unwrap();
+
+    assert_eq!(release.tag.semver, Version::new(3, 0, 0));
+    assert_eq!(release.release_as, Some(Version::new(3, 0, 0)));
+    // the commits still feed the release notes
+    assert_eq!(release.commits.len(), 1);
+    assert!(release.notes.contains("bug fix"));
+}
+
+/// The override is meant for a single run: once the package is tagged at
+/// the requested version, leaving it in place no longer affects versioning.
+#[test]
+fn analyze_packages_release_as_override_is_spent_once_tagged() {
+    let processor = create_package_processor_with_overrides(
+        MockForge::new(),
+        release_as_overrides("3.0.0"),
+    );
+
+    let prepared = vec![PreparedPackage {
+        name: "test-pkg".to_string(),
+        current_tag: Some(Tag {
+            semver: Version::parse("3.0.0").unwrap(),
+            timestamp: Some(1000),
+            ..Default::default()
+        }),
+        commits: vec![
+            ForgeCommitBuilder::default()
+                .id("commit1")
+                .short_id("c1")
+                .message("fix: bug fix")
+                .timestamp(2000)
+                .build()
+                .unwrap(),
+        ],
+        previous_authors: None,
+    }];
+
+    let analyzed = processor.analyze_packages(prepared).unwrap();
+    let release = analyzed[0].release.as_ref().unwrap();
+
+    assert_eq!(release.tag.semver, Version::new(3, 0, 1));
+    assert!(release.release_as.is_none());
+}
@@ -0,0 +1,154 @@
+//! Tests for `repository.cascade`.
+//!
+//! Tests for:
+//! - Releasing dependents (and their dependents) of a released package
+//! - Leaving dependents alone when cascade is off
+
+use semver::Version;
+
+use crate::{
+    config::{
+        Config, package::PackageConfigBuilder, release_type::ReleaseType,
+        repository::RepositoryConfig,
+    },
+    forge::{
+        request::{ForgeCommitBuilder, GetFileContentRequest, Tag},
+        traits::MockForge,
+    },
+    orchestrator::package_processor::PackageProcessor,
+    packages::{analyzed::AnalyzedPackage, prepared::PreparedPackage},
+};
+
+use super::common::*;
+
+/// `core` ← `lib` ← `cli`, plus an unrelated `docs` crate.
+fn workspace_forge() -> MockForge {
+    let mut mock = MockForge::new();
+
+    mock.expect_get_file_content()
+        .returning(|req: GetFileContentRequest| {
+            let manifest = |name: &str, deps: &str| {
+                format!(
+                    "[package]\nname = \"{name}\"\nversion = \"1.0.0\"\n\
+                     [dependencies]\n{deps}"
+                )
+            };
+
+            Ok(match req.path.as_str() {
+                "crates/core/Cargo.toml" => Some(manifest("core", "")),
+                "crates/lib/Cargo.toml" => {
+                    Some(manifest("lib", "core = { path = \"../core\" }\n"))
+                }
+                "crates/cli/Cargo.toml" => {
+                    Some(manifest("cli", "lib = { path = \"../lib\" }\n"))
+                }
+                "crates/docs/Cargo.toml" => Some(manifest("docs", "")),
+                _ => None,
+            })
+        });
+
+    mock
+}
+
+fn processor(cascade: bool) -> PackageProcessor {
+    let packages = ["core", "lib", "cli", "docs"]
+        .iter()
+        .map(|name| {
+            PackageConfigBuilder::default()
+                .name(*name)
+                .path(format!("crates/{name}"))
+                .tag_prefix(format!("{name}-v"))
+                .release_type(ReleaseType::Rust)
+                .build()
+                .unwrap()
+        })
+        .collect();
+
+    create_package_processor(
+        workspace_forge(),
+        Some(packages),
+        Some(Config {
+            repository: RepositoryConfig {
+                cascade: Some(cascade),
+                ..RepositoryConfig::default()
+            },
+            ..Config::default()
+        }),
+    )
+}
+
+fn prepared() -> Vec<PreparedPackage> {
+    let tag = |name: &str| Tag {
+        name: format!("{name}-v1.0.0"),
+        semver: Version::parse("1.0.0").unwrap(),
+        ..Default::default()
+    };
+
+    ["core", "lib", "cli", "docs"]
+        .iter()
+        .map(|name| PreparedPackage {
+            name: name.to_string(),
+            current_tag: Some(tag(name)),
+            commits: if *name == "core" {
+                vec![
+                    ForgeCommitBuilder::default()
+                        .id("abc1234567")
+                        .short_id("abc1234")
+                        .message("feat: new api")
+                        .timestamp(1000)
+                        .build()
+                        .unwrap(),
+                ]
+            } else {
+                vec![]
+            },
+            previous_authors: None,
+        })
+        .collect()
+}
+
+fn version(analyzed: &[AnalyzedPackage], name: &str) -> Option<String> {
+    analyzed
+        .iter()
+        .find(|a| a.name == name)
+        .unwrap()
+        .release
+        .as_ref()
+        .map(|r| r.tag.semver.to_string())
+}
+
+#[tokio::test]
+async fn cascade_releases_transitive_dependents() {
+    let analyzed = processor(true)
+        .analyze_related_packages(prepared())
+        .await
+        .unwrap();
+
+    assert_eq!(version(&analyzed, "core").as_deref(), Some("1.1.0"));
+    assert_eq!(version(&analyzed, "lib").as_deref(), Some("1.0.1"));
+    assert_eq!(version(&analyzed, "cli").as_deref(), Some("1.0.1"));
+    assert_eq!(version(&analyzed, "docs"), None);
+
+    let lib = analyzed[1].release.as_ref().unwrap();
+
+    assert_eq!(lib.commits.len(), 1);
+    assert_eq!(lib.commits[0].title, "bump core to 1.1.0");
+    assert_eq!(lib.commits[0].group, "<!-- 02 -->📦 Dependencies");
+    assert_eq!(lib.sha, "abc1234567");
+    assert!(lib.notes.contains("bump core to 1.1.0"), "{}", lib.notes);
+
+    let cli = analyzed[2].release.as_ref().unwrap();
+    assert_eq!(cli.commits[0].title, "bump lib to 1.0.1");
+}
+
+#[tokio::test]
+async fn dependents_are_not_released_without_cascade() {
+    let analyzed = processor(false)
+        .analyze_related_packages(prepared())
+        .await
+        .unwrap();
+
+    assert_eq!(version(&analyzed, "core").as_deref(), Some("1.1.0"));
+    assert_eq!(version(&analyzed, "lib"), None);
+    assert_eq!(version(&analyzed, "cli"), None);
+}
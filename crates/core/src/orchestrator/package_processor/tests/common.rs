@@ -10,7 +10,7 @@ use url::Url;
 use crate::{
     config::{
         Config,
-        overrides::{CommitModifiers, GlobalOverrides},
+        overrides::{CommitModifiers, GlobalOverrides, PackageOverridesHash},
         package::{PackageConfig, PackageConfigBuilder},
     },
     forge::{
@@ -19,7 +19,10 @@ use crate::{
         traits::MockForge,
     },
     orchestrator::package_processor::PackageProcessor,
-    packages::{releasable::ReleasablePackage, release_pr::ReleasePRPackage},
+    packages::{
+        releasable::{ReleasableChangelog, ReleasablePackage},
+        release_pr::ReleasePRPackage,
+    },
     resolver::Resolver,
 };
 
@@ -30,6 +33,47 @@ pub fn create_package_processor(
     mock_forge: MockForge,
     pkg_configs: Option<Vec<PackageConfig>>,
     config: Option<Config>,
+) -> PackageProcessor {
+    build_package_processor(
+        mock_forge,
+        pkg_configs,
+        config,
+        PackageOverridesHash::new(),
+        false,
+    )
+}
+
+/// Same as [`create_package_processor`] but resolved with the given
+/// per-package runtime overrides, as the CLI's `--set-package` and
+/// `--release-as` flags supply.
+pub fn create_package_processor_with_overrides(
+    mock_forge: MockForge,
+    package_overrides: PackageOverridesHash,
+) -> PackageProcessor {
+    build_package_processor(mock_forge, None, None, package_overrides, false)
+}
+
+/// Same as [`create_package_processor`] but with strict manifest update
+/// verification enabled, as `release-pr --strict` does.
+pub fn create_strict_package_processor(
+    mock_forge: MockForge,
+    pkg_configs: Option<Vec<PackageConfig>>,
+) -> PackageProcessor {
+    build_package_processor(
+        mock_forge,
+        pkg_configs,
+        None,
+        PackageOverridesHash::new(),
+        true,
+    )
+}
+
+fn build_package_processor(
+    mock_forge: MockForge,
+    pkg_configs: Option<Vec<PackageConfig>>,
+    config: Option<Config>,
+    package_overrides: PackageOverridesHash,
+    strict_manifest_updates: bool,
 ) -> PackageProcessor {
     let config = Rc::new(config.unwrap_or_default());
 
@@ -41,9 +85,10 @@ pub fn create_package_processor(
         .compare_link_base_url(
             Url::parse("https://example.com/compare/").unwrap(),
         )
-        .package_overrides(std::collections::HashMap::new())
+        .package_overrides(package_overrides)
         .global_overrides(GlobalOverrides::default())
         .commit_modifiers(CommitModifiers::default())
+        .strict_manifest_updates(strict_manifest_updates)
         .build()
         .unwrap();
 
@@ -102,10 +147,17 @@ pub fn capture_release_branch_messages(
 
 /// Builds a minimal releasable package with `tag` parsed into a semver.
 pub fn releasable(name: &str, tag: &str) -> ReleasablePackage {
+    let notes = format!("notes for {name}");
+
     ReleasablePackage {
         name: name.into(),
         tag: tag_for(tag),
-        notes: format!("notes for {name}"),
+        changelogs: vec![ReleasableChangelog {
+            path: "CHANGELOG.md".into(),
+            content: notes.clone(),
+            ..Default::default()
+        }],
+        notes,
         ..Default::default()
     }
 }
@@ -124,6 +176,8 @@ pub fn release_pr_package(
         notes: String::new(),
         tag_compare_link: String::new(),
         sha_compare_link: String::new(),
+        commits: vec![],
+        timestamp: 0,
         file_changes: vec![],
         release_branch: "releasaurus-release-main".into(),
         commit_message_template: commit_message_template.into(),
@@ -0,0 +1,86 @@
+//! Tests for manifest update verification.
+//!
+//! Tests for:
+//! - Unchanged manifest targets only warning by default
+//! - Strict mode refusing to build release PR packages for them
+
+use super::common::*;
+
+use crate::{
+    config::{package::PackageConfigBuilder, release_type::ReleaseType},
+    forge::traits::MockForge,
+    packages::manifests::ManifestFile,
+    result::ReleasaurusError,
+};
+
+fn node_package_config() -> crate::config::package::PackageConfig {
+    PackageConfigBuilder::default()
+        .name("test-pkg")
+        .path(".")
+        .release_type(ReleaseType::Node)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn release_pr_packages_warns_when_manifests_are_missing() {
+    let processor = create_package_processor(
+        MockForge::new(),
+        Some(vec![node_package_config()]),
+        None,
+    );
+
+    let mut releasable = releasable("test-pkg", "v1.0.0");
+    releasable.release_type = ReleaseType::Node;
+
+    let packages = processor.release_pr_packages(vec![releasable]).unwrap();
+
+    // only the changelog is written
+    assert_eq!(packages[0].file_changes.len(), 1);
+}
+
+#[test]
+fn release_pr_packages_errors_in_strict_mode_when_manifests_are_missing() {
+    let processor = create_strict_package_processor(
+        MockForge::new(),
+        Some(vec![node_package_config()]),
+    );
+
+    let mut releasable = releasable("test-pkg", "v1.0.0");
+    releasable.release_type = ReleaseType::Node;
+
+    let Err(err) = processor.release_pr_packages(vec![releasable]) else {
+        panic!("expected strict verification to fail");
+    };
+
+    assert!(matches!(err, ReleasaurusError::ManifestVerification(_)));
+    assert!(
+        err.to_string().contains("no node manifest files found"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn release_pr_packages_passes_strict_mode_when_manifests_are_updated() {
+    let processor = create_strict_package_processor(
+        MockForge::new(),
+        Some(vec![node_package_config()]),
+    );
+
+    let mut releasable = releasable("test-pkg", "v1.0.0");
+    releasable.release_type = ReleaseType::Node;
+    releasable.manifest_files = Some(vec![ManifestFile {
+        path: "package.json".into(),
+        basename: "package.json".into(),
+        content: r#"{"name": "test-pkg", "version": "0.1.0"}"#.into(),
+    }]);
+
+    let packages = processor.release_pr_packages(vec![releasable]).unwrap();
+
+    assert!(
+        packages[0]
+            .file_changes
+            .iter()
+            .any(|c| c.path == "package.json" && c.content.contains("1.0.0"))
+    );
+}
@@ -26,7 +26,7 @@ async fn release_pr_packages_by_branch_groups_all_when_not_separate() {
 
     let toml_config = Config {
         repository: RepositoryConfig {
-            separate_pull_requests: false,
+            separate_pull_requests: Some(false),
             ..RepositoryConfig::default()
         },
         ..Config::default()
@@ -93,7 +93,7 @@ async fn release_pr_packages_by_branch_separates_when_configured() {
 
     let toml_config = Config {
         repository: RepositoryConfig {
-            separate_pull_requests: true,
+            separate_pull_requests: Some(true),
             ..RepositoryConfig::default()
         },
         ..Config::default()
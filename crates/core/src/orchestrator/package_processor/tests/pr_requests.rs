@@ -7,19 +7,25 @@
 //! - Single vs multiple package PR handling
 
 use semver::Version;
+use url::Url;
 
 use super::common::*;
 
 use crate::{
+    changelog::keep_a_changelog::ReleaseLinks,
     config::{
-        Config, package::PackageConfigBuilder, repository::RepositoryConfig,
+        Config, changelog::ChangelogFormat, package::PackageConfigBuilder,
+        repository::RepositoryConfig,
     },
     forge::{
-        request::{Commit, CreateReleaseBranchRequest, PullRequest, Tag},
+        request::{
+            Commit, CreateReleaseBranchRequest, FileUpdateType, PullRequest,
+            Tag,
+        },
         traits::MockForge,
     },
     orchestrator::tests::common::{PrBodyInput, make_pr_body},
-    packages::releasable::ReleasablePackage,
+    packages::releasable::{ReleasableChangelog, ReleasablePackage},
 };
 
 #[tokio::test]
@@ -245,7 +251,7 @@ async fn create_pr_branches_handles_multiple_packages_on_same_branch() {
 
     let config = Config {
         repository: RepositoryConfig {
-            separate_pull_requests: false,
+            separate_pull_requests: Some(false),
             ..RepositoryConfig::default()
         },
         ..Config::default()
@@ -341,7 +347,7 @@ async fn create_pr_branches_handles_separate_branches() {
 
     let config = Config {
         repository: RepositoryConfig {
-            separate_pull_requests: true,
+            separate_pull_requests: Some(true),
             ..RepositoryConfig::default()
         },
         ..Config::default()
@@ -430,6 +436,11 @@ async fn create_pr_branches_includes_file_changes() {
             ..Default::default()
         },
         notes: "Release notes".to_string(),
+        changelogs: vec![ReleasableChangelog {
+            path: "CHANGELOG.md".into(),
+            content: "Release notes".into(),
+            ..Default::default()
+        }],
         ..Default::default()
     };
 
@@ -443,6 +454,66 @@ async fn create_pr_branches_includes_file_changes() {
     assert_eq!(pr_requests.len(), 1);
 }
 
+#[test]
+fn release_pr_packages_merges_keep_a_changelog_files() {
+    let mut mock_forge = MockForge::new();
+
+    mock_forge
+        .expect_release_link_base_url()
+        .returning(|| Url::parse("https://example.com/releases/").unwrap());
+    mock_forge
+        .expect_compare_link_base_url()
+        .returning(|| Url::parse("https://example.com/compare/").unwrap());
+
+    let processor = create_package_processor(mock_forge, None, None);
+
+    let releasable = ReleasablePackage {
+        name: "test-pkg".to_string(),
+        tag: Tag {
+            name: "v1.1.0".to_string(),
+            semver: Version::parse("1.1.0").unwrap(),
+            ..Default::default()
+        },
+        tag_compare_link: "https://example.com/compare/v1.0.0...v1.1.0".into(),
+        changelogs: vec![ReleasableChangelog {
+            path: "CHANGELOG.md".into(),
+            content: "## [1.1.0]\n\n### Fixed\n\n- a fix".into(),
+            format: ChangelogFormat::KeepAChangelog,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let packages = processor.release_pr_packages(vec![releasable]).unwrap();
+
+    let change = packages[0]
+        .file_changes
+        .iter()
+        .find(|c| c.path == "CHANGELOG.md")
+        .unwrap();
+
+    assert_eq!(
+        change.update_type,
+        FileUpdateType::KeepAChangelog {
+            entry: "## [1.1.0]\n\n### Fixed\n\n- a fix".into(),
+            links: ReleaseLinks {
+                version: "1.1.0".into(),
+                compare_link: "https://example.com/compare/v1.0.0...v1.1.0"
+                    .into(),
+                unreleased_link: "https://example.com/compare/v1.1.0...HEAD"
+                    .into(),
+            },
+        }
+    );
+    // a changelog that doesn't exist yet is created with the full layout
+    assert!(
+        change.content.starts_with("# Changelog\n"),
+        "unexpected content:\n{}",
+        change.content
+    );
+    assert!(change.content.contains("\n## [Unreleased]\n\n## [1.1.0]\n"));
+}
+
 #[tokio::test]
 async fn create_pr_branches_uses_correct_title_format() {
     let mut mock_forge = MockForge::new();
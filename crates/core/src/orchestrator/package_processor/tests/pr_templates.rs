@@ -44,7 +44,7 @@ fn config(
 ) -> Option<Config> {
     Some(Config {
         repository: RepositoryConfig {
-            separate_pull_requests,
+            separate_pull_requests: Some(separate_pull_requests),
             ..RepositoryConfig::default()
         },
         defaults,
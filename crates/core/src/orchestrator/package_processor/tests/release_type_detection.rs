@@ -0,0 +1,111 @@
+//! Tests for `release_type = "auto"` detection.
+//!
+//! Tests for:
+//! - Picking the release type from the single manifest found
+//! - Falling back to generic when no known manifest exists
+//! - Rejecting directories with manifests for several release types
+
+use crate::{
+    analyzer::release::Release,
+    config::{
+        package::{PackageConfig, PackageConfigBuilder},
+        release_type::ReleaseType,
+    },
+    forge::{request::GetFileContentRequest, traits::MockForge},
+    packages::analyzed::AnalyzedPackage,
+    result::ReleasaurusError,
+};
+
+use super::common::*;
+
+fn auto_package() -> PackageConfig {
+    PackageConfigBuilder::default()
+        .name("test-pkg")
+        .path("pkg")
+        .release_type(ReleaseType::Auto)
+        .build()
+        .unwrap()
+}
+
+fn analyzed() -> Vec<AnalyzedPackage> {
+    vec![AnalyzedPackage {
+        name: "test-pkg".into(),
+        release: Some(Release::default()),
+    }]
+}
+
+/// Serves the listed paths with a placeholder body, everything else 404s.
+fn mock_with_files(files: &'static [&'static str]) -> MockForge {
+    let mut mock = MockForge::new();
+
+    mock.expect_get_file_content()
+        .returning(|req: GetFileContentRequest| {
+            if files.contains(&req.path.as_str()) {
+                Ok(Some(r#"{"version": "1.0.0"}"#.into()))
+            } else {
+                Ok(None)
+            }
+        });
+
+    mock
+}
+
+#[tokio::test]
+async fn releasable_packages_detects_single_manifest() {
+    let processor = create_package_processor(
+        mock_with_files(&["pkg/package.json"]),
+        Some(vec![auto_package()]),
+        None,
+    );
+
+    let releasable = processor.releasable_packages(analyzed()).await.unwrap();
+
+    assert_eq!(releasable[0].release_type, ReleaseType::Node);
+
+    let manifests = releasable[0].manifest_files.as_ref().unwrap();
+    assert!(
+        manifests
+            .iter()
+            .any(|m| m.path.to_string_lossy() == "pkg/package.json")
+    );
+}
+
+#[tokio::test]
+async fn releasable_packages_falls_back_to_generic_without_manifests() {
+    let processor = create_package_processor(
+        mock_with_files(&[]),
+        Some(vec![auto_package()]),
+        None,
+    );
+
+    let releasable = processor.releasable_packages(analyzed()).await.unwrap();
+
+    assert_eq!(releasable[0].release_type, ReleaseType::Generic);
+    assert!(releasable[0].manifest_files.is_none());
+}
+
+#[tokio::test]
+async fn releasable_packages_rejects_ambiguous_directory() {
+    let processor = create_package_processor(
+        mock_with_files(&["pkg/Cargo.toml", "pkg/pyproject.toml"]),
+        Some(vec![auto_package()]),
+        None,
+    );
+
+    let Err(err) = processor.releasable_packages(analyzed()).await else {
+        panic!("expected ambiguous manifests to be rejected");
+    };
+
+    assert!(matches!(err, ReleasaurusError::InvalidConfig(_)));
+
+    let msg = err.to_string();
+    assert!(msg.contains("ambiguous"), "unexpected error: {msg}");
+    assert!(
+        msg.contains("pkg/Cargo.toml (rust)"),
+        "unexpected error: {msg}"
+    );
+    assert!(
+        msg.contains("pkg/pyproject.toml (python)"),
+        "unexpected error: {msg}"
+    );
+}
@@ -0,0 +1,177 @@
+//! Tests for fixed and linked version groups.
+//!
+//! Tests for:
+//! - Releasing every fixed group member at the group's highest version
+//! - Aligning only the releasing members of a linked group
+//! - Rejecting members already past the group's version
+
+use semver::Version;
+
+use crate::{
+    config::{
+        Config,
+        package::PackageConfigBuilder,
+        version_group::{VersionGroupConfig, VersionGroupMode},
+    },
+    forge::{
+        request::{ForgeCommitBuilder, Tag},
+        traits::MockForge,
+    },
+    orchestrator::package_processor::PackageProcessor,
+    packages::{analyzed::AnalyzedPackage, prepared::PreparedPackage},
+};
+
+use super::common::*;
+
+fn processor(mode: VersionGroupMode) -> PackageProcessor {
+    let packages = ["sdk", "plugin", "extra"]
+        .iter()
+        .map(|name| {
+            PackageConfigBuilder::default()
+                .name(*name)
+                .path(format!("packages/{name}"))
+                .tag_prefix(format!("{name}-v"))
+                .build()
+                .unwrap()
+        })
+        .collect();
+
+    create_package_processor(
+        MockForge::new(),
+        Some(packages),
+        Some(Config {
+            version_groups: vec![VersionGroupConfig {
+                mode,
+                packages: vec!["sdk".into(), "plugin".into(), "extra".into()],
+            }],
+            ..Config::default()
+        }),
+    )
+}
+
+/// `sdk` has a feature, `plugin` a fix and `extra` nothing, all at 1.0.0.
+fn prepared() -> Vec<PreparedPackage> {
+    prepared_at(["1.0.0", "1.0.0", "1.0.0"])
+}
+
+/// Like [`prepared`], with `sdk`, `plugin` and `extra` at `versions`.
+fn prepared_at(versions: [&str; 3]) -> Vec<PreparedPackage> {
+    let commit = |id: &str, message: &str| {
+        ForgeCommitBuilder::default()
+            .id(id)
+            .short_id(id)
+            .message(message)
+            .timestamp(1000)
+            .build()
+            .unwrap()
+    };
+
+    [
+        ("sdk", vec![commit("aaa1111", "feat: new api")]),
+        ("plugin", vec![commit("bbb2222", "fix: crash")]),
+        ("extra", vec![]),
+    ]
+    .into_iter()
+    .zip(versions)
+    .map(|((name, commits), version)| PreparedPackage {
+        name: name.to_string(),
+        current_tag: Some(Tag {
+            name: format!("{name}-v{version}"),
+            semver: Version::parse(version).unwrap(),
+            ..Default::default()
+        }),
+        commits,
+        previous_authors: None,
+    })
+    .collect()
+}
+
+fn version(analyzed: &[AnalyzedPackage], name: &str) -> Option<String> {
+    analyzed
+        .iter()
+        .find(|a| a.name == name)
+        .unwrap()
+        .release
+        .as_ref()
+        .map(|r| r.tag.semver.to_string())
+}
+
+#[tokio::test]
+async fn fixed_group_releases_every_member_at_the_highest_version() {
+    let analyzed = processor(VersionGroupMode::Fixed)
+        .analyze_related_packages(prepared())
+        .await
+        .unwrap();
+
+    assert_eq!(version(&analyzed, "sdk").as_deref(), Some("1.1.0"));
+    assert_eq!(version(&analyzed, "plugin").as_deref(), Some("1.1.0"));
+    assert_eq!(version(&analyzed, "extra").as_deref(), Some("1.1.0"));
+
+    let plugin = analyzed[1].release.as_ref().unwrap();
+    assert_eq!(plugin.tag.name, "plugin-v1.1.0");
+    assert_eq!(plugin.commits[0].title, "crash");
+
+    let extra = analyzed[2].release.as_ref().unwrap();
+    assert!(extra.commits.is_empty());
+    assert_eq!(extra.sha, "aaa1111");
+}
+
+#[tokio::test]
+async fn linked_group_aligns_only_releasing_members() {
+    let analyzed = processor(VersionGroupMode::Linked)
+        .analyze_related_packages(prepared())
+        .await
+        .unwrap();
+
+    assert_eq!(version(&analyzed, "sdk").as_deref(), Some("1.1.0"));
+    assert_eq!(version(&analyzed, "plugin").as_deref(), Some("1.1.0"));
+    assert_eq!(version(&analyzed, "extra"), None);
+}
+
+#[tokio::test]
+async fn fixed_group_releases_members_behind_at_the_shared_version() {
+    let analyzed = processor(VersionGroupMode::Fixed)
+        .analyze_related_packages(prepared_at(["1.0.0", "0.4.0", "0.9.1"]))
+        .await
+        .unwrap();
+
+    assert_eq!(version(&analyzed, "sdk").as_deref(), Some("1.1.0"));
+    assert_eq!(version(&analyzed, "plugin").as_deref(), Some("1.1.0"));
+    assert_eq!(version(&analyzed, "extra").as_deref(), Some("1.1.0"));
+}
+
+/// `extra` has nothing to release but is tagged past the version `sdk`
+/// sets, so the group can't share one version.
+#[tokio::test]
+async fn fixed_member_past_the_shared_version_is_an_error() {
+    let err = processor(VersionGroupMode::Fixed)
+        .analyze_related_packages(prepared_at(["1.0.0", "1.0.0", "3.0.0"]))
+        .await
+        .unwrap_err();
+
+    assert!(
+        err.to_string().contains(
+            "package extra: current tag extra-v3.0.0 is already at or past \
+             version 1.1.0 shared by its fixed version group"
+        ),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn groups_without_releasing_members_are_left_alone() {
+    let unchanged = prepared()
+        .into_iter()
+        .map(|p| PreparedPackage {
+            commits: vec![],
+            ..p
+        })
+        .collect();
+
+    let analyzed = processor(VersionGroupMode::Fixed)
+        .analyze_related_packages(unchanged)
+        .await
+        .unwrap();
+
+    assert!(analyzed.iter().all(|a| a.release.is_none()));
+}
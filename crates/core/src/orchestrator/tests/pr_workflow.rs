@@ -176,7 +176,7 @@ async fn create_release_prs_creates_new_prs() {
 
     let config = Config {
         repository: RepositoryConfig {
-            separate_pull_requests: true,
+            separate_pull_requests: Some(true),
             ..RepositoryConfig::default()
         },
         ..Default::default()
@@ -274,7 +274,7 @@ async fn create_release_prs_targets_specific_package() {
 
     let config = Config {
         repository: RepositoryConfig {
-            separate_pull_requests: true,
+            separate_pull_requests: Some(true),
             ..RepositoryConfig::default()
         },
         ..Default::default()
@@ -129,7 +129,7 @@ async fn create_releases_handles_separate_pull_requests() {
 
     let config = Config {
         repository: RepositoryConfig {
-            separate_pull_requests: true,
+            separate_pull_requests: Some(true),
             ..RepositoryConfig::default()
         },
         ..Default::default()
@@ -197,7 +197,7 @@ async fn create_releases_targets_specific_package() {
 
     let config = Config {
         repository: RepositoryConfig {
-            separate_pull_requests: true,
+            separate_pull_requests: Some(true),
             ..RepositoryConfig::default()
         },
         ..Default::default()
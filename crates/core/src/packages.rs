@@ -7,6 +7,7 @@
 //! [`release_pr::ReleasePRPackage`]
 
 pub mod analyzed;
+pub mod dependency_graph;
 pub mod manifests;
 pub mod prepared;
 pub mod releasable;
@@ -3,7 +3,7 @@ use crate::analyzer::release::Release;
 /// Package after commit analysis.
 ///
 /// `release` is `None` when no commits triggered a version bump.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct AnalyzedPackage {
     pub name: String,
     pub release: Option<Release>,
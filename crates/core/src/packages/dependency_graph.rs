@@ -0,0 +1,114 @@
+//! Dependency relationships between the configured packages, read from
+//! their manifests.
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::packages::manifests::ManifestFile;
+
+/// Which configured packages depend on which others.
+///
+/// A package depends on another when one of its manifests lists the other's
+/// [canonical name][ManifestFile::canonical_name] as a runtime dependency.
+/// Workspace-level manifests shared between packages (those without a
+/// canonical name of their own) are ignored, so a package isn't credited
+/// with every dependency its workspace declares.
+#[derive(Debug, Default)]
+pub struct DependencyGraph {
+    /// Package name → names of the packages it depends on
+    dependencies: HashMap<String, BTreeSet<String>>,
+}
+
+impl DependencyGraph {
+    /// Builds the graph from each package's name and loaded manifests.
+    pub fn new(packages: &[(String, Vec<ManifestFile>)]) -> Self {
+        let package_manifests = |manifests: &[ManifestFile]| {
+            manifests
+                .iter()
+                .filter(|m| m.canonical_name().is_some())
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        let owners = packages
+            .iter()
+            .flat_map(|(name, manifests)| {
+                package_manifests(manifests)
+                    .into_iter()
+                    .filter_map(|m| m.canonical_name())
+                    .map(move |canonical| (canonical, name.clone()))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let dependencies = packages
+            .iter()
+            .map(|(name, manifests)| {
+                let deps = package_manifests(manifests)
+                    .iter()
+                    .flat_map(|m| m.dependency_names())
+                    .filter_map(|dep| owners.get(&dep))
+                    .filter(|owner| *owner != name)
+                    .cloned()
+                    .collect::<BTreeSet<_>>();
+
+                (name.clone(), deps)
+            })
+            .collect();
+
+        Self { dependencies }
+    }
+
+    /// Names of the configured packages `package` depends on, in name order.
+    pub fn dependencies_of(&self, package: &str) -> impl Iterator<Item = &str> {
+        self.dependencies
+            .get(package)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cargo(name: &str, deps: &[&str]) -> ManifestFile {
+        let deps = deps
+            .iter()
+            .map(|d| format!("{d} = {{ path = \"../{d}\" }}\n"))
+            .collect::<String>();
+
+        ManifestFile {
+            path: format!("crates/{name}/Cargo.toml").into(),
+            basename: "Cargo.toml".into(),
+            content: format!(
+                "[package]\nname = \"{name}\"\n[dependencies]\n{deps}"
+            ),
+        }
+    }
+
+    #[test]
+    fn links_packages_through_manifest_dependencies() {
+        let workspace = ManifestFile {
+            path: "Cargo.toml".into(),
+            basename: "Cargo.toml".into(),
+            content: "[workspace]\n[dependencies]\nlib = \"1\"\n".into(),
+        };
+
+        let graph = DependencyGraph::new(&[
+            (
+                "core".into(),
+                vec![workspace.clone(), cargo("core-rs", &[])],
+            ),
+            (
+                "cli".into(),
+                vec![workspace.clone(), cargo("cli", &["core-rs", "serde"])],
+            ),
+            ("lib".into(), vec![workspace, cargo("lib", &["lib"])]),
+        ]);
+
+        assert_eq!(graph.dependencies_of("cli").collect::<Vec<_>>(), ["core"]);
+        assert_eq!(graph.dependencies_of("core").count(), 0);
+        assert_eq!(graph.dependencies_of("lib").count(), 0);
+        assert_eq!(graph.dependencies_of("unknown").count(), 0);
+    }
+}
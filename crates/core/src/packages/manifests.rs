@@ -1,6 +1,6 @@
 use regex::Regex;
 use serde::{Serialize, ser::SerializeStruct};
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::LazyLock};
 
 use crate::config::package::GENERIC_VERSION_REGEX;
 
@@ -63,6 +63,246 @@ pub struct ManifestFile {
     pub content: String,
 }
 
+static MAVEN_PARENT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<parent>.*?</parent>").unwrap());
+
+static MAVEN_ARTIFACT_ID: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"<artifactId>\s*([^<\s]+)\s*</artifactId>").unwrap()
+});
+
+static MAVEN_DEPENDENCY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)<dependency>(.*?)</dependency>").unwrap()
+});
+
+static MAVEN_TEST_SCOPE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"<scope>\s*(test|provided)\s*</scope>").unwrap()
+});
+
+static GEMSPEC_DEPENDENCY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"\.add_(?:runtime_)?dependency\s*\(?\s*["']([^"']+)["']"#)
+        .unwrap()
+});
+
+/// Python requirement names end at the first version specifier, extra or
+/// marker, e.g. `core[cli]>=1.0; python_version > "3.8"`.
+static PYTHON_REQUIREMENT_NAME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*([A-Za-z0-9][A-Za-z0-9._-]*)").unwrap());
+
+impl ManifestFile {
+    /// Reads the canonical package name declared by this manifest, e.g.
+    /// `[package] name` in Cargo.toml or `"name"` in package.json. Returns
+    /// `None` for unsupported or unparsable manifests.
+    pub fn canonical_name(&self) -> Option<String> {
+        let json_name = || {
+            serde_json::from_str::<serde_json::Value>(&self.content)
+                .ok()?
+                .get("name")?
+                .as_str()
+                .map(String::from)
+        };
+
+        match self.basename.as_str() {
+            "Cargo.toml" => self
+                .content
+                .parse::<toml::Table>()
+                .ok()?
+                .get("package")?
+                .get("name")?
+                .as_str()
+                .map(String::from),
+            "package.json" | "composer.json" => json_name(),
+            "pyproject.toml" => {
+                let manifest = self.content.parse::<toml::Table>().ok()?;
+                manifest
+                    .get("project")
+                    .and_then(|p| p.get("name"))
+                    .or_else(|| {
+                        manifest.get("tool")?.get("poetry")?.get("name")
+                    })?
+                    .as_str()
+                    .map(String::from)
+            }
+            "pom.xml" => {
+                let without_parent =
+                    MAVEN_PARENT.replace_all(&self.content, "");
+                MAVEN_ARTIFACT_ID
+                    .captures(&without_parent)
+                    .map(|m| m[1].to_string())
+            }
+            "go.mod" => {
+                let module = self
+                    .content
+                    .lines()
+                    .find_map(|l| l.trim().strip_prefix("module "))?
+                    .trim();
+                let mut segments = module.rsplit('/');
+                let last = segments.next()?;
+                // major version suffixes (module/v2) aren't the name
+                let is_major = last.strip_prefix('v').is_some_and(|v| {
+                    !v.is_empty() && v.chars().all(|c| c.is_ascii_digit())
+                });
+                if is_major {
+                    segments.next().map(String::from)
+                } else {
+                    Some(last.to_string())
+                }
+            }
+            basename => basename.strip_suffix(".gemspec").map(String::from),
+        }
+    }
+
+    /// Reads the names of the packages this manifest depends on at runtime,
+    /// in the same form [`Self::canonical_name`] reports them. Development
+    /// and test dependencies are left out since they don't ship with the
+    /// package. Returns an empty list for unsupported or unparsable
+    /// manifests.
+    pub fn dependency_names(&self) -> Vec<String> {
+        match self.basename.as_str() {
+            "Cargo.toml" => cargo_dependency_names(&self.content),
+            "package.json" => json_dependency_names(
+                &self.content,
+                &["dependencies", "peerDependencies", "optionalDependencies"],
+            ),
+            "composer.json" => {
+                json_dependency_names(&self.content, &["require"])
+            }
+            "pyproject.toml" => python_dependency_names(&self.content),
+            "pom.xml" => MAVEN_DEPENDENCY
+                .captures_iter(&self.content)
+                .filter(|c| !MAVEN_TEST_SCOPE.is_match(&c[1]))
+                .filter_map(|c| {
+                    MAVEN_ARTIFACT_ID.captures(&c[1]).map(|m| m[1].to_string())
+                })
+                .collect(),
+            "go.mod" => go_dependency_names(&self.content),
+            basename if basename.ends_with(".gemspec") => GEMSPEC_DEPENDENCY
+                .captures_iter(&self.content)
+                .map(|c| c[1].to_string())
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+fn cargo_dependency_names(content: &str) -> Vec<String> {
+    let Ok(manifest) = content.parse::<toml::Table>() else {
+        return vec![];
+    };
+
+    let tables = ["dependencies", "build-dependencies"];
+
+    // target-specific tables, e.g. [target.'cfg(unix)'.dependencies]
+    let targets = manifest
+        .get("target")
+        .and_then(|t| t.as_table())
+        .into_iter()
+        .flat_map(|t| t.values());
+
+    std::iter::once(&manifest)
+        .chain(targets.filter_map(|t| t.as_table()))
+        .flat_map(|table| tables.iter().filter_map(|key| table.get(*key)))
+        .filter_map(|deps| deps.as_table())
+        .flat_map(|deps| deps.iter())
+        .map(|(key, spec)| {
+            // renamed dependencies: foo = { package = "real-name" }
+            spec.get("package")
+                .and_then(|p| p.as_str())
+                .unwrap_or(key)
+                .to_string()
+        })
+        .collect()
+}
+
+fn json_dependency_names(content: &str, keys: &[&str]) -> Vec<String> {
+    let Ok(manifest) = serde_json::from_str::<serde_json::Value>(content)
+    else {
+        return vec![];
+    };
+
+    keys.iter()
+        .filter_map(|key| manifest.get(key)?.as_object())
+        .flat_map(|deps| deps.keys().cloned())
+        .collect()
+}
+
+fn python_dependency_names(content: &str) -> Vec<String> {
+    let Ok(manifest) = content.parse::<toml::Table>() else {
+        return vec![];
+    };
+
+    let requirements = manifest
+        .get("project")
+        .and_then(|p| p.get("dependencies"))
+        .and_then(|d| d.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|r| r.as_str())
+        .filter_map(|r| PYTHON_REQUIREMENT_NAME.captures(r))
+        .map(|c| c[1].to_string());
+
+    let poetry = manifest
+        .get("tool")
+        .and_then(|t| t.get("poetry"))
+        .and_then(|p| p.get("dependencies"))
+        .and_then(|d| d.as_table())
+        .into_iter()
+        .flat_map(|d| d.keys())
+        .filter(|name| name.as_str() != "python")
+        .cloned();
+
+    requirements.chain(poetry).collect()
+}
+
+/// Module paths in `require` directives, reduced to the same last path
+/// segment that [`ManifestFile::canonical_name`] uses.
+fn go_dependency_names(content: &str) -> Vec<String> {
+    let mut names = vec![];
+    let mut in_block = false;
+
+    for line in content.lines().map(str::trim) {
+        let module = if in_block {
+            if line.starts_with(')') {
+                in_block = false;
+                continue;
+            }
+            line
+        } else if let Some(rest) = line.strip_prefix("require") {
+            let rest = rest.trim();
+            if rest.starts_with('(') {
+                in_block = true;
+                continue;
+            }
+            rest
+        } else {
+            continue;
+        };
+
+        let Some(path) = module.split_whitespace().next() else {
+            continue;
+        };
+
+        if path.starts_with("//") {
+            continue;
+        }
+
+        let mut segments = path.rsplit('/');
+        let Some(last) = segments.next() else {
+            continue;
+        };
+        let is_major = last.strip_prefix('v').is_some_and(|v| {
+            !v.is_empty() && v.chars().all(|c| c.is_ascii_digit())
+        });
+
+        if is_major {
+            names.extend(segments.next().map(String::from));
+        } else {
+            names.push(last.to_string());
+        }
+    }
+
+    names
+}
+
 impl Serialize for ManifestFile {
     fn serialize<S>(
         &self,
@@ -106,3 +346,114 @@ impl From<&AdditionalManifestFile> for ManifestFile {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(basename: &str, content: &str) -> ManifestFile {
+        ManifestFile {
+            path: basename.into(),
+            basename: basename.into(),
+            content: content.into(),
+        }
+    }
+
+    #[test]
+    fn canonical_name_reads_each_manifest_format() {
+        let cases = [
+            ("Cargo.toml", "[package]\nname = \"crate-a\"\n", "crate-a"),
+            ("package.json", r#"{"name": "@scope/pkg"}"#, "@scope/pkg"),
+            ("composer.json", r#"{"name": "vendor/lib"}"#, "vendor/lib"),
+            ("pyproject.toml", "[project]\nname = \"py-lib\"\n", "py-lib"),
+            (
+                "pyproject.toml",
+                "[tool.poetry]\nname = \"poetry-lib\"\n",
+                "poetry-lib",
+            ),
+            (
+                "pom.xml",
+                "<parent><artifactId>parent</artifactId></parent>\
+                 <artifactId>child</artifactId>",
+                "child",
+            ),
+            ("go.mod", "module github.com/org/svc/v3\n", "svc"),
+            ("my-gem.gemspec", "", "my-gem"),
+        ];
+
+        for (basename, content, expected) in cases {
+            assert_eq!(
+                manifest(basename, content).canonical_name().as_deref(),
+                Some(expected),
+                "{basename}"
+            );
+        }
+    }
+
+    #[test]
+    fn dependency_names_reads_runtime_dependencies() {
+        let cases: [(&str, &str, &[&str]); 7] = [
+            (
+                "Cargo.toml",
+                "[dependencies]\ncore = { workspace = true }\n\
+                 alias = { package = \"real\", path = \"../real\" }\n\
+                 [dev-dependencies]\ntest-utils = \"1\"\n\
+                 [target.'cfg(unix)'.dependencies]\nunix-only = \"1\"\n",
+                &["real", "core", "unix-only"],
+            ),
+            (
+                "package.json",
+                r#"{"dependencies": {"@scope/core": "^1.0.0"},
+                    "devDependencies": {"jest": "^29"}}"#,
+                &["@scope/core"],
+            ),
+            (
+                "composer.json",
+                r#"{"require": {"vendor/core": "^1.0"}}"#,
+                &["vendor/core"],
+            ),
+            (
+                "pyproject.toml",
+                "[project]\ndependencies = [\"py-core[cli]>=1.0\"]\n\
+                 [tool.poetry.dependencies]\npython = \"^3.9\"\nlib = \"1\"\n",
+                &["py-core", "lib"],
+            ),
+            (
+                "pom.xml",
+                "<dependencies><dependency><artifactId>core</artifactId>\
+                 </dependency><dependency><artifactId>junit</artifactId>\
+                 <scope>test</scope></dependency></dependencies>",
+                &["core"],
+            ),
+            (
+                "go.mod",
+                "module example.com/org/cli\n\
+                 require example.com/org/util v1.0.0\n\
+                 require (\n\texample.com/org/core/v2 v2.1.0\n)\n",
+                &["util", "core"],
+            ),
+            (
+                "cli.gemspec",
+                "spec.add_dependency \"core\", \"~> 1.0\"\n\
+                 spec.add_development_dependency \"rspec\"\n",
+                &["core"],
+            ),
+        ];
+
+        for (basename, content, expected) in cases {
+            assert_eq!(
+                manifest(basename, content).dependency_names(),
+                expected,
+                "{basename}"
+            );
+        }
+    }
+
+    #[test]
+    fn canonical_name_is_none_for_workspace_only_manifests() {
+        let cargo = manifest("Cargo.toml", "[workspace]\nmembers = []\n");
+
+        assert_eq!(cargo.canonical_name(), None);
+        assert_eq!(manifest("setup.py", "").canonical_name(), None);
+    }
+}
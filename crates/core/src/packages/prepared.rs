@@ -1,10 +1,13 @@
-use crate::forge::request::{ForgeCommit, Tag};
+use crate::forge::request::{ForgeCommit, ForgeCommitAuthor, Tag};
 
 /// Package ready for analysis, with commits filtered to those
 /// relevant to this package since its last release tag.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PreparedPackage {
     pub name: String,
     pub current_tag: Option<Tag>,
     pub commits: Vec<ForgeCommit>,
+    /// Authors of every commit up to `current_tag`, when the package's
+    /// changelog includes contributors
+    pub previous_authors: Option<Vec<ForgeCommitAuthor>>,
 }
@@ -2,8 +2,8 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 use crate::{
-    analyzer::release::Release,
-    config::release_type::ReleaseType,
+    analyzer::{commit::Commit, release::Release},
+    config::{changelog::ChangelogFormat, release_type::ReleaseType},
     forge::request::Tag,
     packages::manifests::{AdditionalManifestFile, ManifestFile},
 };
@@ -34,6 +34,19 @@ impl ReleasableSubPackage {
     }
 }
 
+/// A package's rendered changelog entry for one of its changelog files.
+#[derive(Debug, Default, Clone)]
+pub struct ReleasableChangelog {
+    /// Repository path of the changelog file
+    pub path: PathBuf,
+    /// Entry added to the file
+    pub content: String,
+    /// Regex matching the line the entry is inserted below
+    pub insert_after: String,
+    /// How the entry is merged into the file
+    pub format: ChangelogFormat,
+}
+
 /// Package ready for manifest updates and PR creation, with a
 /// computed next-version tag, changelog notes, and loaded manifest
 /// file content.
@@ -45,6 +58,9 @@ pub struct ReleasablePackage {
     pub notes: String,
     pub tag_compare_link: String,
     pub sha_compare_link: String,
+    pub commits: Vec<Commit>,
+    pub timestamp: i64,
+    pub changelogs: Vec<ReleasableChangelog>,
     pub sub_packages: Vec<ReleasableSubPackage>,
     pub manifest_files: Option<Vec<ManifestFile>>,
     pub additional_manifest_files: Option<Vec<AdditionalManifestFile>>,
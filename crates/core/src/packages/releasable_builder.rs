@@ -5,11 +5,12 @@ use crate::{
     packages::{
         manifests::{AdditionalManifestFile, ManifestFile},
         releasable::{
-            ReleasablePackage, ReleasableSubPackage,
+            ReleasableChangelog, ReleasablePackage, ReleasableSubPackage,
             SerializableReleasablePackage,
         },
         resolved::ResolvedPackage,
     },
+    result::Result,
 };
 
 /// Trait for building releasable package types from analyzed data.
@@ -22,7 +23,7 @@ pub trait ReleasablePackageBuilder: Sized {
         manifest_files: Option<Vec<ManifestFile>>,
         additional_manifest_files: Option<Vec<AdditionalManifestFile>>,
         sub_packages: Vec<ReleasableSubPackage>,
-    ) -> Self;
+    ) -> Result<Self>;
 }
 
 impl ReleasablePackageBuilder for ReleasablePackage {
@@ -33,18 +34,35 @@ impl ReleasablePackageBuilder for ReleasablePackage {
         manifest_files: Option<Vec<ManifestFile>>,
         additional_manifest_files: Option<Vec<AdditionalManifestFile>>,
         sub_packages: Vec<ReleasableSubPackage>,
-    ) -> Self {
-        Self {
+    ) -> Result<Self> {
+        // files without a template of their own get the release notes
+        let changelogs = pkg_config
+            .changelog_files
+            .iter()
+            .map(|file| {
+                Ok(ReleasableChangelog {
+                    path: file.path.clone(),
+                    content: file.render(&release)?,
+                    insert_after: file.insert_after.clone(),
+                    format: file.format,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
             name,
             release_type: pkg_config.release_type,
             tag: release.tag,
             notes: release.notes,
             tag_compare_link: release.tag_compare_link,
             sha_compare_link: release.sha_compare_link,
+            commits: release.commits,
+            timestamp: release.timestamp,
+            changelogs,
             sub_packages,
             additional_manifest_files,
             manifest_files,
-        }
+        })
     }
 }
 
@@ -56,8 +74,8 @@ impl ReleasablePackageBuilder for SerializableReleasablePackage {
         manifest_files: Option<Vec<ManifestFile>>,
         additional_manifest_files: Option<Vec<AdditionalManifestFile>>,
         sub_packages: Vec<ReleasableSubPackage>,
-    ) -> Self {
-        Self {
+    ) -> Result<Self> {
+        Ok(Self {
             name,
             path: pkg_config.normalized_full_path.clone(),
             release_type: pkg_config.release_type,
@@ -65,7 +83,7 @@ impl ReleasablePackageBuilder for SerializableReleasablePackage {
             sub_packages,
             additional_manifest_files,
             manifest_files,
-        }
+        })
     }
 }
 
@@ -75,10 +93,12 @@ mod tests {
 
     use crate::{
         config::{
+            changelog::ChangelogFormat,
             defaults::DEFAULT_COMMIT_AND_PR_TITLE_TEMPLATE,
             release_type::ReleaseType,
         },
         forge::request::Tag,
+        packages::resolved::{ChangelogFile, PathFilter},
     };
 
     use super::*;
@@ -92,8 +112,12 @@ mod tests {
             tag_prefix: "v".to_string(),
             sub_packages: vec![],
             aggregate_prereleases: false,
+            release_as: None,
             normalized_additional_paths: vec![],
+            path_filter: PathFilter::default(),
+            scopes: vec![],
             compiled_additional_manifests: vec![],
+            changelog_files: vec![],
             analyzer_config: Default::default(),
             versioning_config: Default::default(),
             commit_message_template: DEFAULT_COMMIT_AND_PR_TITLE_TEMPLATE
@@ -122,6 +146,10 @@ mod tests {
             include_pr_link: false,
             notes: "Test release notes".to_string(),
             timestamp: 1234567890,
+            release_as: None,
+            include_contributors: false,
+            contributors: vec![],
+            new_contributors: vec![],
         }
     }
 
@@ -137,7 +165,8 @@ mod tests {
             None,
             None,
             vec![],
-        );
+        )
+        .unwrap();
 
         assert_eq!(package.name, "test-package");
         assert_eq!(package.release_type, ReleaseType::Node);
@@ -160,7 +189,8 @@ mod tests {
             None,
             None,
             vec![],
-        );
+        )
+        .unwrap();
 
         assert_eq!(package.name, "test-package");
         assert_eq!(package.path, PathBuf::from("/test/path"));
@@ -189,7 +219,8 @@ mod tests {
             Some(manifest_files),
             None,
             vec![],
-        );
+        )
+        .unwrap();
 
         let manifest_files = package.manifest_files.as_ref().unwrap();
         assert_eq!(manifest_files.len(), 1);
@@ -213,9 +244,65 @@ mod tests {
             None,
             None,
             sub_packages,
-        );
+        )
+        .unwrap();
 
         assert_eq!(package.sub_packages.len(), 1);
         assert_eq!(package.sub_packages[0].name, "sub-pkg");
     }
+
+    #[test]
+    fn test_builder_renders_changelog_files() {
+        let mut pkg_config = create_test_resolved_package();
+        pkg_config.changelog_files = vec![
+            ChangelogFile {
+                path: PathBuf::from("/test/path/CHANGELOG.md"),
+                body: None,
+                insert_after: "^# Changelog$".into(),
+                format: ChangelogFormat::default(),
+                packaging: None,
+            },
+            ChangelogFile {
+                path: PathBuf::from("/test/path/HISTORY.rst"),
+                body: Some("{{ version }}\n=====".into()),
+                insert_after: "^History$".into(),
+                format: ChangelogFormat::default(),
+                packaging: None,
+            },
+        ];
+
+        let package = ReleasablePackage::build(
+            "test-package".to_string(),
+            create_test_release(),
+            &pkg_config,
+            None,
+            None,
+            vec![],
+        )
+        .unwrap();
+
+        let changelogs = package
+            .changelogs
+            .iter()
+            .map(|c| {
+                (
+                    c.path.to_string_lossy(),
+                    c.content.as_str(),
+                    c.insert_after.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            changelogs,
+            [
+                (
+                    "/test/path/CHANGELOG.md".into(),
+                    "Test release notes",
+                    "^# Changelog$"
+                ),
+                ("/test/path/HISTORY.rst".into(), "1.0.0\n=====", "^History$"),
+            ]
+        );
+    }
 }
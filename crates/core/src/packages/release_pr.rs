@@ -1,4 +1,7 @@
-use crate::forge::request::{FileChange, PullRequest, Tag};
+use crate::{
+    analyzer::commit::Commit,
+    forge::request::{FileChange, PullRequest, Tag},
+};
 
 /// Represents a fully analyzed and updated package ready for PR creation.
 /// Includes next tag and list of file changes to include in PR
@@ -9,6 +12,8 @@ pub struct ReleasePRPackage {
     pub notes: String,
     pub tag_compare_link: String,
     pub sha_compare_link: String,
+    pub commits: Vec<Commit>,
+    pub timestamp: i64,
     pub file_changes: Vec<FileChange>,
     pub release_branch: String,
     pub commit_message_template: String,
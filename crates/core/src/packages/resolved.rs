@@ -1,9 +1,17 @@
+use globset::GlobSet;
 use regex::Regex;
-use std::path::PathBuf;
+use semver::Version;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
 
 use crate::{
-    analyzer::config::AnalyzerConfig,
-    config::{release_type::ReleaseType, versioning::VersioningConfig},
+    analyzer::{config::AnalyzerConfig, release::Release},
+    changelog::packaging,
+    config::{
+        changelog::ChangelogFormat, release_type::ReleaseType,
+        versioning::VersioningConfig,
+    },
+    result::Result,
 };
 
 /// Compiled version of AdditionalManifest with pre-compiled regex.
@@ -18,6 +26,70 @@ pub struct CompiledAdditionalManifest {
     pub version_regex: Regex,
 }
 
+/// A file a package's changelog is written to in its release PR.
+#[derive(Debug, Clone)]
+pub struct ChangelogFile {
+    /// Repository path of the file
+    pub path: PathBuf,
+    /// Tera template for the file's entries, or `None` to write the release
+    /// notes
+    pub body: Option<String>,
+    /// Validated regex matching the line entries are inserted below
+    pub insert_after: String,
+    /// How entries are merged into the file
+    pub format: ChangelogFormat,
+    /// Package metadata for `debian` and `rpm` files
+    pub packaging: Option<ResolvedPackaging>,
+}
+
+impl ChangelogFile {
+    /// Renders the file's entry for `release`: its template with
+    /// `packaging` added to the context, or the release notes.
+    pub fn render(&self, release: &Release) -> Result<String> {
+        match (self.body.as_deref(), self.packaging.as_ref()) {
+            (Some(body), Some(settings)) => {
+                packaging::render(release, body, settings)
+            }
+            (Some(body), None) => release.render(body),
+            (None, _) => Ok(release.notes.clone()),
+        }
+    }
+}
+
+/// Resolved `packaging` settings of a `debian` or `rpm` changelog file.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedPackaging {
+    pub package: String,
+    pub maintainer: String,
+    pub distribution: String,
+    pub urgency: String,
+    pub revision: String,
+}
+
+/// Compiled `additional_paths` globs and `exclude_paths` deciding which
+/// changed files count towards a package.
+///
+/// Populated during config resolution. Plain `additional_paths` entries stay
+/// in [`ResolvedPackage::normalized_additional_paths`] and match by prefix.
+#[derive(Debug, Clone, Default)]
+pub struct PathFilter {
+    /// Glob `additional_paths` entries
+    pub include_globs: GlobSet,
+    /// Plain `exclude_paths` entries, each excluding a path and everything
+    /// under it
+    pub exclude_prefixes: Vec<PathBuf>,
+    /// Glob `exclude_paths` entries
+    pub exclude_globs: GlobSet,
+}
+
+impl PathFilter {
+    /// Whether changes to `file` are ignored by `exclude_paths`.
+    pub fn is_excluded(&self, file: &Path) -> bool {
+        self.exclude_prefixes.iter().any(|p| file.starts_with(p))
+            || self.exclude_globs.is_match(file)
+    }
+}
+
 /// A fully resolved package configuration ready for processing.
 ///
 /// This type represents a package after all configuration sources
@@ -33,8 +105,16 @@ pub struct ResolvedPackage {
     pub tag_prefix: String,
     pub sub_packages: Vec<ResolvedPackage>,
     pub normalized_additional_paths: Vec<PathBuf>,
+    pub path_filter: PathFilter,
+    /// Conventional commit scopes routed to this package
+    pub scopes: Vec<String>,
     pub compiled_additional_manifests: Vec<CompiledAdditionalManifest>,
+    /// Changelog files written in the release PR; empty when disabled
+    pub changelog_files: Vec<ChangelogFile>,
     pub aggregate_prereleases: bool,
+    /// Version to release at instead of the computed one, from a
+    /// `release_as` override
+    pub release_as: Option<Version>,
     pub analyzer_config: AnalyzerConfig,
     pub versioning_config: VersioningConfig,
     pub commit_message_template: String,
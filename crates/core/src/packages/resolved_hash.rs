@@ -73,6 +73,7 @@ mod tests {
             defaults::DEFAULT_COMMIT_AND_PR_TITLE_TEMPLATE,
             release_type::ReleaseType, versioning::VersioningConfig,
         },
+        packages::resolved::PathFilter,
     };
 
     use super::*;
@@ -87,8 +88,12 @@ mod tests {
             tag_prefix: "v".to_string(),
             sub_packages: vec![],
             aggregate_prereleases: false,
+            release_as: None,
             normalized_additional_paths: vec![],
+            path_filter: PathFilter::default(),
+            scopes: vec![],
             compiled_additional_manifests: vec![],
+            changelog_files: vec![],
             analyzer_config: AnalyzerConfig::default(),
             versioning_config: VersioningConfig::default(),
             commit_message_template: DEFAULT_COMMIT_AND_PR_TITLE_TEMPLATE
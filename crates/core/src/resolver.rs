@@ -1,23 +1,35 @@
 use derive_builder::Builder;
-use std::rc::Rc;
+use std::{collections::HashSet, rc::Rc};
 use url::Url;
 
 use crate::{
+    analyzer::mailmap::Mailmap,
     config::{
         Config,
         overrides::{CommitModifiers, GlobalOverrides, PackageOverridesHash},
         package::PackageConfig,
+        repository::ScopeRouting,
+        version_group::VersionGroupConfig,
     },
-    packages::resolved_hash::ResolvedPackageHash,
+    forge::manager::ForgeManager,
+    packages::{resolved::ChangelogFile, resolved_hash::ResolvedPackageHash},
     resolver::resolvers::{
         base_branch::resolve_base_branch,
+        changelog::resolve_aggregate_changelog,
         commit_modifiers::resolve_commit_modifiers,
+        discovery::{discover_packages, is_package_glob},
+        includes::{IncludedPackage, check_collisions, include_packages},
+        interpolation::{interpolate_config, process_env},
         package::{PackageResolverParams, resolve_package},
+        package_name::resolve_package_name,
+        tag_prefix::resolve_tag_prefix,
         templates::resolve_monorepo_templates,
+        version_groups::resolve_version_groups,
     },
     result::{ReleasaurusError, Result},
 };
 
+pub mod explain;
 pub mod resolvers;
 
 /// Fully resolved runtime configuration for the release pipeline.
@@ -35,12 +47,23 @@ pub struct ResolvedConfig {
     pub base_branch: String,
     /// Whether each package gets its own release PR.
     pub separate_pull_requests: bool,
+    /// Whether dependents of released packages are released too.
+    pub cascade: bool,
+    /// How commit scopes route commits to packages.
+    pub scope_routing: ScopeRouting,
+    /// Root changelog with one entry per release PR, if enabled.
+    pub aggregate_changelog: Option<ChangelogFile>,
+    /// Validated groups of packages whose versions move together.
+    pub version_groups: Vec<VersionGroupConfig>,
     /// Template to use for commit messages when separate_pull_requests=false
     pub monorepo_commit_message_template: String,
     /// Template to use for PR titles when separate_pull_requests=false
     pub monorepo_pr_title_template: String,
     /// Resolved per-package config, indexed by package name.
     pub package_configs: ResolvedPackageHash,
+    /// Fail instead of warn when a configured manifest target is left
+    /// unchanged by a release.
+    pub strict_manifest_updates: bool,
 }
 
 #[derive(Builder)]
@@ -51,19 +74,41 @@ pub struct Resolver {
     pub repo_default_branch: String,
     pub release_link_base_url: Url,
     pub compare_link_base_url: Url,
+    /// Base URL `#123` issue references link to by default; without one
+    /// they are left unlinked
+    #[builder(default)]
+    pub issue_link_base_url: Option<Url>,
+    /// The repository's `.mailmap`, applied to commit authors; empty when
+    /// the repository has none. See [`Resolver::load_mailmap`]
+    #[builder(default)]
+    pub mailmap: Mailmap,
     pub package_overrides: PackageOverridesHash,
     pub global_overrides: GlobalOverrides,
     pub commit_modifiers: CommitModifiers,
+    #[builder(default)]
+    pub strict_manifest_updates: bool,
 }
 
 impl ResolverBuilder {
+    /// Builds the resolver, interpolating `${VAR}` references in the TOML
+    /// config from the process environment.
     pub fn build(&self) -> Result<Resolver> {
-        self._build().map_err(|e| {
+        let mut resolver = self._build().map_err(|e| {
             ReleasaurusError::invalid_config(format!(
                 "Failed to build resolver: {}",
                 e
             ))
-        })
+        })?;
+
+        let allowlist = &resolver.toml_config.repository.env_allowlist;
+
+        if let Some(config) =
+            interpolate_config(&*resolver.toml_config, allowlist, &process_env)?
+        {
+            resolver.toml_config = Rc::new(config);
+        }
+
+        Ok(resolver)
     }
 }
 
@@ -72,15 +117,109 @@ impl Resolver {
         ResolverBuilder::default()
     }
 
+    /// The branch release PRs target, after CLI overrides and `${VAR}`
+    /// interpolation of the TOML config.
+    pub fn base_branch(&self) -> String {
+        resolve_base_branch(
+            &self.toml_config.repository,
+            &self.global_overrides,
+            &self.repo_default_branch,
+        )
+    }
+
+    /// Loads the repository's `.mailmap` from [`Self::base_branch`] into
+    /// [`Self::mailmap`], replacing any set on the builder.
+    pub async fn load_mailmap(&mut self, forge: &ForgeManager) -> Result<()> {
+        self.mailmap = Mailmap::load(forge, Some(self.base_branch())).await?;
+
+        Ok(())
+    }
+
+    /// Collects the TOML config's packages and the package fragments matched
+    /// by its `include` globs, then expands glob package entries (a glob
+    /// `path` or a `packages` manifest glob) into one [`PackageConfig`] per
+    /// package found on the base branch. Must run before [`Self::resolve`]
+    /// when the config may contain glob entries or includes.
+    pub async fn discover_packages(
+        &self,
+        forge: &ForgeManager,
+    ) -> Result<Vec<PackageConfig>> {
+        let base_branch = self.base_branch();
+
+        let mut included =
+            include_packages(&self.toml_config.include, forge, &base_branch)
+                .await?;
+
+        for include in included.iter_mut() {
+            if let Some(package) = interpolate_config(
+                &include.package,
+                &self.toml_config.repository.env_allowlist,
+                &process_env,
+            )
+            .map_err(|e| {
+                ReleasaurusError::invalid_config(format!(
+                    "{}: {e}",
+                    include.fragment
+                ))
+            })? {
+                include.package = package;
+            }
+        }
+
+        let mut packages = discover_packages(
+            self.toml_config.packages_or_root(),
+            forge,
+            &base_branch,
+        )
+        .await?;
+
+        // fragments are expanded one at a time so each discovered package
+        // still names the fragment it came from
+        let mut expanded = vec![];
+
+        for include in included {
+            let discovered =
+                discover_packages(vec![include.package], forge, &base_branch)
+                    .await?;
+
+            expanded.extend(discovered.into_iter().map(|package| {
+                IncludedPackage {
+                    fragment: include.fragment.clone(),
+                    package,
+                }
+            }));
+        }
+
+        check_collisions(&packages, &expanded, |package| {
+            let name = resolve_package_name(package, &self.repo_name);
+            let tag_prefix = resolve_tag_prefix(
+                &name,
+                package,
+                &self.package_overrides,
+                &self.global_overrides,
+            );
+
+            (name, tag_prefix)
+        })?;
+
+        packages.extend(expanded.into_iter().map(|i| i.package));
+
+        Ok(packages)
+    }
+
     pub fn resolve(
         &self,
         packages: Vec<PackageConfig>,
     ) -> Result<Rc<ResolvedConfig>> {
-        let base_branch = resolve_base_branch(
-            &self.toml_config.repository,
-            &self.global_overrides,
-            &self.repo_default_branch,
-        );
+        if let Some(package) = packages.iter().find(|p| is_package_glob(p)) {
+            return Err(ReleasaurusError::invalid_config(format!(
+                "package glob {} must be expanded with \
+                 Resolver::discover_packages before resolving",
+                package.packages.as_deref().unwrap_or(&package.path)
+            )));
+        }
+
+        let base_branch = self.base_branch();
 
         let commit_modifiers = resolve_commit_modifiers(
             &self.toml_config.repository,
@@ -88,7 +227,7 @@ impl Resolver {
         )?;
 
         let separate_pull_requests =
-            self.toml_config.repository.separate_pull_requests;
+            self.toml_config.repository.separate_pull_requests();
 
         let mut resolved_packages = vec![];
 
@@ -105,6 +244,8 @@ impl Resolver {
                 global_overrides: &self.global_overrides,
                 compare_link_base_url: &self.compare_link_base_url,
                 release_link_base_url: &self.release_link_base_url,
+                issue_link_base_url: self.issue_link_base_url.as_ref(),
+                mailmap: &self.mailmap,
             };
 
             let resolved_package = resolve_package(params)?;
@@ -114,22 +255,52 @@ impl Resolver {
 
         let resolved_hash = ResolvedPackageHash::new(resolved_packages)?;
 
+        let version_groups = resolve_version_groups(
+            &self.toml_config.version_groups,
+            &resolved_hash
+                .hash()
+                .keys()
+                .map(String::as_str)
+                .collect::<HashSet<_>>(),
+        )?;
+
+        let aggregate_changelog = resolve_aggregate_changelog(
+            self.toml_config.repository.aggregate_changelog.as_ref(),
+            separate_pull_requests,
+            &resolved_hash,
+        )?;
+
         Ok(Rc::new(ResolvedConfig {
             repo_name: self.repo_name.clone(),
             base_branch,
             package_configs: resolved_hash,
             separate_pull_requests,
+            cascade: self.toml_config.repository.cascade(),
+            scope_routing: self
+                .toml_config
+                .repository
+                .scope_routing
+                .unwrap_or_default(),
+            aggregate_changelog,
+            version_groups,
             monorepo_commit_message_template: monorepo_templates.commit_message,
             monorepo_pr_title_template: monorepo_templates.pr_title,
+            strict_manifest_updates: self.strict_manifest_updates,
         }))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::{collections::HashMap, path::PathBuf};
 
-    use crate::config::{defaults::DefaultsConfig, package::PackageConfig};
+    use crate::{
+        config::{
+            changelog::AggregateChangelogConfig, defaults::DefaultsConfig,
+            package::PackageConfig, repository::RepositoryConfig,
+        },
+        forge::{manager::ForgeOptions, traits::MockForge},
+    };
 
     use super::*;
 
@@ -184,6 +355,131 @@ mod tests {
         assert_eq!(pkg.pr_title_template, "pkg title");
     }
 
+    #[test]
+    fn resolve_rejects_unexpanded_package_globs() {
+        let resolver = resolver(Config::default());
+        let package = PackageConfig {
+            path: "crates/*".into(),
+            ..PackageConfig::default()
+        };
+
+        let Err(err) = resolver.resolve(vec![package]) else {
+            panic!("expected unexpanded glob to be rejected");
+        };
+
+        assert!(err.to_string().contains("crates/*"), "{err}");
+    }
+
+    fn forge(files: Vec<(&'static str, &'static str)>) -> ForgeManager {
+        let mut mock = MockForge::new();
+        let paths: Vec<String> =
+            files.iter().map(|(p, _)| p.to_string()).collect();
+        let files = HashMap::<_, _>::from_iter(files);
+
+        mock.expect_list_files()
+            .returning(move |_| Ok(paths.clone()));
+        mock.expect_get_file_content().returning(move |req| {
+            Ok(files.get(req.path.as_str()).map(|c| c.to_string()))
+        });
+
+        ForgeManager::new(Box::new(mock), ForgeOptions { dry_run: false })
+    }
+
+    /// A config without `[[package]]` entries manages the repository root,
+    /// unless it pulls its packages in through `include`.
+    #[tokio::test]
+    async fn discover_packages_falls_back_to_root_only_without_includes() {
+        let config: Config = toml::from_str("[repository]\n").unwrap();
+        let packages = resolver(config)
+            .discover_packages(&forge(vec![]))
+            .await
+            .unwrap();
+
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages[0].path, ".");
+
+        let config: Config =
+            toml::from_str("include = [\"*/releasaurus.package.toml\"]\n")
+                .unwrap();
+        let packages = resolver(config)
+            .discover_packages(&forge(vec![(
+                "api/releasaurus.package.toml",
+                "name = \"api\"\n",
+            )]))
+            .await
+            .unwrap();
+
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages[0].name, "api");
+        assert_eq!(packages[0].path, "api");
+    }
+
+    /// The mailmap is read from the interpolated base branch, not from the
+    /// literal `${VAR}` in the TOML config.
+    #[tokio::test]
+    async fn load_mailmap_reads_the_interpolated_base_branch() {
+        let config: Config = toml::from_str(
+            "[repository]\n\
+             base_branch = \"${RELEASAURUS_TEST_MAILMAP_BRANCH:-release}\"\n\
+             env_allowlist = [\"RELEASAURUS_TEST_MAILMAP_BRANCH\"]\n",
+        )
+        .unwrap();
+
+        let mut mock = MockForge::new();
+        mock.expect_get_file_content()
+            .withf(|req| {
+                req.branch.as_deref() == Some("release")
+                    && req.path == ".mailmap"
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(Some(
+                    "Jane Doe <jane@example.com> <jd@old.example>\n".into(),
+                ))
+            });
+        let forge =
+            ForgeManager::new(Box::new(mock), ForgeOptions { dry_run: false });
+
+        let mut resolver = resolver(config);
+        resolver.load_mailmap(&forge).await.unwrap();
+
+        assert_eq!(resolver.base_branch(), "release");
+        assert_eq!(
+            resolver.mailmap.resolve("jd", "jd@old.example"),
+            ("Jane Doe".to_string(), "jane@example.com".to_string())
+        );
+    }
+
+    /// Fragment packages collide on the name and tag prefix they resolve
+    /// to, not only on the keys they set.
+    #[tokio::test]
+    async fn discover_packages_rejects_derived_collisions_with_fragments() {
+        let config: Config = toml::from_str(
+            "include = [\"*/releasaurus.package.toml\"]\n\
+             [[package]]\nname = \"site\"\npath = \"site\"\n\
+             tag_prefix = \"api-v\"\n",
+        )
+        .unwrap();
+
+        let Err(err) = resolver(config)
+            .discover_packages(&forge(vec![(
+                "api/releasaurus.package.toml",
+                "release_type = \"node\"\n",
+            )]))
+            .await
+        else {
+            panic!("expected the derived tag prefix to collide");
+        };
+
+        assert!(
+            err.to_string().contains(
+                "api/releasaurus.package.toml: tag prefix 'api-v' is \
+                 already used in the root config"
+            ),
+            "{err}"
+        );
+    }
+
     /// Validation runs during resolution, so a bad template stops the
     /// release before any forge call is made.
     #[test]
@@ -202,4 +498,55 @@ mod tests {
 
         assert!(matches!(err, ReleasaurusError::InvalidConfig(_)));
     }
+
+    #[test]
+    fn resolve_aggregate_changelog_at_repository_root() {
+        let config = |aggregate_changelog, separate_pull_requests| Config {
+            repository: RepositoryConfig {
+                aggregate_changelog,
+                separate_pull_requests: Some(separate_pull_requests),
+                ..RepositoryConfig::default()
+            },
+            ..Config::default()
+        };
+        let nested = || PackageConfig {
+            path: "packages/api".into(),
+            ..package("api")
+        };
+
+        let resolved = resolver(config(None, false))
+            .resolve(vec![nested()])
+            .unwrap();
+
+        assert!(resolved.aggregate_changelog.is_none());
+
+        let resolved =
+            resolver(config(Some(AggregateChangelogConfig::default()), false))
+                .resolve(vec![nested()])
+                .unwrap();
+        let file = resolved.aggregate_changelog.as_ref().unwrap();
+
+        assert_eq!(file.path, PathBuf::from("CHANGELOG.md"));
+        assert!(file.body.is_some());
+
+        // Every package in a separate PR leaves nothing to aggregate
+        let Err(err) =
+            resolver(config(Some(AggregateChangelogConfig::default()), true))
+                .resolve(vec![nested()])
+        else {
+            panic!("expected separate pull requests to be rejected");
+        };
+
+        assert!(err.to_string().contains("separate_pull_requests"), "{err}");
+
+        // The root package already owns CHANGELOG.md
+        let Err(err) =
+            resolver(config(Some(AggregateChangelogConfig::default()), false))
+                .resolve(vec![package("root")])
+        else {
+            panic!("expected the path collision to be rejected");
+        };
+
+        assert!(err.to_string().contains("package root"), "{err}");
+    }
 }
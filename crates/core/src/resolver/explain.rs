@@ -0,0 +1,691 @@
+//! Explains a resolved configuration: the effective value of each
+//! per-package setting, which tier it came from, and whether the paths and
+//! manifests it points at exist in the repository.
+
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::{collections::HashSet, fmt, path::Path};
+
+use crate::{
+    config::{
+        package::PackageConfig,
+        release_type::ReleaseType,
+        versioning::{
+            DEFAULT_BREAKING_ALWAYS_INCREMENT_MAJOR,
+            DEFAULT_FEAT_ALWAYS_INCREMENT_MINOR, DEFAULT_SKIP_MERGE_COMMITS,
+            DEFAULT_VERSION_TYPE,
+        },
+    },
+    forge::manager::ForgeManager,
+    packages::resolved::ResolvedPackage,
+    resolver::{
+        ResolvedConfig, Resolver,
+        resolvers::{
+            package_name::resolve_package_name,
+            path_utils::{GLOB_CHARS, normalize_path},
+        },
+    },
+    result::Result,
+    updater::manager::UpdateManager,
+};
+
+/// The configuration tier an effective setting was taken from, highest
+/// precedence first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettingSource {
+    /// A `--set-package <name>.<key>=` flag
+    CliPackage,
+    /// A global CLI flag such as `--tag-prefix`
+    CliGlobal,
+    /// The package's own `[[package]]` entry
+    Package,
+    /// The `[defaults]` table
+    Defaults,
+    /// Computed from other settings, e.g. a name taken from the path
+    Derived,
+    /// Releasaurus' built-in default
+    BuiltIn,
+}
+
+impl fmt::Display for SettingSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let source = match self {
+            SettingSource::CliPackage => "cli (--set-package)",
+            SettingSource::CliGlobal => "cli",
+            SettingSource::Package => "package",
+            SettingSource::Defaults => "defaults",
+            SettingSource::Derived => "derived",
+            SettingSource::BuiltIn => "built-in",
+        };
+
+        write!(f, "{source}")
+    }
+}
+
+/// One effective setting of a package.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExplainedSetting {
+    /// Config key, dotted for nested tables (e.g. `versioning.version_type`)
+    pub key: String,
+    /// Effective value after resolution
+    pub value: Value,
+    /// Tier the value came from
+    pub source: SettingSource,
+}
+
+/// The effective settings of one package and any problems found with the
+/// paths and manifests it references.
+#[derive(Debug, Clone, Serialize)]
+pub struct PackageExplanation {
+    pub name: String,
+    pub settings: Vec<ExplainedSetting>,
+    pub problems: Vec<String>,
+}
+
+impl Resolver {
+    /// Describes every resolved package in `resolved`, naming the tier each
+    /// setting came from. `packages` must be the (discovered) package
+    /// configs `resolved` was produced from.
+    pub fn explain(
+        &self,
+        packages: &[PackageConfig],
+        resolved: &ResolvedConfig,
+    ) -> Result<Vec<PackageExplanation>> {
+        let defaults = serde_json::to_value(&self.toml_config.defaults)?;
+
+        packages
+            .iter()
+            .map(|config| {
+                let name = resolve_package_name(config, &self.repo_name);
+                let package = resolved.package_configs.get(&name)?;
+
+                Ok(PackageExplanation {
+                    name,
+                    settings: self
+                        .explain_package(config, package, &defaults)?,
+                    problems: vec![],
+                })
+            })
+            .collect()
+    }
+
+    /// Resolves the configuration, explains it, and checks through the
+    /// forge that every package path, additional path and manifest exists
+    /// on the base branch.
+    pub async fn check(
+        &self,
+        forge: &ForgeManager,
+    ) -> Result<Vec<PackageExplanation>> {
+        let packages = self.discover_packages(forge).await?;
+        let resolved = self.resolve(packages.clone())?;
+        let mut explanations = self.explain(&packages, &resolved)?;
+
+        let files =
+            forge.list_files(Some(resolved.base_branch.clone())).await?;
+        let files = files.iter().map(String::as_str).collect::<HashSet<_>>();
+
+        for explanation in explanations.iter_mut() {
+            let package = resolved.package_configs.get(&explanation.name)?;
+
+            check_package(
+                package,
+                &files,
+                forge,
+                &resolved.base_branch,
+                &mut explanation.problems,
+            )
+            .await?;
+
+            for sub_package in package.sub_packages.iter() {
+                check_package(
+                    sub_package,
+                    &files,
+                    forge,
+                    &resolved.base_branch,
+                    &mut explanation.problems,
+                )
+                .await?;
+            }
+        }
+
+        Ok(explanations)
+    }
+
+    fn explain_package(
+        &self,
+        config: &PackageConfig,
+        package: &ResolvedPackage,
+        defaults: &Value,
+    ) -> Result<Vec<ExplainedSetting>> {
+        let config_value = serde_json::to_value(config)?;
+        let package_overrides = self.package_overrides.get(&package.name);
+        let global = &self.global_overrides;
+
+        // tier lookup for keys only settable in config files
+        let from_config = |key: &str, allow_defaults: bool| {
+            let pointer = format!("/{}", key.replace('.', "/"));
+
+            if config_value.pointer(&pointer).is_some_and(|v| !v.is_null()) {
+                SettingSource::Package
+            } else if allow_defaults
+                && defaults.pointer(&pointer).is_some_and(|v| !v.is_null())
+            {
+                SettingSource::Defaults
+            } else {
+                SettingSource::BuiltIn
+            }
+        };
+
+        let cli_or_config = |key: &str, package_cli: bool, global_cli: bool| {
+            if package_cli {
+                SettingSource::CliPackage
+            } else if global_cli {
+                SettingSource::CliGlobal
+            } else {
+                from_config(key, true)
+            }
+        };
+
+        let setting =
+            |key: &str, value: Value, source: SettingSource| ExplainedSetting {
+                key: key.into(),
+                value,
+                source,
+            };
+
+        let is_nested = config.workspace_root != "." || config.path != ".";
+
+        let tag_prefix_source =
+            if package_overrides.is_some_and(|o| o.tag_prefix.is_some()) {
+                SettingSource::CliPackage
+            } else if global.tag_prefix.is_some() {
+                SettingSource::CliGlobal
+            } else if config.tag_prefix.is_some() {
+                SettingSource::Package
+            } else if is_nested {
+                SettingSource::Derived
+            } else {
+                SettingSource::BuiltIn
+            };
+
+        let versioning = serde_json::to_value(&package.versioning_config)?;
+        let versioning_value = |key: &str, builtin: Value| {
+            versioning
+                .get(key)
+                .filter(|v| !v.is_null())
+                .cloned()
+                .unwrap_or(builtin)
+        };
+
+        let prerelease = package.versioning_config.prerelease.as_ref();
+
+        let mut settings = vec![
+            setting(
+                "name",
+                json!(package.name),
+                if config.name.is_empty() {
+                    SettingSource::Derived
+                } else {
+                    SettingSource::Package
+                },
+            ),
+            setting(
+                "workspace_root",
+                json!(display_path(&package.normalized_workspace_root)),
+                if config.workspace_root == "." {
+                    SettingSource::BuiltIn
+                } else {
+                    SettingSource::Package
+                },
+            ),
+            setting(
+                "path",
+                json!(display_path(&package.normalized_full_path)),
+                if config.path == "." {
+                    SettingSource::BuiltIn
+                } else {
+                    SettingSource::Package
+                },
+            ),
+            setting(
+                "release_type",
+                json!(package.release_type),
+                from_config("release_type", false),
+            ),
+            setting("tag_prefix", json!(package.tag_prefix), tag_prefix_source),
+            setting(
+                "additional_paths",
+                json!(
+                    package
+                        .normalized_additional_paths
+                        .iter()
+                        .map(|p| display_path(p))
+                        .chain(
+                            config
+                                .additional_paths
+                                .iter()
+                                .flatten()
+                                .filter(|p| p.contains(GLOB_CHARS))
+                                .map(|p| normalize_path(p).to_string())
+                        )
+                        .collect::<Vec<_>>()
+                ),
+                from_config("additional_paths", false),
+            ),
+            setting(
+                "exclude_paths",
+                json!(
+                    config
+                        .exclude_paths
+                        .iter()
+                        .flatten()
+                        .map(|p| normalize_path(p).to_string())
+                        .collect::<Vec<_>>()
+                ),
+                from_config("exclude_paths", false),
+            ),
+            setting(
+                "scopes",
+                json!(package.scopes),
+                from_config("scopes", false),
+            ),
+            setting(
+                "additional_manifest_files",
+                json!(
+                    package
+                        .compiled_additional_manifests
+                        .iter()
+                        .map(|m| display_path(&m.path))
+                        .collect::<Vec<_>>()
+                ),
+                from_config("additional_manifest_files", false),
+            ),
+            setting(
+                "versioning.version_type",
+                versioning_value("version_type", json!(DEFAULT_VERSION_TYPE)),
+                cli_or_config(
+                    "versioning.version_type",
+                    package_overrides.is_some_and(|o| o.version_type.is_some()),
+                    global.version_type.is_some(),
+                ),
+            ),
+            setting(
+                "versioning.prerelease.suffix",
+                json!(prerelease.map(|p| p.suffix.clone())),
+                cli_or_config(
+                    "versioning.prerelease.suffix",
+                    package_overrides
+                        .is_some_and(|o| o.prerelease_suffix.is_some()),
+                    global.prerelease_suffix.is_some(),
+                ),
+            ),
+            setting(
+                "versioning.prerelease.strategy",
+                json!(prerelease.map(|p| p.strategy)),
+                cli_or_config(
+                    "versioning.prerelease.strategy",
+                    package_overrides
+                        .is_some_and(|o| o.prerelease_strategy.is_some()),
+                    global.prerelease_strategy.is_some(),
+                ),
+            ),
+        ];
+
+        for (key, builtin) in [
+            ("auto_start_next", json!(false)),
+            (
+                "breaking_always_increment_major",
+                json!(DEFAULT_BREAKING_ALWAYS_INCREMENT_MAJOR),
+            ),
+            (
+                "features_always_increment_minor",
+                json!(DEFAULT_FEAT_ALWAYS_INCREMENT_MINOR),
+            ),
+            ("skip_merge_commits", json!(DEFAULT_SKIP_MERGE_COMMITS)),
+            ("custom_major_increment_regex", Value::Null),
+            ("custom_minor_increment_regex", Value::Null),
+        ] {
+            let value = versioning_value(key, builtin);
+            let key = format!("versioning.{key}");
+            let source = from_config(&key, true);
+            settings.push(setting(&key, value, source));
+        }
+
+        let analyzer = &package.analyzer_config;
+
+        // the files come from three keys; report the highest tier setting any
+        let changelog_files_sources =
+            ["changelog.enabled", "changelog.path", "changelog.output"]
+                .map(|key| from_config(key, true));
+        let changelog_files_source =
+            [SettingSource::Package, SettingSource::Defaults]
+                .into_iter()
+                .find(|source| changelog_files_sources.contains(source))
+                .unwrap_or(SettingSource::BuiltIn);
+
+        settings.extend([
+            setting(
+                "changelog.body",
+                json!(analyzer.body),
+                from_config("changelog.body", true),
+            ),
+            setting(
+                "changelog.include_author",
+                json!(analyzer.include_author),
+                from_config("changelog.include_author", true),
+            ),
+            setting(
+                "changelog.include_pr_link",
+                json!(analyzer.include_pr_link),
+                from_config("changelog.include_pr_link", true),
+            ),
+            setting(
+                "changelog.include_contributors",
+                json!(analyzer.include_contributors),
+                from_config("changelog.include_contributors", true),
+            ),
+            setting(
+                "changelog.aggregate_prereleases",
+                json!(package.aggregate_prereleases),
+                from_config("changelog.aggregate_prereleases", true),
+            ),
+            setting(
+                "changelog.files",
+                json!(
+                    package
+                        .changelog_files
+                        .iter()
+                        .map(|f| display_path(&f.path))
+                        .collect::<Vec<_>>()
+                ),
+                changelog_files_source,
+            ),
+            setting(
+                "commit_message_template",
+                json!(package.commit_message_template),
+                from_config("commit_message_template", true),
+            ),
+            setting(
+                "pr_title_template",
+                json!(package.pr_title_template),
+                from_config("pr_title_template", true),
+            ),
+        ]);
+
+        Ok(settings)
+    }
+}
+
+/// Records a problem for each path or manifest of `package` missing from
+/// `files`.
+async fn check_package(
+    package: &ResolvedPackage,
+    files: &HashSet<&str>,
+    forge: &ForgeManager,
+    base_branch: &str,
+    problems: &mut Vec<String>,
+) -> Result<()> {
+    let exists_dir = |dir: &Path| {
+        let dir = dir.to_string_lossy();
+        dir.is_empty()
+            || dir == "."
+            || files.iter().any(|f| {
+                f.strip_prefix(dir.as_ref())
+                    .is_some_and(|rest| rest.starts_with('/'))
+            })
+    };
+    let exists_file =
+        |file: &Path| files.contains(file.to_string_lossy().as_ref());
+
+    if !exists_dir(&package.normalized_full_path) {
+        problems.push(format!(
+            "{}: package path {} does not exist",
+            package.name,
+            package.normalized_full_path.display()
+        ));
+        return Ok(());
+    }
+
+    for additional in package.normalized_additional_paths.iter() {
+        if !exists_dir(additional) && !exists_file(additional) {
+            problems.push(format!(
+                "{}: additional path {} does not exist",
+                package.name,
+                additional.display()
+            ));
+        }
+    }
+
+    // compiled manifest paths are already relative to the repository root
+    for manifest in package.compiled_additional_manifests.iter() {
+        if !exists_file(&manifest.path) {
+            problems.push(format!(
+                "{}: additional manifest {} does not exist",
+                package.name,
+                manifest.path.display()
+            ));
+        }
+    }
+
+    match package.release_type {
+        ReleaseType::Generic => {}
+        ReleaseType::Auto => {
+            if let Err(e) =
+                UpdateManager::detect_release_type(package, forge, base_branch)
+                    .await
+            {
+                problems.push(e.to_string());
+            }
+        }
+        release_type => {
+            let found = UpdateManager::release_type_markers(&package.name)
+                .into_iter()
+                .filter(|(r, _)| *r == release_type)
+                .flat_map(|(_, markers)| markers)
+                .any(|marker| {
+                    exists_file(&package.normalized_full_path.join(marker))
+                });
+
+            if !found {
+                problems.push(format!(
+                    "{}: no {release_type} manifest found in {}",
+                    package.name,
+                    display_path(&package.normalized_full_path)
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Renders a normalized path, showing the repository root as `.`.
+fn display_path(path: &Path) -> String {
+    let path = path.to_string_lossy();
+    if path.is_empty() {
+        ".".into()
+    } else {
+        path.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, rc::Rc};
+
+    use url::Url;
+
+    use crate::{
+        config::{
+            Config,
+            changelog::ChangelogConfig,
+            defaults::DefaultsConfig,
+            overrides::{CommitModifiers, GlobalOverrides, PackageOverrides},
+            package::AdditionalManifestSpec,
+        },
+        forge::{manager::ForgeOptions, traits::MockForge},
+    };
+
+    use super::*;
+
+    fn resolver(config: Config, tag_prefix_override: Option<&str>) -> Resolver {
+        let package_overrides = tag_prefix_override
+            .map(|prefix| {
+                HashMap::from([(
+                    "api".to_string(),
+                    PackageOverrides {
+                        tag_prefix: Some(prefix.into()),
+                        prerelease_suffix: None,
+                        prerelease_strategy: None,
+                        version_type: None,
+                        release_as: None,
+                    },
+                )])
+            })
+            .unwrap_or_default();
+
+        Resolver::builder()
+            .toml_config(Rc::new(config))
+            .repo_name("test-repo")
+            .repo_default_branch("main")
+            .release_link_base_url(Url::parse("https://example.com/").unwrap())
+            .compare_link_base_url(
+                Url::parse("https://example.com/compare/").unwrap(),
+            )
+            .package_overrides(package_overrides)
+            .global_overrides(GlobalOverrides::default())
+            .commit_modifiers(CommitModifiers::default())
+            .build()
+            .unwrap()
+    }
+
+    fn config() -> Config {
+        Config {
+            defaults: DefaultsConfig {
+                changelog: Some(ChangelogConfig {
+                    include_author: Some(true),
+                    ..ChangelogConfig::default()
+                }),
+                ..DefaultsConfig::default()
+            },
+            packages: vec![PackageConfig {
+                path: "services/api".into(),
+                release_type: Some(ReleaseType::Node),
+                changelog: Some(ChangelogConfig {
+                    include_pr_link: Some(true),
+                    ..ChangelogConfig::default()
+                }),
+                ..PackageConfig::default()
+            }],
+            ..Config::default()
+        }
+    }
+
+    fn source_of(explanation: &PackageExplanation, key: &str) -> SettingSource {
+        explanation
+            .settings
+            .iter()
+            .find(|s| s.key == key)
+            .unwrap_or_else(|| panic!("missing setting {key}"))
+            .source
+    }
+
+    #[test]
+    fn explain_reports_the_tier_of_each_setting() {
+        let resolver = resolver(config(), None);
+        let packages = resolver.toml_config.packages.clone();
+        let resolved = resolver.resolve(packages.clone()).unwrap();
+
+        let explanations = resolver.explain(&packages, &resolved).unwrap();
+        let api = &explanations[0];
+
+        assert_eq!(api.name, "api");
+        assert_eq!(source_of(api, "name"), SettingSource::Derived);
+        assert_eq!(source_of(api, "release_type"), SettingSource::Package);
+        assert_eq!(source_of(api, "tag_prefix"), SettingSource::Derived);
+        assert_eq!(
+            source_of(api, "changelog.include_pr_link"),
+            SettingSource::Package
+        );
+        assert_eq!(
+            source_of(api, "changelog.include_author"),
+            SettingSource::Defaults
+        );
+        assert_eq!(
+            source_of(api, "versioning.version_type"),
+            SettingSource::BuiltIn
+        );
+    }
+
+    #[test]
+    fn explain_attributes_cli_overrides() {
+        let resolver = resolver(config(), Some("cli-v"));
+        let packages = resolver.toml_config.packages.clone();
+        let resolved = resolver.resolve(packages.clone()).unwrap();
+
+        let explanations = resolver.explain(&packages, &resolved).unwrap();
+        let tag_prefix = explanations[0]
+            .settings
+            .iter()
+            .find(|s| s.key == "tag_prefix")
+            .unwrap();
+
+        assert_eq!(tag_prefix.value, json!("cli-v"));
+        assert_eq!(tag_prefix.source, SettingSource::CliPackage);
+    }
+
+    #[tokio::test]
+    async fn check_reports_missing_paths_and_manifests() {
+        let mut config = config();
+        config.packages[0].additional_paths = Some(vec!["shared".into()]);
+
+        let mut mock = MockForge::new();
+        mock.expect_list_files().returning(|_| {
+            Ok(vec!["services/api/index.js".into(), "README.md".into()])
+        });
+
+        let forge =
+            ForgeManager::new(Box::new(mock), ForgeOptions { dry_run: false });
+
+        let explanations = resolver(config, None).check(&forge).await.unwrap();
+
+        assert_eq!(
+            explanations[0].problems,
+            vec![
+                "api: additional path shared does not exist",
+                "api: no node manifest found in services/api",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn check_resolves_additional_manifests_of_nested_packages() {
+        let mut config = config();
+        config.packages[0].path = "crates/foo".into();
+        config.packages[0].additional_manifest_files = Some(vec![
+            AdditionalManifestSpec::Path("VERSION".into()),
+            AdditionalManifestSpec::Path("docs/missing.md".into()),
+        ]);
+
+        let mut mock = MockForge::new();
+        mock.expect_list_files().returning(|_| {
+            Ok(vec![
+                "crates/foo/package.json".into(),
+                "crates/foo/VERSION".into(),
+            ])
+        });
+
+        let forge =
+            ForgeManager::new(Box::new(mock), ForgeOptions { dry_run: false });
+
+        let explanations = resolver(config, None).check(&forge).await.unwrap();
+
+        assert_eq!(
+            explanations[0].problems,
+            vec![
+                "foo: additional manifest crates/foo/docs/missing.md does not exist"
+            ]
+        );
+    }
+}
@@ -9,6 +9,9 @@ pub mod analyzer;
 pub mod base_branch;
 pub mod changelog;
 pub mod commit_modifiers;
+pub mod discovery;
+pub mod includes;
+pub mod interpolation;
 pub mod manifest;
 pub mod package;
 pub mod package_name;
@@ -17,6 +20,7 @@ pub mod prerelease;
 pub mod sub_packages;
 pub mod tag_prefix;
 pub mod templates;
+pub mod version_groups;
 pub mod versioning;
 
 #[cfg(test)]
@@ -7,11 +7,15 @@
 use url::Url;
 
 use crate::{
-    analyzer::config::AnalyzerConfig,
+    analyzer::{
+        config::{AnalyzerConfig, ReferenceMatcher},
+        mailmap::Mailmap,
+    },
     config::{
         changelog::{
             ChangelogConfig, DEFAULT_BODY, DEFAULT_INCLUDE_AUTHOR,
-            DEFAULT_INCLUDE_PR_LINK,
+            DEFAULT_INCLUDE_CONTRIBUTORS, DEFAULT_INCLUDE_PR_LINK,
+            DEFAULT_STRIP_TRAILERS,
         },
         overrides::CommitModifiers,
         versioning::{
@@ -33,6 +37,8 @@ pub struct AnalyzerParams {
     pub release_link_base_url: Option<Url>,
     pub compare_link_base_url: Option<Url>,
     pub commit_modifiers: CommitModifiers,
+    pub references: Vec<ReferenceMatcher>,
+    pub mailmap: Mailmap,
 }
 
 /// Builds an AnalyzerConfig from resolved parameters.
@@ -67,6 +73,14 @@ pub fn build_analyzer_config(params: AnalyzerParams) -> AnalyzerConfig {
             .changelog
             .include_pr_link
             .unwrap_or(DEFAULT_INCLUDE_PR_LINK),
+        include_contributors: params
+            .changelog
+            .include_contributors
+            .unwrap_or(DEFAULT_INCLUDE_CONTRIBUTORS),
+        strip_trailers: params
+            .changelog
+            .strip_trailers
+            .unwrap_or(DEFAULT_STRIP_TRAILERS),
         prerelease: params.versioning.prerelease,
         release_link_base_url: params.release_link_base_url,
         compare_link_base_url: params.compare_link_base_url,
@@ -84,5 +98,7 @@ pub fn build_analyzer_config(params: AnalyzerParams) -> AnalyzerConfig {
             .named_parsers
             .unwrap_or_else(|| NAMED_PARSERS.clone()),
         custom_parsers: params.versioning.custom_parsers.unwrap_or_default().0,
+        references: params.references,
+        mailmap: params.mailmap,
     }
 }
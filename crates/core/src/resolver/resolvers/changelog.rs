@@ -1,6 +1,26 @@
-use crate::config::{
-    changelog::{ChangelogConfig, DEFAULT_BODY},
-    package::PackageConfig,
+use regex::Regex;
+use std::path::Path;
+use url::Url;
+
+use crate::{
+    analyzer::config::ReferenceMatcher,
+    config::{
+        changelog::{
+            AggregateChangelogConfig, ChangelogConfig, ChangelogFormat,
+            DEFAULT_AGGREGATE_BODY, DEFAULT_BODY, DEFAULT_CHANGELOG_ENABLED,
+            DEFAULT_CHANGELOG_PATH, DEFAULT_DEBIAN_DISTRIBUTION,
+            DEFAULT_DEBIAN_URGENCY, DEFAULT_INSERT_MARKER,
+            DEFAULT_ISSUE_REFERENCE_PATTERN, DEFAULT_PACKAGE_REVISION,
+            DEFAULT_RPM_INSERT_AFTER,
+        },
+        package::PackageConfig,
+    },
+    packages::{
+        resolved::{ChangelogFile, ResolvedPackaging},
+        resolved_hash::ResolvedPackageHash,
+    },
+    resolver::resolvers::path_utils::normalize_path,
+    result::{ReleasaurusError, Result},
 };
 
 pub fn resolve_changelog_config(
@@ -22,10 +42,234 @@ pub fn resolve_changelog_config(
     changelog_config
 }
 
+/// Resolves the files a package's changelog is written to: each `output`
+/// entry if any are set, otherwise the single file at `path`. Paths are
+/// relative to the package path. Files without a template of their own get
+/// their format's built-in one, except Keep a Changelog files when `body`
+/// was customized.
+pub fn resolve_changelog_files(
+    package_name: &str,
+    normalized_full_path: &Path,
+    changelog_config: &ChangelogConfig,
+) -> Result<Vec<ChangelogFile>> {
+    if !changelog_config
+        .enabled
+        .unwrap_or(DEFAULT_CHANGELOG_ENABLED)
+    {
+        return Ok(vec![]);
+    }
+
+    let custom_body = changelog_config
+        .body
+        .as_deref()
+        .is_some_and(|body| body != DEFAULT_BODY);
+
+    let file = |path: &str,
+                body: Option<String>,
+                insert_after: Option<&str>,
+                format: Option<ChangelogFormat>| {
+        let format = format.or(changelog_config.format).unwrap_or_default();
+
+        let body = match format {
+            ChangelogFormat::KeepAChangelog if custom_body => body,
+            _ => body.or_else(|| format.default_body().map(String::from)),
+        };
+
+        // a spec file's entries belong in its changelog section whatever
+        // the other files use
+        let insert_after = match format {
+            ChangelogFormat::Rpm => {
+                insert_after.or(Some(DEFAULT_RPM_INSERT_AFTER))
+            }
+            _ => insert_after.or(changelog_config.insert_after.as_deref()),
+        };
+
+        let mut file =
+            changelog_file(normalized_full_path, path, body, insert_after)?;
+
+        file.format = format;
+
+        if format.is_packaging() {
+            file.packaging =
+                Some(resolve_packaging(package_name, path, changelog_config)?);
+        }
+
+        Ok(file)
+    };
+
+    match changelog_config.outputs.as_ref() {
+        Some(outputs) => outputs
+            .iter()
+            .map(|output| {
+                file(
+                    &output.path,
+                    output.body.clone(),
+                    output.insert_after.as_deref(),
+                    output.format,
+                )
+            })
+            .collect(),
+        None => Ok(vec![file(
+            changelog_config
+                .path
+                .as_deref()
+                .unwrap_or(DEFAULT_CHANGELOG_PATH),
+            None,
+            None,
+            None,
+        )?]),
+    }
+}
+
+/// Resolves the `packaging` settings of a `debian` or `rpm` file, which
+/// needs at least a maintainer.
+fn resolve_packaging(
+    package_name: &str,
+    path: &str,
+    changelog_config: &ChangelogConfig,
+) -> Result<ResolvedPackaging> {
+    let config = changelog_config.packaging.clone().unwrap_or_default();
+
+    let Some(maintainer) = config.maintainer else {
+        return Err(ReleasaurusError::invalid_config(format!(
+            "changelog {path}: packaging.maintainer is required for debian \
+             and rpm changelogs"
+        )));
+    };
+
+    Ok(ResolvedPackaging {
+        package: config.package.unwrap_or_else(|| package_name.into()),
+        maintainer,
+        distribution: config
+            .distribution
+            .unwrap_or_else(|| DEFAULT_DEBIAN_DISTRIBUTION.into()),
+        urgency: config
+            .urgency
+            .unwrap_or_else(|| DEFAULT_DEBIAN_URGENCY.into()),
+        revision: config
+            .revision
+            .unwrap_or_else(|| DEFAULT_PACKAGE_REVISION.into()),
+    })
+}
+
+/// Compiles the changelog's `references` patterns. Without any configured,
+/// `#123` references link to the forge's issues when its issue URL is
+/// known.
+pub fn resolve_references(
+    changelog_config: &ChangelogConfig,
+    issue_link_base_url: Option<&Url>,
+) -> Result<Vec<ReferenceMatcher>> {
+    let Some(references) = changelog_config.references.as_ref() else {
+        return Ok(issue_link_base_url
+            .map(|base| ReferenceMatcher {
+                pattern: Regex::new(DEFAULT_ISSUE_REFERENCE_PATTERN).unwrap(),
+                url: format!("{base}${{1}}"),
+            })
+            .into_iter()
+            .collect());
+    };
+
+    references
+        .iter()
+        .map(|reference| {
+            let pattern = Regex::new(&reference.pattern).map_err(|e| {
+                ReleasaurusError::invalid_config(format!(
+                    "invalid changelog reference pattern \"{}\": {e}",
+                    reference.pattern
+                ))
+            })?;
+
+            Ok(ReferenceMatcher {
+                pattern,
+                url: reference.url.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Resolves `repository.aggregate_changelog`. The root file would be
+/// rewritten by every release PR, so it's only supported with a single
+/// combined PR, and it can't share a path with a package's changelog.
+pub fn resolve_aggregate_changelog(
+    config: Option<&AggregateChangelogConfig>,
+    separate_pull_requests: bool,
+    packages: &ResolvedPackageHash,
+) -> Result<Option<ChangelogFile>> {
+    let Some(config) = config else {
+        return Ok(None);
+    };
+
+    if separate_pull_requests {
+        return Err(ReleasaurusError::invalid_config(
+            "aggregate_changelog requires separate_pull_requests to be off",
+        ));
+    }
+
+    let file = changelog_file(
+        Path::new(""),
+        config.path.as_deref().unwrap_or(DEFAULT_CHANGELOG_PATH),
+        Some(
+            config
+                .body
+                .clone()
+                .unwrap_or_else(|| DEFAULT_AGGREGATE_BODY.into()),
+        ),
+        config.insert_after.as_deref(),
+    )?;
+
+    if let Some(package) = packages
+        .hash()
+        .values()
+        .find(|p| p.changelog_files.iter().any(|f| f.path == file.path))
+    {
+        return Err(ReleasaurusError::invalid_config(format!(
+            "aggregate_changelog path {} is already the changelog of \
+             package {}",
+            file.path.display(),
+            package.name
+        )));
+    }
+
+    Ok(Some(file))
+}
+
+/// A changelog file at `path` below `dir`, inserting below `insert_after`
+/// or, by default, the insert marker comment.
+fn changelog_file(
+    dir: &Path,
+    path: &str,
+    body: Option<String>,
+    insert_after: Option<&str>,
+) -> Result<ChangelogFile> {
+    let insert_after = insert_after
+        .map(String::from)
+        .unwrap_or_else(|| regex::escape(DEFAULT_INSERT_MARKER));
+
+    Regex::new(&insert_after).map_err(|e| {
+        ReleasaurusError::invalid_config(format!(
+            "changelog {path}: invalid insert_after regex: {e}"
+        ))
+    })?;
+
+    Ok(ChangelogFile {
+        path: dir.join(normalize_path(path).as_ref()),
+        body,
+        insert_after,
+        format: ChangelogFormat::default(),
+        packaging: None,
+    })
+}
+
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
     use crate::{
-        config::changelog::DEFAULT_BODY,
+        config::changelog::{
+            ChangelogOutputBuilder, DEFAULT_BODY, DEFAULT_DEBIAN_BODY,
+            DEFAULT_KEEP_A_CHANGELOG_BODY, PackagingConfigBuilder,
+            ReferencePatternBuilder,
+        },
         resolver::resolvers::test_helper::create_test_package,
     };
 
@@ -139,4 +383,287 @@ mod tests {
             "an opt-out on one field must not block inheriting others"
         );
     }
+
+    #[test]
+    fn resolve_changelog_files_relative_to_package_path() {
+        let package_path = Path::new("packages/api");
+        let paths = |config: &ChangelogConfig| {
+            resolve_changelog_files("api", package_path, config)
+                .unwrap()
+                .into_iter()
+                .map(|f| (f.path, f.body))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            paths(&ChangelogConfig::default()),
+            [(PathBuf::from("packages/api/CHANGELOG.md"), None)]
+        );
+
+        assert_eq!(
+            paths(&ChangelogConfig {
+                path: Some("./docs/CHANGES.md".into()),
+                ..ChangelogConfig::default()
+            }),
+            [(PathBuf::from("packages/api/docs/CHANGES.md"), None)]
+        );
+
+        assert!(
+            paths(&ChangelogConfig {
+                enabled: Some(false),
+                ..ChangelogConfig::default()
+            })
+            .is_empty()
+        );
+
+        // outputs replace the single file at `path`
+        assert_eq!(
+            paths(&ChangelogConfig {
+                path: Some("CHANGES.md".into()),
+                outputs: Some(vec![
+                    ChangelogOutputBuilder::default()
+                        .path("CHANGELOG.md")
+                        .build()
+                        .unwrap(),
+                    ChangelogOutputBuilder::default()
+                        .path("HISTORY.rst")
+                        .body("{{ version }}")
+                        .build()
+                        .unwrap(),
+                ]),
+                ..ChangelogConfig::default()
+            }),
+            [
+                (PathBuf::from("packages/api/CHANGELOG.md"), None),
+                (
+                    PathBuf::from("packages/api/HISTORY.rst"),
+                    Some("{{ version }}".into())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_changelog_files_keep_a_changelog_body() {
+        let files = |config: &ChangelogConfig| {
+            resolve_changelog_files("test", Path::new("."), config)
+                .unwrap()
+                .into_iter()
+                .map(|f| (f.format, f.body))
+                .collect::<Vec<_>>()
+        };
+
+        let keep_a_changelog = Some(DEFAULT_KEEP_A_CHANGELOG_BODY.into());
+
+        // resolved configs always carry a body, the stock one by default
+        assert_eq!(
+            files(&ChangelogConfig {
+                body: Some(DEFAULT_BODY.into()),
+                format: Some(ChangelogFormat::KeepAChangelog),
+                ..ChangelogConfig::default()
+            }),
+            [(ChangelogFormat::KeepAChangelog, keep_a_changelog.clone())]
+        );
+
+        // a customized body is kept for the release notes and the file
+        assert_eq!(
+            files(&ChangelogConfig {
+                body: Some("{{ version }}".into()),
+                format: Some(ChangelogFormat::KeepAChangelog),
+                ..ChangelogConfig::default()
+            }),
+            [(ChangelogFormat::KeepAChangelog, None)]
+        );
+
+        // outputs pick their own format
+        assert_eq!(
+            files(&ChangelogConfig {
+                body: Some(DEFAULT_BODY.into()),
+                outputs: Some(vec![
+                    ChangelogOutputBuilder::default()
+                        .path("CHANGELOG.md")
+                        .format(ChangelogFormat::KeepAChangelog)
+                        .build()
+                        .unwrap(),
+                    ChangelogOutputBuilder::default()
+                        .path("NOTES.md")
+                        .build()
+                        .unwrap(),
+                ]),
+                ..ChangelogConfig::default()
+            }),
+            [
+                (ChangelogFormat::KeepAChangelog, keep_a_changelog),
+                (ChangelogFormat::Markdown, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_changelog_files_packaging_formats() {
+        let outputs = Some(vec![
+            ChangelogOutputBuilder::default()
+                .path("debian/changelog")
+                .format(ChangelogFormat::Debian)
+                .build()
+                .unwrap(),
+            ChangelogOutputBuilder::default()
+                .path("my-service.spec")
+                .format(ChangelogFormat::Rpm)
+                .build()
+                .unwrap(),
+        ]);
+
+        let err = resolve_changelog_files(
+            "my-service",
+            Path::new("."),
+            &ChangelogConfig {
+                outputs: outputs.clone(),
+                ..ChangelogConfig::default()
+            },
+        )
+        .unwrap_err();
+
+        assert!(
+            err.to_string().contains("packaging.maintainer is required"),
+            "unexpected error: {err}"
+        );
+
+        let files = resolve_changelog_files(
+            "my-service",
+            Path::new("."),
+            &ChangelogConfig {
+                // custom bodies are release notes, not packaging entries
+                body: Some("{{ version }}".into()),
+                insert_after: Some("^# Changelog$".into()),
+                outputs,
+                packaging: Some(
+                    PackagingConfigBuilder::default()
+                        .maintainer("Jane Doe <jane@example.com>")
+                        .urgency("low")
+                        .build()
+                        .unwrap(),
+                ),
+                ..ChangelogConfig::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(files[0].body.as_deref(), Some(DEFAULT_DEBIAN_BODY));
+        assert_eq!(files[0].insert_after, "^# Changelog$");
+        assert_eq!(files[1].insert_after, DEFAULT_RPM_INSERT_AFTER);
+
+        let packaging = files[0].packaging.as_ref().unwrap();
+        assert_eq!(
+            (
+                packaging.package.as_str(),
+                packaging.distribution.as_str(),
+                packaging.urgency.as_str(),
+                packaging.revision.as_str(),
+            ),
+            ("my-service", "unstable", "low", "1")
+        );
+    }
+
+    #[test]
+    fn resolve_changelog_files_validates_insert_after() {
+        let files = resolve_changelog_files(
+            "test",
+            Path::new("."),
+            &ChangelogConfig::default(),
+        )
+        .unwrap();
+
+        assert_eq!(files[0].insert_after, regex::escape(DEFAULT_INSERT_MARKER));
+
+        let err = resolve_changelog_files(
+            "test",
+            Path::new("."),
+            &ChangelogConfig {
+                insert_after: Some("^# (Changelog$".into()),
+                ..ChangelogConfig::default()
+            },
+        )
+        .unwrap_err();
+
+        assert!(
+            err.to_string().contains("invalid insert_after regex"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn resolve_references_defaults_to_forge_issues() {
+        let base = Url::parse("https://github.com/owner/repo/issues/").unwrap();
+
+        let references =
+            resolve_references(&ChangelogConfig::default(), Some(&base))
+                .unwrap();
+
+        assert_eq!(references.len(), 1);
+        assert_eq!(
+            references[0].pattern.as_str(),
+            DEFAULT_ISSUE_REFERENCE_PATTERN
+        );
+        assert_eq!(
+            references[0].url,
+            "https://github.com/owner/repo/issues/${1}"
+        );
+
+        // no forge issue URL, nothing to link to
+        assert!(
+            resolve_references(&ChangelogConfig::default(), None)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn resolve_references_configured_patterns_replace_default() {
+        let base = Url::parse("https://github.com/owner/repo/issues/").unwrap();
+
+        let config = ChangelogConfig {
+            references: Some(vec![
+                ReferencePatternBuilder::default()
+                    .pattern(r"PLAT-\d+")
+                    .url("https://jira.example.com/browse/$0")
+                    .build()
+                    .unwrap(),
+            ]),
+            ..ChangelogConfig::default()
+        };
+
+        let references = resolve_references(&config, Some(&base)).unwrap();
+
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].pattern.as_str(), r"PLAT-\d+");
+        assert_eq!(references[0].url, "https://jira.example.com/browse/$0");
+
+        // an empty list turns linking off
+        let config = ChangelogConfig {
+            references: Some(vec![]),
+            ..ChangelogConfig::default()
+        };
+
+        assert!(resolve_references(&config, Some(&base)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn resolve_references_rejects_invalid_pattern() {
+        let config = ChangelogConfig {
+            references: Some(vec![
+                ReferencePatternBuilder::default()
+                    .pattern("PLAT-(")
+                    .url("https://jira.example.com/browse/$0")
+                    .build()
+                    .unwrap(),
+            ]),
+            ..ChangelogConfig::default()
+        };
+
+        let err = resolve_references(&config, None)
+            .expect_err("an invalid pattern must be rejected");
+
+        assert!(matches!(err, ReleasaurusError::InvalidConfig(_)));
+    }
 }
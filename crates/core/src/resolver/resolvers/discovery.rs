@@ -0,0 +1,394 @@
+//! Expansion of glob package entries into concrete packages.
+//!
+//! A `[[package]]` entry whose `path` contains a glob, or which sets
+//! `packages` to a glob of manifest files, stands for every matching
+//! package directory in the repository. Each match becomes its own
+//! [`PackageConfig`], named after the canonical name in its manifest.
+
+use globset::{GlobBuilder, GlobMatcher};
+use std::collections::BTreeMap;
+
+use crate::{
+    config::{package::PackageConfig, release_type::ReleaseType},
+    forge::{manager::ForgeManager, traits::FileLoader},
+    packages::manifests::ManifestFile,
+    resolver::resolvers::{
+        path_utils::{GLOB_CHARS, normalize_path},
+        templates::flatten,
+    },
+    result::{ReleasaurusError, Result},
+    updater::manager::UpdateManager,
+};
+
+/// Whether `package` must be expanded by [`discover_packages`] before it
+/// can be resolved.
+pub fn is_package_glob(package: &PackageConfig) -> bool {
+    package.packages.is_some() || package.path.contains(GLOB_CHARS)
+}
+
+/// Replaces every glob entry in `packages` with one entry per matching
+/// package directory found on `branch`. Entries without globs are
+/// returned unchanged, and the repository is only listed when at least
+/// one glob entry exists.
+pub async fn discover_packages(
+    packages: Vec<PackageConfig>,
+    forge: &ForgeManager,
+    branch: &str,
+) -> Result<Vec<PackageConfig>> {
+    if !packages.iter().any(is_package_glob) {
+        return Ok(packages);
+    }
+
+    let files = forge.list_files(Some(branch.into())).await?;
+    let mut discovered = vec![];
+
+    for package in packages {
+        if !is_package_glob(&package) {
+            discovered.push(package);
+            continue;
+        }
+
+        let expanded = expand_package(&package, &files, forge, branch).await?;
+
+        if expanded.is_empty() {
+            log::warn!(
+                "package glob {} matched no packages",
+                package.packages.as_deref().unwrap_or(&package.path)
+            );
+        }
+
+        discovered.extend(expanded);
+    }
+
+    Ok(discovered)
+}
+
+async fn expand_package(
+    package: &PackageConfig,
+    files: &[String],
+    forge: &ForgeManager,
+    branch: &str,
+) -> Result<Vec<PackageConfig>> {
+    if !package.name.is_empty() {
+        return Err(ReleasaurusError::invalid_config(format!(
+            "package {}: name cannot be set on a glob package entry, names \
+             are read from each matched manifest",
+            package.name
+        )));
+    }
+
+    let root = normalize_path(&package.workspace_root);
+    let root = root.trim_matches('/');
+    let relative = |file: &str| -> Option<String> {
+        if root.is_empty() || root == "." {
+            Some(file.to_string())
+        } else {
+            file.strip_prefix(root)?.strip_prefix('/').map(String::from)
+        }
+    };
+
+    // package directory (relative to workspace_root) -> manifest paths
+    // (relative to the repository root) in preference order
+    let mut matches: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    if let Some(pattern) = package.packages.as_ref() {
+        let matcher = glob_matcher(pattern)?;
+
+        for file in files {
+            if let Some(rel) = relative(file)
+                && matcher.is_match(&rel)
+            {
+                matches
+                    .entry(parent_dir(&rel))
+                    .or_default()
+                    .push(file.clone());
+            }
+        }
+    } else {
+        let matcher = glob_matcher(&package.path)?;
+        let markers = manifest_markers(package.release_type);
+
+        for marker in markers.iter() {
+            for file in files {
+                if let Some(rel) = relative(file)
+                    && is_marker(marker, &rel)
+                    && matcher.is_match(parent_dir(&rel))
+                {
+                    matches
+                        .entry(parent_dir(&rel))
+                        .or_default()
+                        .push(file.clone());
+                }
+            }
+        }
+    }
+
+    let mut expanded = vec![];
+
+    for (path, manifests) in matches {
+        let name = manifest_name(&manifests, forge, branch)
+            .await?
+            .unwrap_or_else(|| {
+                path.rsplit('/').next().unwrap_or(&path).to_string()
+            });
+
+        let tag_prefix = package
+            .tag_prefix
+            .as_deref()
+            .map(|template| render_tag_prefix(template, &name))
+            .transpose()?;
+
+        log::debug!("discovered package {name} at {path}");
+
+        expanded.push(PackageConfig {
+            name,
+            path,
+            tag_prefix,
+            packages: None,
+            ..package.clone()
+        });
+    }
+
+    Ok(expanded)
+}
+
+/// Manifest file names that identify a package directory for a `path`
+/// glob: the markers of the configured release type, or of every release
+/// type when none (or `generic`/`auto`) is configured.
+fn manifest_markers(release_type: Option<ReleaseType>) -> Vec<String> {
+    UpdateManager::release_type_markers("*")
+        .into_iter()
+        .filter(|(r, _)| match release_type {
+            None | Some(ReleaseType::Generic) | Some(ReleaseType::Auto) => true,
+            Some(configured) => *r == configured,
+        })
+        .flat_map(|(_, files)| files)
+        .collect()
+}
+
+fn is_marker(marker: &str, file: &str) -> bool {
+    let basename = file.rsplit('/').next().unwrap_or(file);
+
+    match marker.strip_prefix('*') {
+        Some(suffix) => basename.ends_with(suffix),
+        None => basename == marker,
+    }
+}
+
+async fn manifest_name(
+    manifests: &[String],
+    forge: &ForgeManager,
+    branch: &str,
+) -> Result<Option<String>> {
+    for path in manifests {
+        let Some(content) =
+            forge.load_file(Some(branch.into()), path.clone()).await?
+        else {
+            continue;
+        };
+
+        let manifest = ManifestFile {
+            basename: path.rsplit('/').next().unwrap_or(path).to_string(),
+            path: path.into(),
+            content,
+        };
+
+        if let Some(name) = manifest.canonical_name() {
+            return Ok(Some(name));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Renders a `tag_prefix` template such as `{{ name }}-v` for a
+/// discovered package.
+fn render_tag_prefix(template: &str, name: &str) -> Result<String> {
+    let mut context = tera::Context::new();
+    context.insert("name", name);
+
+    tera::Tera::one_off(template, &context, false).map_err(|e| {
+        ReleasaurusError::invalid_config(format!(
+            "package {name}: tag_prefix: {}",
+            flatten(&e)
+        ))
+    })
+}
+
+pub(super) fn glob_matcher(pattern: &str) -> Result<GlobMatcher> {
+    let pattern = normalize_path(pattern);
+    let pattern = pattern.trim_end_matches('/');
+
+    GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()
+        .map(|glob| glob.compile_matcher())
+        .map_err(|e| {
+            ReleasaurusError::invalid_config(format!(
+                "invalid package glob {pattern}: {e}"
+            ))
+        })
+}
+
+fn parent_dir(file: &str) -> String {
+    file.rsplit_once('/')
+        .map(|(dir, _)| dir.to_string())
+        .unwrap_or_else(|| ".".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        forge::{manager::ForgeOptions, traits::MockForge},
+        resolver::resolvers::test_helper::create_test_package,
+    };
+
+    use super::*;
+
+    fn forge_manager(
+        files: HashMap<&'static str, &'static str>,
+    ) -> ForgeManager {
+        let mut mock = MockForge::new();
+        let paths = files.keys().map(|k| k.to_string()).collect::<Vec<_>>();
+
+        mock.expect_list_files()
+            .times(1)
+            .returning(move |_| Ok(paths.clone()));
+        mock.expect_get_file_content().returning(move |req| {
+            Ok(files.get(req.path.as_str()).map(|c| c.to_string()))
+        });
+
+        ForgeManager::new(Box::new(mock), ForgeOptions { dry_run: false })
+    }
+
+    fn glob_package(path: &str) -> PackageConfig {
+        PackageConfig {
+            path: path.into(),
+            ..create_test_package("")
+        }
+    }
+
+    #[tokio::test]
+    async fn leaves_plain_packages_alone_without_listing_files() {
+        let forge = ForgeManager::new(
+            Box::new(MockForge::new()),
+            ForgeOptions { dry_run: false },
+        );
+
+        let packages = discover_packages(
+            vec![create_test_package("core")],
+            &forge,
+            "main",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages[0].name, "core");
+    }
+
+    #[tokio::test]
+    async fn expands_path_glob_into_manifest_named_packages() {
+        let forge = forge_manager(HashMap::from([
+            ("crates/core/Cargo.toml", "[package]\nname = \"my-core\"\n"),
+            ("crates/cli/Cargo.toml", "[package]\nname = \"my-cli\"\n"),
+            (
+                "crates/cli/src/nested/Cargo.toml",
+                "[package]\nname = \"x\"\n",
+            ),
+            ("crates/README.md", ""),
+        ]));
+
+        let mut package = glob_package("crates/*");
+        package.release_type = Some(ReleaseType::Rust);
+        package.tag_prefix = Some("{{ name }}-v".into());
+
+        let packages = discover_packages(vec![package], &forge, "main")
+            .await
+            .unwrap();
+
+        let found = packages
+            .iter()
+            .map(|p| {
+                (
+                    p.name.as_str(),
+                    p.path.as_str(),
+                    p.tag_prefix.as_deref().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            found,
+            vec![
+                ("my-cli", "crates/cli", "my-cli-v"),
+                ("my-core", "crates/core", "my-core-v"),
+            ]
+        );
+        assert!(packages.iter().all(|p| p.packages.is_none()));
+    }
+
+    #[tokio::test]
+    async fn expands_manifest_glob_relative_to_workspace_root() {
+        let forge = forge_manager(HashMap::from([
+            ("js/packages/ui/package.json", r#"{"name": "@acme/ui"}"#),
+            ("js/packages/deep/api/package.json", r#"{"name": "api"}"#),
+            ("packages/other/package.json", r#"{"name": "other"}"#),
+        ]));
+
+        let package = PackageConfig {
+            workspace_root: "js".into(),
+            packages: Some("packages/**/package.json".into()),
+            release_type: Some(ReleaseType::Node),
+            ..create_test_package("")
+        };
+
+        let packages = discover_packages(vec![package], &forge, "main")
+            .await
+            .unwrap();
+
+        let found = packages
+            .iter()
+            .map(|p| (p.name.as_str(), p.path.as_str()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            found,
+            vec![("api", "packages/deep/api"), ("@acme/ui", "packages/ui")]
+        );
+        assert!(packages.iter().all(|p| p.workspace_root == "js"));
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_directory_name_without_manifest_name() {
+        let forge = forge_manager(HashMap::from([(
+            "services/api/go.mod",
+            "go 1.22\n",
+        )]));
+
+        let packages =
+            discover_packages(vec![glob_package("services/*")], &forge, "main")
+                .await
+                .unwrap();
+
+        assert_eq!(packages[0].name, "api");
+        assert_eq!(packages[0].path, "services/api");
+    }
+
+    #[tokio::test]
+    async fn rejects_a_name_on_a_glob_entry() {
+        let forge = forge_manager(HashMap::new());
+        let package = PackageConfig {
+            name: "fixed".into(),
+            ..glob_package("crates/*")
+        };
+
+        let err = discover_packages(vec![package], &forge, "main")
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("name cannot be set"));
+    }
+}
@@ -0,0 +1,425 @@
+//! Loading of package fragments pulled in by the root `include` globs.
+//!
+//! Teams can keep a package's release settings next to its code in a
+//! fragment such as `packages/foo/releasaurus.package.toml`. Each fragment
+//! holds the keys of a single `[[package]]` entry, with paths relative to
+//! the directory the fragment lives in.
+
+use std::{collections::HashMap, path::Path};
+
+use crate::{
+    config::package::PackageConfig,
+    forge::{manager::ForgeManager, traits::FileLoader},
+    resolver::resolvers::{
+        discovery::glob_matcher, path_utils::normalize_path,
+    },
+    result::{ReleasaurusError, Result},
+};
+
+/// A package loaded from a fragment file.
+#[derive(Debug, Clone)]
+pub struct IncludedPackage {
+    /// Repository path of the fragment the package came from
+    pub fragment: String,
+    /// The fragment's package, with paths relative to the repository root
+    pub package: PackageConfig,
+}
+
+/// Loads every fragment on `branch` matching one of `patterns`, in path
+/// order, rebased so their paths are relative to the repository root.
+pub async fn include_packages(
+    patterns: &[String],
+    forge: &ForgeManager,
+    branch: &str,
+) -> Result<Vec<IncludedPackage>> {
+    if patterns.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let matchers = patterns
+        .iter()
+        .map(|pattern| glob_matcher(pattern))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut fragments = forge
+        .list_files(Some(branch.into()))
+        .await?
+        .into_iter()
+        .filter(|file| matchers.iter().any(|m| m.is_match(file)))
+        .collect::<Vec<_>>();
+
+    fragments.sort();
+
+    if fragments.is_empty() {
+        log::warn!(
+            "include {} matched no package fragments",
+            patterns.join(", ")
+        );
+    }
+
+    let mut packages = vec![];
+
+    for fragment in fragments {
+        let Some(content) = forge
+            .load_file(Some(branch.into()), fragment.clone())
+            .await?
+        else {
+            continue;
+        };
+
+        let package: PackageConfig = toml::from_str(&content).map_err(|e| {
+            ReleasaurusError::invalid_config(format!("{fragment}: {e}"))
+        })?;
+
+        let dir = Path::new(&fragment)
+            .parent()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        log::debug!("including package fragment {fragment}");
+
+        packages.push(IncludedPackage {
+            package: rebase_fragment(package, &dir, &fragment)?,
+            fragment,
+        });
+    }
+
+    Ok(packages)
+}
+
+/// Rejects included packages whose name or tag prefix is already used by a
+/// package in the root config or another fragment. Glob entries must
+/// already be expanded, and `identity` resolves a package's name and tag
+/// prefix, so derived `{name}-v` prefixes collide just like configured ones.
+pub fn check_collisions(
+    configured: &[PackageConfig],
+    included: &[IncludedPackage],
+    identity: impl Fn(&PackageConfig) -> (String, String),
+) -> Result<()> {
+    if included.is_empty() {
+        return Ok(());
+    }
+
+    let mut names: HashMap<String, &str> = HashMap::new();
+    let mut tag_prefixes: HashMap<String, &str> = HashMap::new();
+
+    for package in configured {
+        let (name, prefix) = identity(package);
+        names.entry(name).or_insert("the root config");
+        tag_prefixes.entry(prefix).or_insert("the root config");
+    }
+
+    for include in included {
+        let source = include.fragment.as_str();
+        let (name, prefix) = identity(&include.package);
+
+        if let Some(other) = names.insert(name.clone(), source) {
+            return Err(ReleasaurusError::invalid_config(format!(
+                "{source}: package name '{name}' is already used in {other}"
+            )));
+        }
+
+        if let Some(other) = tag_prefixes.insert(prefix.clone(), source) {
+            return Err(ReleasaurusError::invalid_config(format!(
+                "{source}: tag prefix '{prefix}' is already used in {other}"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Rewrites the paths of a fragment found in `dir` relative to the
+/// repository root. A fragment that sets its own `workspace_root` has that
+/// rebased and keeps `path` relative to it; otherwise the workspace stays at
+/// the repository root and `path` is rebased instead.
+fn rebase_fragment(
+    mut package: PackageConfig,
+    dir: &str,
+    fragment: &str,
+) -> Result<PackageConfig> {
+    let rebase = |path: &str| join_relative(dir, path, fragment);
+
+    if normalize_path(&package.workspace_root).trim_matches('/') != "." {
+        package.workspace_root = rebase(&package.workspace_root)?;
+    } else {
+        package.path = rebase(&package.path)?;
+
+        if let Some(glob) = package.packages.as_ref() {
+            package.packages = Some(rebase(glob)?);
+        }
+
+        for sub in package.sub_packages.iter_mut().flatten() {
+            sub.path = rebase(&sub.path)?;
+        }
+    }
+
+    // globs are rebased too, so `**/*.md` in a fragment only matches
+    // below the fragment's directory
+    for paths in [
+        package.additional_paths.as_mut(),
+        package.exclude_paths.as_mut(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        for path in paths.iter_mut() {
+            *path = rebase(path)?;
+        }
+    }
+
+    Ok(package)
+}
+
+/// Joins `path` onto `dir`, resolving `.` and `..` segments. Paths that
+/// would leave the repository are rejected.
+fn join_relative(dir: &str, path: &str, fragment: &str) -> Result<String> {
+    let mut segments: Vec<&str> = vec![];
+
+    for segment in dir.split('/').chain(path.split(['/', '\\'])) {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                if segments.pop().is_none() {
+                    return Err(ReleasaurusError::invalid_config(format!(
+                        "{fragment}: path {path} is outside the repository"
+                    )));
+                }
+            }
+            segment => segments.push(segment),
+        }
+    }
+
+    if segments.is_empty() {
+        Ok(".".into())
+    } else {
+        Ok(segments.join("/"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        config::overrides::{GlobalOverrides, PackageOverridesHash},
+        forge::{manager::ForgeOptions, traits::MockForge},
+        resolver::resolvers::{
+            package_name::resolve_package_name, tag_prefix::resolve_tag_prefix,
+        },
+    };
+
+    use super::*;
+
+    fn forge_manager(
+        files: HashMap<&'static str, &'static str>,
+    ) -> ForgeManager {
+        let mut mock = MockForge::new();
+        let paths = files.keys().map(|k| k.to_string()).collect::<Vec<_>>();
+
+        mock.expect_list_files()
+            .returning(move |_| Ok(paths.clone()));
+        mock.expect_get_file_content().returning(move |req| {
+            Ok(files.get(req.path.as_str()).map(|c| c.to_string()))
+        });
+
+        ForgeManager::new(Box::new(mock), ForgeOptions { dry_run: false })
+    }
+
+    #[tokio::test]
+    async fn includes_fragments_relative_to_their_directory() {
+        let forge = forge_manager(HashMap::from([
+            (
+                "packages/web/releasaurus.package.toml",
+                "name = \"web\"\nrelease_type = \"node\"\n\
+                 tag_prefix = \"web-v\"\nadditional_paths = [\"../shared\"]\n\
+                 exclude_paths = [\"**/*.md\"]\n",
+            ),
+            (
+                "packages/api/releasaurus.package.toml",
+                "name = \"api\"\npath = \"server\"\n",
+            ),
+            ("packages/api/README.md", ""),
+        ]));
+
+        let packages = include_packages(
+            &["packages/*/releasaurus.package.toml".into()],
+            &forge,
+            "main",
+        )
+        .await
+        .unwrap();
+
+        let packages =
+            packages.into_iter().map(|i| i.package).collect::<Vec<_>>();
+
+        assert_eq!(packages.len(), 2);
+        assert_eq!(packages[0].name, "api");
+        assert_eq!(packages[0].path, "packages/api/server");
+        assert_eq!(packages[1].name, "web");
+        assert_eq!(packages[1].path, "packages/web");
+        assert_eq!(packages[1].workspace_root, ".");
+        assert_eq!(
+            packages[1].additional_paths,
+            Some(vec!["packages/shared".into()])
+        );
+        assert_eq!(
+            packages[1].exclude_paths,
+            Some(vec!["packages/web/**/*.md".into()])
+        );
+    }
+
+    #[tokio::test]
+    async fn rebases_workspace_root_when_fragment_sets_one() {
+        let forge = forge_manager(HashMap::from([(
+            "js/packages/ui/releasaurus.package.toml",
+            "workspace_root = \"../..\"\npath = \"packages/ui\"\n",
+        )]));
+
+        let packages = include_packages(
+            &["**/releasaurus.package.toml".into()],
+            &forge,
+            "main",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            packages[0].fragment,
+            "js/packages/ui/releasaurus.package.toml"
+        );
+        assert_eq!(packages[0].package.workspace_root, "js");
+        assert_eq!(packages[0].package.path, "packages/ui");
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_fragments_and_escaping_paths() {
+        let invalid = forge_manager(HashMap::from([(
+            "a/releasaurus.package.toml",
+            "unknown_key = true\n",
+        )]));
+        let escaping = forge_manager(HashMap::from([(
+            "a/releasaurus.package.toml",
+            "path = \"../../elsewhere\"\n",
+        )]));
+        let patterns = vec!["*/releasaurus.package.toml".to_string()];
+
+        let err = include_packages(&patterns, &invalid, "main")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("a/releasaurus.package.toml"));
+
+        let err = include_packages(&patterns, &escaping, "main")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("outside the repository"));
+    }
+
+    /// Resolves names and tag prefixes the way the resolver does without
+    /// any CLI overrides.
+    fn identity(package: &PackageConfig) -> (String, String) {
+        let name = resolve_package_name(package, "repo");
+        let prefix = resolve_tag_prefix(
+            &name,
+            package,
+            &PackageOverridesHash::new(),
+            &GlobalOverrides::default(),
+        );
+
+        (name, prefix)
+    }
+
+    #[test]
+    fn check_collisions_names_the_conflicting_fragment() {
+        let root = PackageConfig {
+            name: "core".into(),
+            tag_prefix: Some("core-v".into()),
+            ..PackageConfig::default()
+        };
+        let included = |name: &str, prefix: &str| IncludedPackage {
+            fragment: format!("packages/{name}/releasaurus.package.toml"),
+            package: PackageConfig {
+                name: name.into(),
+                tag_prefix: Some(prefix.into()),
+                ..PackageConfig::default()
+            },
+        };
+
+        check_collisions(
+            std::slice::from_ref(&root),
+            &[included("web", "web-v")],
+            identity,
+        )
+        .unwrap();
+
+        let err = check_collisions(
+            std::slice::from_ref(&root),
+            &[included("core", "other-v")],
+            identity,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            ReleasaurusError::invalid_config(
+                "packages/core/releasaurus.package.toml: package name 'core' \
+                 is already used in the root config"
+            )
+            .to_string()
+        );
+
+        let err = check_collisions(
+            &[],
+            &[included("web", "shared-v"), included("api", "shared-v")],
+            identity,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains(
+            "packages/api/releasaurus.package.toml: tag prefix 'shared-v' \
+             is already used in packages/web/releasaurus.package.toml"
+        ));
+    }
+
+    #[test]
+    fn check_collisions_covers_derived_names_and_tag_prefixes() {
+        // the root package's explicit prefix matches the one derived for
+        // the fragment package at packages/web
+        let root = PackageConfig {
+            name: "site".into(),
+            path: "site".into(),
+            tag_prefix: Some("web-v".into()),
+            ..PackageConfig::default()
+        };
+        let web = |fragment: &str| IncludedPackage {
+            fragment: fragment.into(),
+            package: PackageConfig {
+                path: "packages/web".into(),
+                ..PackageConfig::default()
+            },
+        };
+
+        let err = check_collisions(
+            std::slice::from_ref(&root),
+            &[web("packages/web/releasaurus.package.toml")],
+            identity,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains(
+            "packages/web/releasaurus.package.toml: tag prefix 'web-v' is \
+             already used in the root config"
+        ));
+
+        let err = check_collisions(
+            &[],
+            &[
+                web("a/releasaurus.package.toml"),
+                web("b/releasaurus.package.toml"),
+            ],
+            identity,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains(
+            "b/releasaurus.package.toml: package name 'web' is already used \
+             in a/releasaurus.package.toml"
+        ));
+    }
+}
@@ -0,0 +1,251 @@
+//! `${VAR}` and `${VAR:-default}` interpolation of config string values.
+//!
+//! Every string in the config can reference environment variables, but only
+//! those named in `repository.env_allowlist` — anything else is an error, so
+//! a secret can't be rendered into a changelog or PR by accident. `$${`
+//! produces a literal `${`.
+
+use regex::{Captures, Regex};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use std::sync::LazyLock;
+
+use crate::result::{ReleasaurusError, Result};
+
+static VARIABLE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\$\$\{|\$\{([A-Za-z_][A-Za-z0-9_]*)(?::-([^}]*))?\}").unwrap()
+});
+
+/// Source of variable values, normally [`std::env::var`].
+pub type EnvLookup<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+/// Looks variables up in the process environment.
+pub fn process_env(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
+/// Interpolates every string inside `value`, which is round-tripped
+/// through JSON only when it contains a `${` at all.
+pub fn interpolate_config<T: Serialize + DeserializeOwned>(
+    value: &T,
+    allowlist: &[String],
+    lookup: EnvLookup,
+) -> Result<Option<T>> {
+    let mut json = serde_json::to_value(value)?;
+
+    if !contains_variable(&json) {
+        return Ok(None);
+    }
+
+    interpolate_json(&mut json, "", allowlist, lookup)?;
+
+    Ok(Some(serde_json::from_value(json)?))
+}
+
+/// Interpolates the variables in a single string. `key` names the config
+/// value in error messages.
+pub fn interpolate(
+    input: &str,
+    key: &str,
+    allowlist: &[String],
+    lookup: EnvLookup,
+) -> Result<String> {
+    let mut error = None;
+
+    let output = VARIABLE.replace_all(input, |caps: &Captures| {
+        let Some(name) = caps.get(1).map(|m| m.as_str()) else {
+            return "${".to_string();
+        };
+
+        match resolve_variable(name, caps.get(2), allowlist, lookup) {
+            Ok(value) => value,
+            Err(message) => {
+                error.get_or_insert_with(|| {
+                    ReleasaurusError::invalid_config(format!(
+                        "{key}: {message}"
+                    ))
+                });
+                String::new()
+            }
+        }
+    });
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(output.into_owned()),
+    }
+}
+
+fn resolve_variable(
+    name: &str,
+    default: Option<regex::Match>,
+    allowlist: &[String],
+    lookup: EnvLookup,
+) -> std::result::Result<String, String> {
+    if !is_allowed(name, allowlist) {
+        return Err(format!(
+            "environment variable {name} is not listed in \
+             repository.env_allowlist"
+        ));
+    }
+
+    match (lookup(name).filter(|v| !v.is_empty()), default) {
+        (Some(value), _) => Ok(value),
+        (None, Some(default)) => Ok(default.as_str().to_string()),
+        (None, None) => Err(format!(
+            "environment variable {name} is not set and has no default"
+        )),
+    }
+}
+
+/// Allow-list entries are exact names, or a prefix followed by `*`.
+fn is_allowed(name: &str, allowlist: &[String]) -> bool {
+    allowlist.iter().any(|entry| match entry.strip_suffix('*') {
+        Some(prefix) => name.starts_with(prefix),
+        None => entry == name,
+    })
+}
+
+fn contains_variable(value: &Value) -> bool {
+    match value {
+        Value::String(s) => s.contains("${"),
+        Value::Array(items) => items.iter().any(contains_variable),
+        Value::Object(map) => map.values().any(contains_variable),
+        _ => false,
+    }
+}
+
+fn interpolate_json(
+    value: &mut Value,
+    key: &str,
+    allowlist: &[String],
+    lookup: EnvLookup,
+) -> Result<()> {
+    match value {
+        Value::String(s) if s.contains("${") => {
+            *s = interpolate(s, key, allowlist, lookup)?;
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                let key = format!("{key}[{index}]");
+                interpolate_json(item, &key, allowlist, lookup)?;
+            }
+        }
+        Value::Object(map) => {
+            for (name, item) in map.iter_mut() {
+                let key = if key.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{key}.{name}")
+                };
+                interpolate_json(item, &key, allowlist, lookup)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::config::{Config, package::PackageConfig};
+
+    use super::*;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<HashMap<_, _>>();
+        move |name| vars.get(name).cloned()
+    }
+
+    fn allow(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn substitutes_variables_and_defaults() {
+        let lookup = env(&[("CI_BRANCH", "release"), ("EMPTY", "")]);
+        let allowlist = allow(&["CI_*", "EMPTY", "CHANNEL"]);
+
+        let cases = [
+            ("${CI_BRANCH}", "release"),
+            ("api-${CI_BRANCH}-v", "api-release-v"),
+            ("${CHANNEL:-beta}", "beta"),
+            ("${EMPTY:-fallback}", "fallback"),
+            ("${CI_MISSING:-}", ""),
+            ("$${CI_BRANCH}", "${CI_BRANCH}"),
+            ("plain {{ tera }}", "plain {{ tera }}"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(
+                interpolate(input, "key", &allowlist, &lookup).unwrap(),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_variables_outside_the_allowlist_and_unset_ones() {
+        let lookup = env(&[("GITHUB_TOKEN", "secret")]);
+        let allowlist = allow(&["CI_*"]);
+
+        let err = interpolate("${GITHUB_TOKEN}", "x", &allowlist, &lookup)
+            .unwrap_err();
+        assert!(err.to_string().contains("not listed"), "{err}");
+        assert!(!err.to_string().contains("secret"));
+
+        let err =
+            interpolate("${CI_TAG}", "x", &allowlist, &lookup).unwrap_err();
+        assert!(err.to_string().contains("not set"), "{err}");
+    }
+
+    #[test]
+    fn interpolates_nested_config_strings_with_key_paths() {
+        let lookup = env(&[("SUFFIX", "rc"), ("PREFIX", "core-v")]);
+        let allowlist = allow(&["SUFFIX", "PREFIX"]);
+        let mut config: Config = toml::from_str(
+            "[repository]\nbase_branch = \"${BRANCH:-main}\"\n\
+             [defaults.versioning.prerelease]\nsuffix = \"${SUFFIX}\"\n\
+             [[package]]\ntag_prefix = \"${PREFIX}\"\n",
+        )
+        .unwrap();
+
+        let err = interpolate_config(&config, &allowlist, &lookup)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("repository.base_branch"), "{err}");
+
+        config.repository.base_branch = None;
+        let config = interpolate_config(&config, &allowlist, &lookup)
+            .unwrap()
+            .unwrap();
+        let suffix = config
+            .defaults
+            .versioning
+            .unwrap()
+            .prerelease
+            .unwrap()
+            .suffix;
+
+        assert_eq!(suffix, "rc");
+        assert_eq!(config.packages[0].tag_prefix.as_deref(), Some("core-v"));
+    }
+
+    #[test]
+    fn leaves_values_without_variables_untouched() {
+        let package = PackageConfig::default();
+
+        assert!(
+            interpolate_config(&package, &[], &env(&[]))
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use url::Url;
 
 use crate::{
+    analyzer::mailmap::Mailmap,
     config::{
         changelog::DEFAULT_AGGREGATE_PRERELEASES,
         defaults::DefaultsConfig,
@@ -11,14 +12,20 @@ use crate::{
     packages::resolved::ResolvedPackage,
     resolver::resolvers::{
         analyzer::{AnalyzerParams, build_analyzer_config},
-        changelog::resolve_changelog_config,
+        changelog::{
+            resolve_changelog_config, resolve_changelog_files,
+            resolve_references,
+        },
         manifest::compile_additional_manifests,
         package_name::resolve_package_name,
-        path_utils::{normalize_additional_paths, normalize_package_paths},
+        path_utils::{
+            compile_path_filter, normalize_additional_paths,
+            normalize_package_paths,
+        },
         sub_packages::resolve_sub_packages_full,
         tag_prefix::resolve_tag_prefix,
         templates::resolve_package_templates,
-        versioning::resolve_versioning,
+        versioning::{resolve_release_as, resolve_versioning},
     },
     result::Result,
 };
@@ -32,6 +39,8 @@ pub struct PackageResolverParams<'a> {
     pub global_overrides: &'a GlobalOverrides,
     pub compare_link_base_url: &'a Url,
     pub release_link_base_url: &'a Url,
+    pub issue_link_base_url: Option<&'a Url>,
+    pub mailmap: &'a Mailmap,
 }
 
 pub fn resolve_package(
@@ -46,6 +55,8 @@ pub fn resolve_package(
         global_overrides,
         compare_link_base_url,
         release_link_base_url,
+        issue_link_base_url,
+        mailmap,
     } = params;
 
     let name = resolve_package_name(&package_config, repo_name);
@@ -65,6 +76,8 @@ pub fn resolve_package(
         global_overrides,
     )?;
 
+    let release_as = resolve_release_as(&name, package_overrides)?;
+
     warn_ignored_semantic_config(
         &name,
         &versioning_config,
@@ -82,12 +95,23 @@ pub fn resolve_package(
     // Resolve additional paths
     let normalized_additional_paths =
         normalize_additional_paths(&package_config);
+    let path_filter = compile_path_filter(&package_config)?;
+    let scopes = package_config.scopes.clone().unwrap_or_default();
 
     let default_changelog = defaults.changelog.clone().unwrap_or_default();
 
     let changelog_config =
         resolve_changelog_config(&package_config, &default_changelog);
 
+    let changelog_files = resolve_changelog_files(
+        &name,
+        &normalized_full_path,
+        &changelog_config,
+    )?;
+
+    let references =
+        resolve_references(&changelog_config, issue_link_base_url)?;
+
     let aggregate_prereleases = changelog_config
         .aggregate_prereleases
         .unwrap_or(DEFAULT_AGGREGATE_PRERELEASES);
@@ -100,6 +124,8 @@ pub fn resolve_package(
         compare_link_base_url: Some(compare_link_base_url.clone()),
         release_link_base_url: Some(release_link_base_url.clone()),
         tag_prefix: tag_prefix.clone(),
+        references,
+        mailmap: mailmap.clone(),
     });
 
     let release_type = package_config.release_type.unwrap_or_default();
@@ -125,8 +151,12 @@ pub fn resolve_package(
         tag_prefix,
         sub_packages,
         aggregate_prereleases,
+        release_as,
         normalized_additional_paths,
+        path_filter,
+        scopes,
         compiled_additional_manifests,
+        changelog_files,
         analyzer_config,
         versioning_config,
         commit_message_template: templates.commit_message,
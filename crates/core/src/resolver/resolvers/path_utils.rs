@@ -4,12 +4,20 @@
 //! and Unix systems by normalizing separators and removing redundant
 //! path segments.
 
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
 use std::{
     borrow::Cow,
     path::{Path, PathBuf},
 };
 
-use crate::config::package::PackageConfig;
+use crate::{
+    config::package::PackageConfig,
+    packages::resolved::PathFilter,
+    result::{ReleasaurusError, Result},
+};
+
+/// Characters that make a configured path a glob.
+pub const GLOB_CHARS: &[char] = &['*', '?', '[', '{'];
 
 /// Normalizes a path by replacing backslashes with forward slashes
 /// and removing all "./" sequences.
@@ -70,24 +78,75 @@ pub fn normalize_package_paths(package: &PackageConfig) -> (PathBuf, PathBuf) {
     (normalized_workspace_root, normalized_full_path)
 }
 
-/// Normalizes additional paths for a package.
+/// Normalizes the plain (non-glob) additional paths for a package. Glob
+/// entries are compiled by [`compile_path_filter`] instead.
 pub fn normalize_additional_paths(package: &PackageConfig) -> Vec<PathBuf> {
     package
         .additional_paths
-        .clone()
-        .unwrap_or_default()
         .iter()
-        .map(|p| {
-            let normalized = normalize_path(p).to_string();
-            if normalized == "." {
-                Path::new("").to_path_buf()
-            } else {
-                Path::new(&normalized).to_path_buf()
-            }
-        })
+        .flatten()
+        .filter(|p| !p.contains(GLOB_CHARS))
+        .map(|p| normalize_plain_path(p))
         .collect()
 }
 
+/// Compiles the glob `additional_paths` entries and all `exclude_paths`
+/// entries for a package.
+pub fn compile_path_filter(package: &PackageConfig) -> Result<PathFilter> {
+    let excludes = package.exclude_paths.iter().flatten();
+
+    Ok(PathFilter {
+        include_globs: compile_globs(
+            package
+                .additional_paths
+                .iter()
+                .flatten()
+                .filter(|p| p.contains(GLOB_CHARS)),
+        )?,
+        exclude_prefixes: excludes
+            .clone()
+            .filter(|p| !p.contains(GLOB_CHARS))
+            .map(|p| normalize_plain_path(p))
+            .collect(),
+        exclude_globs: compile_globs(
+            excludes.filter(|p| p.contains(GLOB_CHARS)),
+        )?,
+    })
+}
+
+fn normalize_plain_path(path: &str) -> PathBuf {
+    let normalized = normalize_path(path);
+    let normalized = normalized.trim_end_matches('/');
+    if normalized == "." {
+        PathBuf::new()
+    } else {
+        PathBuf::from(normalized)
+    }
+}
+
+fn compile_globs<'a>(
+    patterns: impl Iterator<Item = &'a String>,
+) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+
+    for pattern in patterns {
+        let normalized = normalize_path(pattern);
+        let glob = GlobBuilder::new(normalized.trim_end_matches('/'))
+            .literal_separator(true)
+            .build()
+            .map_err(|e| {
+                ReleasaurusError::invalid_config(format!(
+                    "invalid path glob {pattern}: {e}"
+                ))
+            })?;
+        builder.add(glob);
+    }
+
+    builder.build().map_err(|e| {
+        ReleasaurusError::invalid_config(format!("invalid path globs: {e}"))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,4 +186,40 @@ mod tests {
     fn handles_empty_path() {
         assert_eq!(normalize_path(""), "");
     }
+
+    #[test]
+    fn splits_plain_paths_from_globs() {
+        let package = PackageConfig {
+            additional_paths: Some(vec![
+                "./shared/".into(),
+                "proto/**/*.proto".into(),
+            ]),
+            exclude_paths: Some(vec!["**/*.md".into(), "api/tests".into()]),
+            ..PackageConfig::default()
+        };
+
+        assert_eq!(
+            normalize_additional_paths(&package),
+            vec![PathBuf::from("shared")]
+        );
+
+        let filter = compile_path_filter(&package).unwrap();
+
+        assert!(filter.include_globs.is_match("proto/v1/api.proto"));
+        assert!(!filter.include_globs.is_match("proto/README.md"));
+        assert!(filter.is_excluded(Path::new("api/src/README.md")));
+        assert!(filter.is_excluded(Path::new("api/tests/it.rs")));
+        assert!(!filter.is_excluded(Path::new("api/src/lib.rs")));
+    }
+
+    #[test]
+    fn rejects_invalid_globs() {
+        let package = PackageConfig {
+            exclude_paths: Some(vec!["docs/[".into()]),
+            ..PackageConfig::default()
+        };
+
+        let err = compile_path_filter(&package).unwrap_err();
+        assert!(err.to_string().contains("docs/["), "{err}");
+    }
 }
@@ -3,7 +3,7 @@ use std::path::Path;
 use crate::{
     analyzer::config::AnalyzerConfig,
     config::{package::PackageConfig, versioning::VersioningConfig},
-    packages::resolved::ResolvedPackage,
+    packages::resolved::{PathFilter, ResolvedPackage},
     resolver::resolvers::{
         package_name::resolve_sub_package_name, path_utils::normalize_path,
     },
@@ -48,8 +48,12 @@ pub fn resolve_sub_packages_full(
                 tag_prefix: tag_prefix.to_string(),
                 sub_packages: vec![],
                 aggregate_prereleases: false,
+                release_as: None,
                 normalized_additional_paths: vec![],
+                path_filter: PathFilter::default(),
+                scopes: vec![],
                 compiled_additional_manifests: vec![],
+                changelog_files: vec![],
                 analyzer_config: analyzer_config.clone(),
                 versioning_config: versioning_config.clone(),
                 // A sub-package shares its parent's release PR, so it
@@ -111,6 +111,7 @@ mod tests {
                 prerelease_suffix: None,
                 prerelease_strategy: None,
                 version_type: None,
+                release_as: None,
             },
         );
 
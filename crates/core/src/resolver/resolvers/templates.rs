@@ -180,7 +180,7 @@ const ONE_OFF_TEMPLATE_NAME: &str = "__tera_one_off";
 /// '<template>'`; the actionable detail — the missing variable, the
 /// syntax error and its position — sits further down the chain, so
 /// reporting just the outermost error would say nothing useful.
-fn flatten(err: &tera::Error) -> String {
+pub(super) fn flatten(err: &tera::Error) -> String {
     let mut messages = vec![];
     let mut next: Option<&(dyn std::error::Error + 'static)> = Some(err);
 
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use crate::{
+    config::version_group::VersionGroupConfig,
+    result::{ReleasaurusError, Result},
+};
+
+/// Checks that every version group names at least two configured packages
+/// and that no package belongs to more than one group.
+pub fn resolve_version_groups(
+    groups: &[VersionGroupConfig],
+    package_names: &HashSet<&str>,
+) -> Result<Vec<VersionGroupConfig>> {
+    let mut grouped = HashSet::new();
+
+    for group in groups {
+        if group.packages.len() < 2 {
+            return Err(ReleasaurusError::invalid_config(format!(
+                "{} version group [{}] needs at least two packages",
+                group.mode,
+                group.packages.join(", ")
+            )));
+        }
+
+        for name in group.packages.iter() {
+            if !package_names.contains(name.as_str()) {
+                return Err(ReleasaurusError::invalid_config(format!(
+                    "{} version group: unknown package '{name}'",
+                    group.mode
+                )));
+            }
+
+            if !grouped.insert(name.as_str()) {
+                return Err(ReleasaurusError::invalid_config(format!(
+                    "package '{name}' belongs to more than one version group"
+                )));
+            }
+        }
+    }
+
+    Ok(groups.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::version_group::VersionGroupMode;
+
+    use super::*;
+
+    fn group(mode: VersionGroupMode, names: &[&str]) -> VersionGroupConfig {
+        VersionGroupConfig {
+            mode,
+            packages: names.iter().map(|n| n.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn accepts_disjoint_groups_of_known_packages() {
+        let groups = vec![
+            group(VersionGroupMode::Fixed, &["sdk", "plugin"]),
+            group(VersionGroupMode::Linked, &["web", "api"]),
+        ];
+
+        let resolved = resolve_version_groups(
+            &groups,
+            &HashSet::from(["sdk", "plugin", "web", "api"]),
+        )
+        .unwrap();
+
+        assert_eq!(resolved.len(), 2);
+    }
+
+    #[test]
+    fn rejects_unknown_overlapping_and_single_member_groups() {
+        let packages = HashSet::from(["sdk", "plugin", "web"]);
+
+        let cases = [
+            (
+                vec![group(VersionGroupMode::Fixed, &["sdk", "missing"])],
+                "unknown package 'missing'",
+            ),
+            (
+                vec![
+                    group(VersionGroupMode::Fixed, &["sdk", "plugin"]),
+                    group(VersionGroupMode::Linked, &["web", "sdk"]),
+                ],
+                "package 'sdk' belongs to more than one version group",
+            ),
+            (
+                vec![group(VersionGroupMode::Linked, &["web"])],
+                "needs at least two packages",
+            ),
+        ];
+
+        for (groups, expected) in cases {
+            let err = resolve_version_groups(&groups, &packages).unwrap_err();
+            assert!(err.to_string().contains(expected), "{err}");
+        }
+    }
+}
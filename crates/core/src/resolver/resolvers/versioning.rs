@@ -1,6 +1,7 @@
 use indexmap::IndexMap;
 use merge::Merge;
 use regex::Regex;
+use semver::Version;
 
 use crate::{
     config::{
@@ -106,6 +107,30 @@ pub fn resolve_versioning(
     Ok(final_versioning)
 }
 
+/// Resolves a package's `release_as` override into the version it asks
+/// for. A leading `v` is accepted, matching `Release-As` commit footers.
+pub fn resolve_release_as(
+    name: &str,
+    package_overrides: &PackageOverridesHash,
+) -> Result<Option<Version>> {
+    let Some(value) = package_overrides
+        .get(name)
+        .and_then(|o| o.release_as.as_ref())
+    else {
+        return Ok(None);
+    };
+
+    let version = Version::parse(value.trim().trim_start_matches('v'))
+        .map_err(|e| {
+            ReleasaurusError::invalid_config(format!(
+                "invalid release_as version \"{value}\" for package {name}: \
+                 {e}"
+            ))
+        })?;
+
+    Ok(Some(version))
+}
+
 /// Layers user overrides on top of [`NAMED_PARSERS`] field by field.
 ///
 /// Precedence per field is package, then `[defaults]`, then the built-in
@@ -211,9 +236,9 @@ fn validate_order_in_range(
 /// Rejects custom parsers that would silently do nothing.
 ///
 /// Unlike named parsers, custom parsers have no built-in defaults to fall
-/// back on: one without a `pattern` never matches, one without a `title`
-/// groups its commits under an empty changelog heading, and one without an
-/// `order` has no defined position among the built-in groups.
+/// back on: one without a `pattern` or `author` never matches, one without a
+/// `title` groups its commits under an empty changelog heading, and one
+/// without an `order` has no defined position among the built-in groups.
 fn validate_custom_parsers(versioning: &VersioningConfig) -> Result<()> {
     let Some(custom_parsers) = versioning.custom_parsers.as_ref() else {
         return Ok(());
@@ -222,33 +247,36 @@ fn validate_custom_parsers(versioning: &VersioningConfig) -> Result<()> {
     for parser in custom_parsers.0.iter() {
         let title = parser.title.as_deref().map(str::trim);
 
-        let Some(pattern) = parser.pattern.as_ref() else {
-            return Err(ReleasaurusError::invalid_config(format!(
-                r#"custom parser "{}" is missing a "pattern": a custom parser without a pattern never matches any commit"#,
-                title.unwrap_or_default()
-            )));
+        // the matcher a commit is identified by in error messages
+        let (kind, matcher) = match (
+            parser.pattern.as_ref(),
+            parser.author.as_ref(),
+        ) {
+            (Some(pattern), _) => ("pattern", pattern.as_str()),
+            (None, Some(author)) => ("author", author.as_str()),
+            (None, None) => {
+                return Err(ReleasaurusError::invalid_config(format!(
+                    r#"custom parser "{}" is missing a "pattern": a custom parser without a pattern or an author never matches any commit"#,
+                    title.unwrap_or_default()
+                )));
+            }
         };
 
         if title.is_none_or(str::is_empty) {
             return Err(ReleasaurusError::invalid_config(format!(
-                r#"custom parser with pattern "{}" is missing a "title": matching commits would be grouped under an empty changelog heading"#,
-                pattern.as_str()
+                r#"custom parser with {kind} "{matcher}" is missing a "title": matching commits would be grouped under an empty changelog heading"#
             )));
         }
 
-        validate_title_has_no_order_tag(
-            title.unwrap_or_default(),
-            pattern.as_str(),
-        )?;
+        validate_title_has_no_order_tag(title.unwrap_or_default(), matcher)?;
 
         if parser.order.is_none() {
             return Err(ReleasaurusError::invalid_config(format!(
-                r#"custom parser with pattern "{}" is missing an "order": custom groups have no built-in position, so "order" (0-{MAX_PARSER_ORDER}) is required"#,
-                pattern.as_str()
+                r#"custom parser with {kind} "{matcher}" is missing an "order": custom groups have no built-in position, so "order" (0-{MAX_PARSER_ORDER}) is required"#
             )));
         }
 
-        validate_order_in_range(parser.order, pattern.as_str())?;
+        validate_order_in_range(parser.order, matcher)?;
     }
 
     Ok(())
@@ -486,6 +514,7 @@ mod tests {
                 tag_prefix: None,
                 prerelease_suffix: None,
                 prerelease_strategy: None,
+                release_as: None,
             },
         )]);
 
@@ -516,6 +545,48 @@ mod tests {
         assert_eq!(resolved.version_type, Some(VersionType::Date));
     }
 
+    #[test]
+    fn resolve_release_as_parses_package_override() {
+        let package_overrides = HashMap::from([(
+            "frontend".to_string(),
+            PackageOverrides {
+                version_type: None,
+                tag_prefix: None,
+                prerelease_suffix: None,
+                prerelease_strategy: None,
+                release_as: Some("v3.0.0".to_string()),
+            },
+        )]);
+
+        assert_eq!(
+            resolve_release_as("frontend", &package_overrides).unwrap(),
+            Some(Version::new(3, 0, 0))
+        );
+        assert_eq!(
+            resolve_release_as("backend", &package_overrides).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn resolve_release_as_rejects_invalid_version() {
+        let package_overrides = HashMap::from([(
+            "frontend".to_string(),
+            PackageOverrides {
+                version_type: None,
+                tag_prefix: None,
+                prerelease_suffix: None,
+                prerelease_strategy: None,
+                release_as: Some("three".to_string()),
+            },
+        )]);
+
+        let err = resolve_release_as("frontend", &package_overrides)
+            .expect_err("a non-semver release_as must be rejected");
+
+        assert!(matches!(err, ReleasaurusError::InvalidConfig(_)));
+    }
+
     /// A `[[package]]` without a `name` is addressed by its *resolved* name
     /// (derived from the repo or path), so the override lookup has to use
     /// that rather than the empty `PackageConfig::name`.
@@ -528,6 +599,7 @@ mod tests {
                 tag_prefix: None,
                 prerelease_suffix: Some("beta".to_string()),
                 prerelease_strategy: None,
+                release_as: None,
             },
         )]);
 
@@ -613,6 +685,7 @@ mod tests {
                 Group::CI,
                 Parser {
                     pattern: None,
+                    author: None,
                     title: None,
                     skip: Some(true),
                     order: None,
@@ -626,6 +699,7 @@ mod tests {
                 Group::CI,
                 Parser {
                     pattern: None,
+                    author: None,
                     title: Some("Pipelines".into()),
                     skip: None,
                     order: None,
@@ -669,6 +743,7 @@ mod tests {
                 Group::Chore,
                 Parser {
                     pattern: None,
+                    author: None,
                     title: None,
                     skip: Some(true),
                     order: None,
@@ -682,6 +757,7 @@ mod tests {
                 Group::Chore,
                 Parser {
                     pattern: None,
+                    author: None,
                     title: None,
                     skip: Some(false),
                     order: None,
@@ -718,6 +794,7 @@ mod tests {
                 Group::Chore,
                 Parser {
                     pattern: None,
+                    author: None,
                     title: None,
                     skip: Some(true),
                     order: None,
@@ -791,6 +868,7 @@ mod tests {
         let package = package_with_versioning(VersioningConfig {
             custom_parsers: Some(ParserList(vec![Parser {
                 pattern: None,
+                author: None,
                 title: Some("Dependencies".into()),
                 skip: Some(false),
                 order: None,
@@ -816,6 +894,7 @@ mod tests {
         let package = package_with_versioning(VersioningConfig {
             custom_parsers: Some(ParserList(vec![Parser {
                 pattern: Some(Regex::new("^deps").unwrap()),
+                author: None,
                 title: Some("   ".into()),
                 skip: Some(false),
                 order: None,
@@ -846,6 +925,7 @@ mod tests {
                 Group::CI,
                 Parser {
                     pattern: None,
+                    author: None,
                     title: Some("   ".into()),
                     skip: None,
                     order: None,
@@ -874,6 +954,7 @@ mod tests {
                 Group::Fix,
                 Parser {
                     pattern: None,
+                    author: None,
                     title: Some("Fixes".into()),
                     skip: None,
                     order: Some(7),
@@ -889,6 +970,7 @@ mod tests {
                 Group::Fix,
                 Parser {
                     pattern: None,
+                    author: None,
                     title: None,
                     skip: None,
                     order: Some(1),
@@ -942,6 +1024,7 @@ mod tests {
         let package = package_with_versioning(VersioningConfig {
             custom_parsers: Some(ParserList(vec![Parser {
                 pattern: Some(Regex::new("^deps").unwrap()),
+                author: None,
                 title: Some("Dependencies".into()),
                 skip: Some(false),
                 order: None,
@@ -973,6 +1056,7 @@ mod tests {
                 Group::CI,
                 Parser {
                     pattern: None,
+                    author: None,
                     title: None,
                     skip: None,
                     order: Some(MAX_PARSER_ORDER + 1),
@@ -1001,6 +1085,7 @@ mod tests {
                 Group::Feature,
                 Parser {
                     pattern: None,
+                    author: None,
                     title: Some("<!-- 01 -->🚀 Features".into()),
                     skip: None,
                     order: None,
@@ -1025,6 +1110,7 @@ mod tests {
         let package = package_with_versioning(VersioningConfig {
             custom_parsers: Some(ParserList(vec![Parser {
                 pattern: Some(Regex::new("^deps").unwrap()),
+                author: None,
                 title: Some("<!-- 03 -->📦 Dependencies".into()),
                 skip: Some(false),
                 order: Some(3),
@@ -1066,4 +1152,50 @@ mod tests {
 
         assert_eq!(resolved.custom_parsers.unwrap().0.len(), 1);
     }
+
+    #[test]
+    fn resolve_versioning_accepts_custom_parser_matching_only_author() {
+        let package = package_with_versioning(VersioningConfig {
+            custom_parsers: Some(ParserList(vec![Parser {
+                author: Some(Regex::new(r"\[bot\]").unwrap()),
+                ..Parser::new(None, "🤖 Bots".into(), true, 3)
+            }])),
+            ..VersioningConfig::default()
+        });
+
+        let resolved = resolve_versioning(
+            "",
+            &package,
+            None,
+            &HashMap::new(),
+            &GlobalOverrides::default(),
+        )
+        .unwrap();
+
+        assert_eq!(resolved.custom_parsers.unwrap().0.len(), 1);
+
+        let package = package_with_versioning(VersioningConfig {
+            custom_parsers: Some(ParserList(vec![Parser {
+                author: Some(Regex::new(r"\[bot\]").unwrap()),
+                order: None,
+                ..Parser::new(None, "🤖 Bots".into(), true, 3)
+            }])),
+            ..VersioningConfig::default()
+        });
+
+        let err = resolve_versioning(
+            "",
+            &package,
+            None,
+            &HashMap::new(),
+            &GlobalOverrides::default(),
+        )
+        .unwrap_err()
+        .to_string();
+
+        assert!(
+            err.contains(r#"author "\[bot\]""#),
+            "unexpected error: {err}"
+        );
+    }
 }
@@ -25,6 +25,9 @@ pub enum ReleasaurusError {
     )]
     PendingRelease { branch: String, pr_number: u64 },
 
+    #[error("Manifest update verification failed: {0}")]
+    ManifestVerification(String),
+
     #[error("Invalid git remote URL: {0}")]
     InvalidRemoteUrl(String),
 
@@ -65,6 +68,10 @@ pub enum ReleasaurusError {
     #[error("JSON parse error: {0}")]
     JsonParseError(#[from] serde_json::Error),
 
+    // YAML parsing errors
+    #[error("YAML parse error: {0}")]
+    YamlParseError(#[from] serde_norway::Error),
+
     // XML parsing errors
     #[error("XML parse error: {0}")]
     XmlError(#[from] quick_xml::Error),
@@ -252,6 +259,14 @@ impl From<gitlab::api::projects::labels::CreateLabelBuilderError>
     }
 }
 
+impl From<gitlab::api::projects::repository::TreeBuilderError>
+    for ReleasaurusError
+{
+    fn from(err: gitlab::api::projects::repository::TreeBuilderError) -> Self {
+        Self::Other(color_eyre::Report::msg(format!("Builder error: {}", err)))
+    }
+}
+
 impl From<gitlab::api::projects::repository::tags::TagsBuilderError>
     for ReleasaurusError
 {
@@ -0,0 +1,758 @@
+//! Scaffolding of a starter `releasaurus.toml` for an existing repository.
+//!
+//! [`detect_packages`] inspects the repository through the
+//! [`ForgeManager`], expanding workspace declarations (Cargo, npm/pnpm,
+//! go.work, Maven modules and uv) into package proposals, and probes the
+//! existing tags to pick a tag prefix that continues the current history.
+//! [`render_config`] turns the proposals into a validated TOML document.
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use regex::Regex;
+use std::{
+    collections::{BTreeSet, HashSet},
+    sync::LazyLock,
+};
+use toml_edit::{ArrayOfTables, DocumentMut, Item, Table, value};
+
+use crate::{
+    config::{Config, release_type::ReleaseType},
+    forge::{manager::ForgeManager, request::GetFileContentRequest},
+    packages::manifests::ManifestFile,
+    result::{ReleasaurusError, Result},
+    updater::manager::UpdateManager,
+};
+
+/// Path used for a package at the repository root.
+const ROOT_PATH: &str = ".";
+
+/// Tag prefix candidates probed for a package at the repository root.
+const ROOT_TAG_PREFIXES: &[&str] = &["v", ""];
+
+/// Tag prefix regex matching any tag with a prefix before its version, e.g.
+/// `v1.2.0` or `api-v0.3.0`.
+const ANY_TAG_PREFIX: &str = r"\D+";
+
+/// Tag prefix templates probed for each package of a monorepo, in order
+/// of preference. The first entry is used when no tags match.
+const MONOREPO_TAG_PREFIXES: &[&str] = &[
+    "{name}-v", "{name}@", "{name}/v", "{name}-", "{name}@v", "{name}/",
+];
+
+static MAVEN_MODULE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<module>\s*([^<\s]+)\s*</module>").unwrap());
+
+/// A package entry proposed for the generated configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProposedPackage {
+    /// Package name, read from its manifest where possible
+    pub name: String,
+    /// Package directory relative to the repository root
+    pub path: String,
+    /// Release type implied by the workspace or manifest it was found in
+    pub release_type: ReleaseType,
+    /// Tag prefix matching existing tags, or the default for new packages
+    pub tag_prefix: String,
+}
+
+/// Snapshot of the repository used while detecting packages.
+struct RepoTree<'a> {
+    forge: &'a ForgeManager,
+    branch: String,
+    files: Vec<String>,
+}
+
+impl<'a> RepoTree<'a> {
+    async fn load(&self, path: &str) -> Result<Option<String>> {
+        if !self.files.iter().any(|f| f == path) {
+            return Ok(None);
+        }
+
+        self.forge
+            .get_file_content(GetFileContentRequest {
+                branch: Some(self.branch.clone()),
+                path: path.into(),
+            })
+            .await
+    }
+
+    /// Directories (relative to the repository root) containing a file
+    /// named `manifest`.
+    fn dirs_with(&self, manifest: &str) -> BTreeSet<String> {
+        self.files
+            .iter()
+            .filter_map(|file| match file.rsplit_once('/') {
+                Some((dir, name)) if name == manifest => Some(dir.to_string()),
+                None if file == manifest => Some(ROOT_PATH.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Expands workspace member patterns against the directories that
+    /// contain `manifest`.
+    fn expand(
+        &self,
+        manifest: &str,
+        include: &[String],
+        exclude: &[String],
+    ) -> Result<Vec<String>> {
+        let include = glob_set(include)?;
+        let exclude = glob_set(exclude)?;
+
+        Ok(self
+            .dirs_with(manifest)
+            .into_iter()
+            .filter(|dir| include.is_match(dir) && !exclude.is_match(dir))
+            .collect())
+    }
+}
+
+/// Inspects the repository at `branch` (or the default branch) and
+/// proposes one package per workspace member, or a single root package
+/// when no workspace is declared.
+pub async fn detect_packages(
+    forge: &ForgeManager,
+    branch: Option<String>,
+) -> Result<Vec<ProposedPackage>> {
+    let branch = branch.unwrap_or_else(|| forge.default_branch().to_string());
+    let files = forge.list_files(Some(branch.clone())).await?;
+    let tree = RepoTree {
+        forge,
+        branch,
+        files,
+    };
+
+    let mut members: Vec<(String, ReleaseType)> = vec![];
+    let mut push = |paths: Vec<String>, release_type: ReleaseType| {
+        for path in paths {
+            if !members.iter().any(|(p, _)| *p == path) {
+                members.push((path, release_type));
+            }
+        }
+    };
+
+    push(cargo_members(&tree).await?, ReleaseType::Rust);
+    push(node_members(&tree).await?, ReleaseType::Node);
+    push(go_members(&tree).await?, ReleaseType::Go);
+    push(maven_members(&tree).await?, ReleaseType::Java);
+    push(uv_members(&tree).await?, ReleaseType::Python);
+
+    if members.is_empty() {
+        let release_type = root_release_type(&tree);
+        let name = package_name(&tree, ROOT_PATH, release_type)
+            .await?
+            .unwrap_or_else(|| tree.forge.repo_name().to_string());
+        let tag_prefix = detect_tag_prefix(&tree, ROOT_TAG_PREFIXES, &name)
+            .await?
+            .unwrap_or_else(|| ROOT_TAG_PREFIXES[0].to_string());
+
+        return Ok(vec![ProposedPackage {
+            name,
+            path: ROOT_PATH.into(),
+            release_type,
+            tag_prefix,
+        }]);
+    }
+
+    let mut packages: Vec<ProposedPackage> = vec![];
+
+    for (path, release_type) in members {
+        let mut name = package_name(&tree, &path, release_type)
+            .await?
+            .unwrap_or_else(|| basename(&path, tree.forge.repo_name()));
+
+        if packages.iter().any(|p| p.name == name) {
+            log::warn!(
+                "package name {name} is used more than once: using the \
+                 path {path} as its name instead"
+            );
+            name = path.replace('/', "-");
+        }
+
+        let tag_prefix = detect_tag_prefix(&tree, MONOREPO_TAG_PREFIXES, &name)
+            .await?
+            .unwrap_or_else(|| {
+                MONOREPO_TAG_PREFIXES[0].replace("{name}", &name)
+            });
+
+        packages.push(ProposedPackage {
+            name,
+            path,
+            release_type,
+            tag_prefix,
+        });
+    }
+
+    Ok(packages)
+}
+
+/// Renders the proposed packages as a `releasaurus.toml` document,
+/// validating that the result parses as a [`Config`].
+pub fn render_config(packages: &[ProposedPackage]) -> Result<String> {
+    let mut doc = DocumentMut::new();
+    let mut entries = ArrayOfTables::new();
+
+    for package in packages {
+        let mut table = Table::new();
+        table.insert("name", value(&package.name));
+        table.insert("path", value(&package.path));
+        table.insert("release_type", value(package.release_type.to_string()));
+        table.insert("tag_prefix", value(&package.tag_prefix));
+        entries.push(table);
+    }
+
+    doc.insert("package", Item::ArrayOfTables(entries));
+
+    let rendered = format!(
+        "# Generated by `releasaurus init`. See \
+         https://releasaurus.rgon.io for all options.\n\n{doc}"
+    );
+
+    toml::from_str::<Config>(&rendered).map_err(|e| {
+        ReleasaurusError::invalid_config(format!(
+            "generated configuration is invalid: {e}"
+        ))
+    })?;
+
+    Ok(rendered)
+}
+
+fn glob_set(patterns: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+
+    for pattern in patterns {
+        let pattern = normalize_member(pattern);
+        let glob = GlobBuilder::new(&pattern)
+            .literal_separator(true)
+            .build()
+            .map_err(|e| {
+            ReleasaurusError::invalid_config(format!(
+                "invalid workspace member {pattern}: {e}"
+            ))
+        })?;
+        builder.add(glob);
+    }
+
+    builder.build().map_err(|e| {
+        ReleasaurusError::invalid_config(format!(
+            "invalid workspace member patterns: {e}"
+        ))
+    })
+}
+
+fn normalize_member(pattern: &str) -> String {
+    let trimmed = pattern
+        .trim()
+        .trim_start_matches("./")
+        .trim_end_matches('/')
+        .to_string();
+
+    if trimmed.is_empty() {
+        ROOT_PATH.into()
+    } else {
+        trimmed
+    }
+}
+
+fn basename(path: &str, fallback: &str) -> String {
+    if path == ROOT_PATH {
+        return fallback.to_string();
+    }
+
+    path.rsplit('/').next().unwrap_or(path).to_string()
+}
+
+fn parent_dir(file: &str) -> &str {
+    file.rsplit_once('/')
+        .map(|(dir, _)| dir)
+        .unwrap_or(ROOT_PATH)
+}
+
+fn join(dir: &str, file: &str) -> String {
+    if dir == ROOT_PATH {
+        file.to_string()
+    } else {
+        format!("{dir}/{file}")
+    }
+}
+
+fn toml_strings(value: Option<&toml::Value>) -> Vec<String> {
+    value
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn parse_toml(path: &str, content: &str) -> Option<toml::Table> {
+    content
+        .parse::<toml::Table>()
+        .inspect_err(|e| log::warn!("skipping unparsable {path}: {e}"))
+        .ok()
+}
+
+async fn cargo_members(tree: &RepoTree<'_>) -> Result<Vec<String>> {
+    let Some(content) = tree.load("Cargo.toml").await? else {
+        return Ok(vec![]);
+    };
+    let Some(manifest) = parse_toml("Cargo.toml", &content) else {
+        return Ok(vec![]);
+    };
+    let Some(workspace) = manifest.get("workspace") else {
+        return Ok(vec![]);
+    };
+
+    let include = toml_strings(workspace.get("members"));
+    let exclude = toml_strings(workspace.get("exclude"));
+    let mut members = tree.expand("Cargo.toml", &include, &exclude)?;
+
+    if manifest.contains_key("package") && !members.iter().any(|m| m == ".") {
+        members.insert(0, ROOT_PATH.into());
+    }
+
+    Ok(members)
+}
+
+async fn node_members(tree: &RepoTree<'_>) -> Result<Vec<String>> {
+    let mut include = vec![];
+    let mut exclude = vec![];
+
+    if let Some(content) = tree.load("package.json").await?
+        && let Ok(manifest) =
+            serde_json::from_str::<serde_json::Value>(&content)
+    {
+        let workspaces = manifest.get("workspaces");
+        let patterns = workspaces
+            .and_then(|w| w.as_array())
+            .or_else(|| workspaces?.get("packages")?.as_array());
+
+        for pattern in patterns.into_iter().flatten() {
+            if let Some(pattern) = pattern.as_str() {
+                split_negated(pattern, &mut include, &mut exclude);
+            }
+        }
+    }
+
+    if let Some(content) = tree.load("pnpm-workspace.yaml").await? {
+        for pattern in pnpm_packages(&content) {
+            split_negated(&pattern, &mut include, &mut exclude);
+        }
+    }
+
+    if include.is_empty() {
+        return Ok(vec![]);
+    }
+
+    tree.expand("package.json", &include, &exclude)
+}
+
+fn split_negated(
+    pattern: &str,
+    include: &mut Vec<String>,
+    exclude: &mut Vec<String>,
+) {
+    match pattern.strip_prefix('!') {
+        Some(negated) => exclude.push(negated.into()),
+        None => include.push(pattern.into()),
+    }
+}
+
+/// Reads the `packages:` list of a pnpm-workspace.yaml file.
+fn pnpm_packages(content: &str) -> Vec<String> {
+    let mut in_packages = false;
+    let mut packages = vec![];
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if !line.starts_with([' ', '\t', '-']) {
+            in_packages = trimmed == "packages:";
+            continue;
+        }
+
+        if in_packages && let Some(item) = trimmed.strip_prefix('-') {
+            let item = item.trim().trim_matches(['"', '\'']);
+            if !item.is_empty() {
+                packages.push(item.to_string());
+            }
+        }
+    }
+
+    packages
+}
+
+async fn go_members(tree: &RepoTree<'_>) -> Result<Vec<String>> {
+    let Some(content) = tree.load("go.work").await? else {
+        return Ok(vec![]);
+    };
+
+    let mut include = vec![];
+    let mut in_block = false;
+
+    for line in content.lines() {
+        let line = line.split("//").next().unwrap_or_default().trim();
+
+        if in_block {
+            if line == ")" {
+                in_block = false;
+            } else if !line.is_empty() {
+                include.push(line.to_string());
+            }
+        } else if let Some(rest) = line.strip_prefix("use") {
+            let rest = rest.trim();
+            if rest == "(" {
+                in_block = true;
+            } else if !rest.is_empty() {
+                include.push(rest.to_string());
+            }
+        }
+    }
+
+    tree.expand("go.mod", &include, &[])
+}
+
+async fn maven_members(tree: &RepoTree<'_>) -> Result<Vec<String>> {
+    let Some(content) = tree.load("pom.xml").await? else {
+        return Ok(vec![]);
+    };
+
+    let include = MAVEN_MODULE
+        .captures_iter(&content)
+        .map(|c| c[1].to_string())
+        .collect::<Vec<_>>();
+
+    if include.is_empty() {
+        return Ok(vec![]);
+    }
+
+    tree.expand("pom.xml", &include, &[])
+}
+
+async fn uv_members(tree: &RepoTree<'_>) -> Result<Vec<String>> {
+    let Some(content) = tree.load("pyproject.toml").await? else {
+        return Ok(vec![]);
+    };
+    let Some(manifest) = parse_toml("pyproject.toml", &content) else {
+        return Ok(vec![]);
+    };
+    let Some(workspace) = manifest
+        .get("tool")
+        .and_then(|t| t.get("uv"))
+        .and_then(|u| u.get("workspace"))
+    else {
+        return Ok(vec![]);
+    };
+
+    let include = toml_strings(workspace.get("members"));
+    let exclude = toml_strings(workspace.get("exclude"));
+
+    tree.expand("pyproject.toml", &include, &exclude)
+}
+
+/// Picks the release type for a repository without workspaces from the
+/// manifests found at its root.
+fn root_release_type(tree: &RepoTree<'_>) -> ReleaseType {
+    let found = UpdateManager::release_type_markers("")
+        .into_iter()
+        .filter(|(release_type, files)| {
+            tree.files.iter().any(|f| {
+                if *release_type == ReleaseType::Ruby {
+                    parent_dir(f) == ROOT_PATH && f.ends_with(".gemspec")
+                } else {
+                    files.contains(f)
+                }
+            })
+        })
+        .map(|(release_type, _)| release_type)
+        .collect::<Vec<_>>();
+
+    match found.as_slice() {
+        [release_type] => *release_type,
+        [] => ReleaseType::Generic,
+        _ => {
+            log::warn!(
+                "found manifests for more than one release type at the \
+                 repository root ({}): using generic",
+                found
+                    .iter()
+                    .map(|r| r.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            ReleaseType::Generic
+        }
+    }
+}
+
+/// Reads the canonical package name from the manifest in `dir`.
+async fn package_name(
+    tree: &RepoTree<'_>,
+    dir: &str,
+    release_type: ReleaseType,
+) -> Result<Option<String>> {
+    let candidates = if release_type == ReleaseType::Ruby {
+        tree.files
+            .iter()
+            .filter(|f| parent_dir(f) == dir && f.ends_with(".gemspec"))
+            .cloned()
+            .collect::<Vec<_>>()
+    } else {
+        UpdateManager::release_type_markers("")
+            .into_iter()
+            .find(|(r, _)| *r == release_type)
+            .map(|(_, files)| files.iter().map(|f| join(dir, f)).collect())
+            .unwrap_or_default()
+    };
+
+    for path in candidates {
+        if let Some(content) = tree.load(&path).await? {
+            let manifest = ManifestFile {
+                basename: basename(&path, &path),
+                path: path.into(),
+                content,
+            };
+
+            if let Some(name) = manifest.canonical_name() {
+                return Ok(Some(name));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Returns the first candidate prefix that existing tags already use. An
+/// empty prefix is only proposed when no tag carries a prefix at all.
+async fn detect_tag_prefix(
+    tree: &RepoTree<'_>,
+    candidates: &[&str],
+    name: &str,
+) -> Result<Option<String>> {
+    let mut tried = HashSet::new();
+
+    for candidate in candidates {
+        let prefix = candidate.replace("{name}", name);
+
+        if !tried.insert(prefix.clone()) {
+            continue;
+        }
+
+        if prefix.is_empty()
+            && !tree
+                .forge
+                .get_tags_for_prefix(ANY_TAG_PREFIX, &tree.branch)
+                .await?
+                .is_empty()
+        {
+            continue;
+        }
+
+        if let Some(tag) = tree
+            .forge
+            .get_latest_tag_for_prefix(&prefix, &tree.branch)
+            .await?
+        {
+            log::info!(
+                "package {name}: found existing tag {} for prefix \"{prefix}\"",
+                tag.name
+            );
+            return Ok(Some(prefix));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forge::{
+        manager::ForgeOptions, request::Tag, traits::MockForge,
+    };
+    use std::collections::HashMap;
+
+    fn forge_manager(
+        files: HashMap<&'static str, &'static str>,
+        tags: Vec<&'static str>,
+    ) -> ForgeManager {
+        let mut mock = MockForge::new();
+        let paths = files.keys().map(|k| k.to_string()).collect::<Vec<_>>();
+
+        mock.expect_default_branch()
+            .return_const("main".to_string());
+        mock.expect_repo_name().return_const("my-repo".to_string());
+        mock.expect_list_files()
+            .returning(move |_| Ok(paths.clone()));
+        mock.expect_get_file_content().returning(move |req| {
+            Ok(files.get(req.path.as_str()).map(|c| c.to_string()))
+        });
+        mock.expect_get_latest_tags_for_prefix().returning(
+            move |prefix, _, _| {
+                // prefixes are regexes, as in the forges
+                let re = Regex::new(&format!("^{prefix}")).unwrap();
+
+                Ok(tags
+                    .iter()
+                    .filter_map(|t| {
+                        let rest = re.find(t)?;
+                        let semver =
+                            semver::Version::parse(&t[rest.end()..]).ok()?;
+
+                        Some(Tag {
+                            name: t.to_string(),
+                            semver,
+                            ..Default::default()
+                        })
+                    })
+                    .collect())
+            },
+        );
+
+        ForgeManager::new(Box::new(mock), ForgeOptions { dry_run: false })
+    }
+
+    #[tokio::test]
+    async fn proposes_root_package_without_workspace() {
+        let forge = forge_manager(
+            HashMap::from([(
+                "package.json",
+                r#"{"name": "my-app", "version": "1.0.0"}"#,
+            )]),
+            vec![],
+        );
+
+        let packages = detect_packages(&forge, None).await.unwrap();
+
+        assert_eq!(
+            packages,
+            vec![ProposedPackage {
+                name: "my-app".into(),
+                path: ".".into(),
+                release_type: ReleaseType::Node,
+                tag_prefix: "v".into(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn expands_cargo_workspace_members_and_excludes() {
+        let forge = forge_manager(
+            HashMap::from([
+                (
+                    "Cargo.toml",
+                    "[workspace]\nmembers = [\"crates/*\"]\n\
+                     exclude = [\"crates/skip\"]\n",
+                ),
+                ("crates/core/Cargo.toml", "[package]\nname = \"my-core\"\n"),
+                ("crates/cli/Cargo.toml", "[package]\nname = \"my-cli\"\n"),
+                ("crates/skip/Cargo.toml", "[package]\nname = \"skip\"\n"),
+            ]),
+            vec!["my-core-v1.2.0"],
+        );
+
+        let packages = detect_packages(&forge, None).await.unwrap();
+
+        assert_eq!(packages.len(), 2);
+        assert_eq!(packages[0].name, "my-cli");
+        assert_eq!(packages[0].path, "crates/cli");
+        assert_eq!(packages[0].release_type, ReleaseType::Rust);
+        assert_eq!(packages[1].name, "my-core");
+        assert_eq!(packages[1].tag_prefix, "my-core-v");
+    }
+
+    #[tokio::test]
+    async fn detects_existing_tag_prefix_style() {
+        let forge = forge_manager(
+            HashMap::from([
+                ("pnpm-workspace.yaml", "packages:\n  - 'packages/*'\n"),
+                ("packages/ui/package.json", r#"{"name": "ui"}"#),
+            ]),
+            vec!["ui@2.0.0"],
+        );
+
+        let packages = detect_packages(&forge, None).await.unwrap();
+
+        assert_eq!(packages[0].tag_prefix, "ui@");
+    }
+
+    #[tokio::test]
+    async fn proposes_an_empty_prefix_only_without_prefixed_tags() {
+        let files = || {
+            HashMap::from([(
+                "package.json",
+                r#"{"name": "my-app", "version": "1.0.0"}"#,
+            )])
+        };
+
+        let bare = forge_manager(files(), vec!["1.0.0"]);
+        let packages = detect_packages(&bare, None).await.unwrap();
+        assert_eq!(packages[0].tag_prefix, "");
+
+        let mixed = forge_manager(files(), vec!["1.0.0", "api-v0.3.0"]);
+        let packages = detect_packages(&mixed, None).await.unwrap();
+        assert_eq!(packages[0].tag_prefix, "v");
+    }
+
+    #[tokio::test]
+    async fn reads_go_work_and_maven_modules() {
+        let forge = forge_manager(
+            HashMap::from([
+                ("go.work", "go 1.22\n\nuse (\n\t./api\n)\n"),
+                ("api/go.mod", "module github.com/org/api/v2\n"),
+                ("pom.xml", "<modules><module>lib</module></modules>"),
+                (
+                    "lib/pom.xml",
+                    "<parent><artifactId>root</artifactId></parent>\
+                     <artifactId>lib-core</artifactId>",
+                ),
+            ]),
+            vec![],
+        );
+
+        let packages = detect_packages(&forge, None).await.unwrap();
+        let names = packages
+            .iter()
+            .map(|p| (p.name.as_str(), p.release_type))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            names,
+            vec![("api", ReleaseType::Go), ("lib-core", ReleaseType::Java)]
+        );
+    }
+
+    #[test]
+    fn render_config_produces_a_valid_config() {
+        let rendered = render_config(&[ProposedPackage {
+            name: "api".into(),
+            path: "services/api".into(),
+            release_type: ReleaseType::Go,
+            tag_prefix: "api-v".into(),
+        }])
+        .unwrap();
+
+        let config: Config = toml::from_str(&rendered).unwrap();
+
+        assert_eq!(config.packages.len(), 1);
+        assert_eq!(config.packages[0].name, "api");
+        assert_eq!(config.packages[0].path, "services/api");
+        assert_eq!(config.packages[0].release_type, Some(ReleaseType::Go));
+        assert_eq!(config.packages[0].tag_prefix.as_deref(), Some("api-v"));
+    }
+
+    #[test]
+    fn pnpm_packages_reads_only_the_packages_list() {
+        let content = "packages:\n  - \"apps/*\"\n  - '!apps/legacy'\n\
+                       catalog:\n  - ignored\n";
+
+        assert_eq!(pnpm_packages(content), vec!["apps/*", "!apps/legacy"]);
+    }
+}
@@ -41,7 +41,11 @@ impl Updater {
     /// Create a new updater instance for the given release type.
     pub fn new(release_type: ReleaseType) -> Self {
         match release_type {
-            ReleaseType::Generic => Updater::Generic(GenericUpdater::default()),
+            // auto is swapped for a concrete type before manifests are
+            // loaded, so there is nothing left to update by this point
+            ReleaseType::Generic | ReleaseType::Auto => {
+                Updater::Generic(GenericUpdater::default())
+            }
             ReleaseType::Go => Updater::Go(GoUpdater::new()),
             ReleaseType::Java => Updater::Java(JavaUpdater::new()),
             ReleaseType::Node => Updater::Node(NodeUpdater::new()),
@@ -43,6 +43,18 @@ impl GenericUpdater {
 
         None
     }
+
+    /// The first version `version_regex` captures in `content`: the value
+    /// [`Self::update_manifest`] rewrites.
+    pub fn read_version(
+        content: &str,
+        version_regex: &Regex,
+    ) -> Option<String> {
+        version_regex
+            .captures(content)
+            .and_then(|caps| caps.name("version"))
+            .map(|m| m.as_str().to_string())
+    }
 }
 
 impl PackageUpdater for GenericUpdater {
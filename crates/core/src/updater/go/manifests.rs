@@ -1,6 +1,13 @@
 use std::path::Path;
 
-use crate::updater::{manager::ManifestTarget, traits::ManifestTargets};
+use crate::{
+    config::package::GENERIC_VERSION_REGEX,
+    updater::{
+        generic::updater::GenericUpdater,
+        manager::ManifestTarget,
+        traits::{ManifestTargets, ManifestVersion},
+    },
+};
 
 pub struct GoManifests {}
 
@@ -31,6 +38,21 @@ impl ManifestTargets for GoManifests {
     }
 }
 
+impl ManifestVersion for GoManifests {
+    fn manifest_version(
+        _pkg_name: &str,
+        basename: &str,
+        content: &str,
+    ) -> Option<String> {
+        match basename {
+            "version.go" => {
+                GenericUpdater::read_version(content, &GENERIC_VERSION_REGEX)
+            }
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
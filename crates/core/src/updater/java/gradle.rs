@@ -26,6 +26,11 @@ impl Gradle {
     pub fn new() -> Self {
         Self {}
     }
+
+    /// The project `version` a build.gradle(.kts) file assigns.
+    pub fn read_version(content: &str) -> Option<String> {
+        GenericUpdater::read_version(content, &GRADLE_VERSION_REGEX)
+    }
 }
 
 impl Default for Gradle {
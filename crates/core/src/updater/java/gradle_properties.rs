@@ -25,6 +25,11 @@ impl GradleProperties {
     pub fn new() -> Self {
         Self {}
     }
+
+    /// The `version` property a gradle.properties file sets.
+    pub fn read_version(content: &str) -> Option<String> {
+        GenericUpdater::read_version(content, &GRADLE_PROPERTIES_VERSION_REGEX)
+    }
 }
 
 impl Default for GradleProperties {
@@ -21,6 +21,15 @@ impl LibsVersionsToml {
         let doc = content.parse::<DocumentMut>()?;
         Ok(doc)
     }
+
+    /// The `[versions]` entry a version catalog keeps for `package_name`.
+    pub fn read_version(package_name: &str, content: &str) -> Option<String> {
+        let doc = content.parse::<DocumentMut>().ok()?;
+        let versions = doc.get("versions")?.as_table_like()?;
+        let key = find_version_key(versions, package_name)?;
+
+        versions.get(&key)?.as_str().map(String::from)
+    }
 }
 
 impl Default for LibsVersionsToml {
@@ -1,6 +1,13 @@
 use std::path::Path;
 
-use crate::updater::{manager::ManifestTarget, traits::ManifestTargets};
+use crate::updater::{
+    java::{
+        gradle::Gradle, gradle_properties::GradleProperties,
+        libs_versions_toml::LibsVersionsToml, maven::Maven,
+    },
+    manager::ManifestTarget,
+    traits::{ManifestTargets, ManifestVersion},
+};
 
 pub struct JavaManifests {}
 
@@ -43,6 +50,26 @@ impl ManifestTargets for JavaManifests {
     }
 }
 
+impl ManifestVersion for JavaManifests {
+    fn manifest_version(
+        pkg_name: &str,
+        basename: &str,
+        content: &str,
+    ) -> Option<String> {
+        match basename {
+            "build.gradle" | "build.gradle.kts" => {
+                Gradle::read_version(content)
+            }
+            "gradle.properties" => GradleProperties::read_version(content),
+            "pom.xml" => Maven::read_version(content),
+            "libs.versions.toml" => {
+                LibsVersionsToml::read_version(pkg_name, content)
+            }
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
@@ -99,4 +126,37 @@ mod tests {
             paths.contains(&"packages/my-java-app/gradle/libs.versions.toml")
         );
     }
+
+    #[test]
+    fn reads_the_project_version_from_each_manifest() {
+        let cases = [
+            ("build.gradle", "version = '1.2.3'\n"),
+            ("build.gradle.kts", "version = \"1.2.3\"\n"),
+            ("gradle.properties", "group=com.example\nversion=1.2.3\n"),
+            (
+                "pom.xml",
+                "<project><parent><version>9.9.9</version></parent>\
+                 <version>1.2.3</version><dependencies><dependency>\
+                 <version>4.5.6</version></dependency></dependencies>\
+                 </project>",
+            ),
+            (
+                "libs.versions.toml",
+                "[versions]\nguava = \"33.0.0\"\nmy-app = \"1.2.3\"\n",
+            ),
+        ];
+
+        for (basename, content) in cases {
+            assert_eq!(
+                JavaManifests::manifest_version("my_app", basename, content),
+                Some("1.2.3".to_string()),
+                "{basename}"
+            );
+        }
+
+        assert_eq!(
+            JavaManifests::manifest_version("my_app", "settings.gradle", ""),
+            None
+        );
+    }
 }
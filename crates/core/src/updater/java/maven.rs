@@ -21,6 +21,33 @@ impl Maven {
         Self {}
     }
 
+    /// The project-level `<version>` a pom.xml declares, ignoring the
+    /// versions of its parent, dependencies and plugins.
+    pub fn read_version(content: &str) -> Option<String> {
+        let mut reader = Reader::from_reader(content.as_bytes());
+        let mut in_project_version = false;
+        let mut depth = 0;
+
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(ref e)) => {
+                    depth += 1;
+                    in_project_version =
+                        depth == 2 && e.name().as_ref() == b"version";
+                }
+                Ok(Event::End(_)) => {
+                    depth -= 1;
+                    in_project_version = false;
+                }
+                Ok(Event::Text(ref e)) if in_project_version => {
+                    return Some(String::from_utf8_lossy(e).trim().to_string());
+                }
+                Ok(Event::Eof) | Err(_) => return None,
+                Ok(_) => {}
+            }
+        }
+    }
+
     /// Update a single pom.xml file
     fn update_pom_file(
         &self,
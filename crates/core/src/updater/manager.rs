@@ -1,4 +1,5 @@
 //! Framework and package management for multi-language support.
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
@@ -11,15 +12,20 @@ use crate::{
     packages::{
         manifests::{AdditionalManifestFile, ManifestFile},
         releasable::ReleasablePackage,
-        resolved::ResolvedPackage,
+        resolved::{CompiledAdditionalManifest, ResolvedPackage},
     },
-    result::Result,
+    result::{ReleasaurusError, Result},
     updater::{
-        dispatch::Updater, generic::updater::GenericUpdater,
-        go::manifests::GoManifests, java::manifests::JavaManifests,
-        node::manifests::NodeManifests, php::manifests::PhpManifests,
-        python::manifests::PythonManifests, ruby::manifests::RubyManifests,
-        rust::manifests::RustManifests, traits::ManifestTargets,
+        dispatch::Updater,
+        generic::updater::GenericUpdater,
+        go::manifests::GoManifests,
+        java::manifests::JavaManifests,
+        node::manifests::NodeManifests,
+        php::manifests::PhpManifests,
+        python::manifests::PythonManifests,
+        ruby::manifests::RubyManifests,
+        rust::manifests::RustManifests,
+        traits::{ManifestTargets, ManifestVersion},
     },
 };
 
@@ -97,6 +103,104 @@ impl UpdateManager {
         }
     }
 
+    /// Resolve the concrete release type for a package.
+    ///
+    /// Packages configured with `release_type = "auto"` have their
+    /// directory probed through the FileLoader for the well-known manifest
+    /// of each supported language. A single match decides the release
+    /// type, no match falls back to generic, and more than one match is
+    /// rejected as ambiguous. Any other release type is returned as-is.
+    pub async fn detect_release_type<F: FileLoader>(
+        pkg: &ResolvedPackage,
+        file_loader: &F,
+        base_branch: &str,
+    ) -> Result<ReleaseType> {
+        if pkg.release_type != ReleaseType::Auto {
+            return Ok(pkg.release_type);
+        }
+
+        let mut found: Vec<(ReleaseType, String)> = vec![];
+
+        for (release_type, files) in Self::release_type_markers(&pkg.name) {
+            for file in files {
+                let path = pkg
+                    .normalized_full_path
+                    .join(file)
+                    .to_string_lossy()
+                    .to_string();
+
+                if file_loader
+                    .load_file(Some(base_branch.into()), path.clone())
+                    .await?
+                    .is_some()
+                {
+                    found.push((release_type, path));
+                    break;
+                }
+            }
+        }
+
+        match found.as_slice() {
+            [] => {
+                log::warn!(
+                    "package {}: no known manifests found for release_type \
+                     auto: falling back to generic",
+                    pkg.name
+                );
+                Ok(ReleaseType::Generic)
+            }
+            [(release_type, path)] => {
+                log::info!(
+                    "package {}: detected release_type {release_type} from {path}",
+                    pkg.name
+                );
+                Ok(*release_type)
+            }
+            _ => {
+                let matches = found
+                    .iter()
+                    .map(|(release_type, path)| {
+                        format!("{path} ({release_type})")
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                Err(ReleasaurusError::invalid_config(format!(
+                    "package {}: release_type auto is ambiguous, found \
+                     manifests for more than one release type: {matches}. \
+                     Set release_type explicitly",
+                    pkg.name
+                )))
+            }
+        }
+    }
+
+    /// The well-known manifest files that identify each release type,
+    /// relative to a package directory, in detection order.
+    pub fn release_type_markers(
+        pkg_name: &str,
+    ) -> Vec<(ReleaseType, Vec<String>)> {
+        let files = |names: &[&str]| {
+            names.iter().map(|n| n.to_string()).collect::<Vec<_>>()
+        };
+
+        vec![
+            (ReleaseType::Rust, files(&["Cargo.toml"])),
+            (ReleaseType::Node, files(&["package.json"])),
+            (
+                ReleaseType::Python,
+                files(&["pyproject.toml", "setup.cfg", "setup.py"]),
+            ),
+            (
+                ReleaseType::Java,
+                files(&["pom.xml", "build.gradle", "build.gradle.kts"]),
+            ),
+            (ReleaseType::Go, files(&["go.mod"])),
+            (ReleaseType::Php, files(&["composer.json"])),
+            (ReleaseType::Ruby, vec![format!("{pkg_name}.gemspec")]),
+        ]
+    }
+
     /// Load additional manifest files for a package using a FileLoader.
     ///
     /// Loads user-configured additional manifest files that are not part of
@@ -224,6 +328,200 @@ impl UpdateManager {
         Ok(file_changes)
     }
 
+    /// Verify the file changes generated for a package actually bump
+    /// every target the package was configured with.
+    ///
+    /// Rewritten manifests are re-parsed to confirm the next version is
+    /// present, additional manifests are re-matched against their
+    /// `version_regex`, and any configured target that produced no change
+    /// is reported. Returns one description per problem found; an empty
+    /// list means every target was updated.
+    pub fn verify_package_manifest_file_changes(
+        package: &ReleasablePackage,
+        additional_targets: &[CompiledAdditionalManifest],
+        file_changes: &[FileChange],
+    ) -> Vec<String> {
+        let mut problems = vec![];
+        let version = package.tag.semver.to_string();
+
+        let mut changes: HashMap<&str, Vec<&FileChange>> = HashMap::new();
+
+        for change in file_changes {
+            changes
+                .entry(change.path.as_str())
+                .or_default()
+                .push(change);
+        }
+
+        Self::verify_release_type_changes(
+            package,
+            &changes,
+            &version,
+            &mut problems,
+        );
+
+        for sub in package.sub_packages.iter() {
+            Self::verify_release_type_changes(
+                &sub.to_releasable(package),
+                &changes,
+                &version,
+                &mut problems,
+            );
+        }
+
+        for target in additional_targets {
+            let path = target.path.to_string_lossy();
+
+            let Some(manifest) = package
+                .additional_manifest_files
+                .as_ref()
+                .and_then(|list| list.iter().find(|m| m.path == target.path))
+            else {
+                problems.push(format!(
+                    "{}: additional manifest not found: {path}",
+                    package.name
+                ));
+                continue;
+            };
+
+            let has_version = |content: &str| {
+                let mut captures =
+                    target.version_regex.captures_iter(content).peekable();
+
+                captures.peek().is_some()
+                    && captures.all(|caps| {
+                        caps.name("version").map(|m| m.as_str())
+                            == Some(version.as_str())
+                    })
+            };
+
+            match changes.get(path.as_ref()) {
+                Some(list) => {
+                    if !list.iter().any(|c| has_version(&c.content)) {
+                        problems.push(format!(
+                            "{}: {path} was rewritten but version_regex no \
+                             longer matches version {version}",
+                            package.name
+                        ));
+                    }
+                }
+                // already at the next version, nothing to do
+                None if has_version(&manifest.content) => {}
+                None => problems.push(format!(
+                    "{}: additional manifest {path} was not updated: \
+                     version_regex '{}' matched nothing to replace",
+                    package.name,
+                    target.version_regex.as_str()
+                )),
+            }
+        }
+
+        problems
+    }
+
+    /// Confirms at least one of the package's release type manifests was
+    /// rewritten with the next version, reading the version back with the
+    /// release type's own manifest parsing so a dependency or a longer
+    /// version containing it doesn't count. Manifests without a version of
+    /// their own, like a virtual workspace's `Cargo.toml`, aren't expected
+    /// to change.
+    fn verify_release_type_changes(
+        package: &ReleasablePackage,
+        changes: &HashMap<&str, Vec<&FileChange>>,
+        version: &str,
+        problems: &mut Vec<String>,
+    ) {
+        if matches!(
+            package.release_type,
+            ReleaseType::Generic | ReleaseType::Auto
+        ) {
+            return;
+        }
+
+        let Some(manifests) = package.manifest_files.as_ref() else {
+            problems.push(format!(
+                "{}: no {} manifest files found",
+                package.name, package.release_type
+            ));
+            return;
+        };
+
+        let manifest_version = |manifest: &ManifestFile, content: &str| {
+            Self::release_type_manifest_version(
+                &package.name,
+                package.release_type,
+                &manifest.basename,
+                content,
+            )
+        };
+
+        let versioned = manifests
+            .iter()
+            .filter(|m| manifest_version(m, &m.content).is_some())
+            .collect::<Vec<_>>();
+
+        if versioned.is_empty() {
+            return;
+        }
+
+        let updated = versioned.iter().any(|m| {
+            changes
+                .get(m.path.to_string_lossy().as_ref())
+                .is_some_and(|list| {
+                    list.iter().any(|c| {
+                        manifest_version(m, &c.content).as_deref()
+                            == Some(version)
+                    })
+                })
+        });
+
+        if !updated {
+            let paths = versioned
+                .iter()
+                .map(|m| m.path.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            problems.push(format!(
+                "{}: no {} manifest was updated to version {version} \
+                 (found: {paths})",
+                package.name, package.release_type
+            ));
+        }
+    }
+
+    fn release_type_manifest_version(
+        pkg_name: &str,
+        release_type: ReleaseType,
+        basename: &str,
+        content: &str,
+    ) -> Option<String> {
+        match release_type {
+            ReleaseType::Generic | ReleaseType::Auto => None,
+            ReleaseType::Go => {
+                GoManifests::manifest_version(pkg_name, basename, content)
+            }
+            ReleaseType::Java => {
+                JavaManifests::manifest_version(pkg_name, basename, content)
+            }
+            ReleaseType::Node => {
+                NodeManifests::manifest_version(pkg_name, basename, content)
+            }
+            ReleaseType::Php => {
+                PhpManifests::manifest_version(pkg_name, basename, content)
+            }
+            ReleaseType::Python => {
+                PythonManifests::manifest_version(pkg_name, basename, content)
+            }
+            ReleaseType::Ruby => {
+                RubyManifests::manifest_version(pkg_name, basename, content)
+            }
+            ReleaseType::Rust => {
+                RustManifests::manifest_version(pkg_name, basename, content)
+            }
+        }
+    }
+
     fn release_type_manifest_targets(
         pkg_name: &str,
         release_type: ReleaseType,
@@ -231,7 +529,7 @@ impl UpdateManager {
         pkg_path: &Path,
     ) -> Vec<ManifestTarget> {
         match release_type {
-            ReleaseType::Generic => vec![],
+            ReleaseType::Generic | ReleaseType::Auto => vec![],
             ReleaseType::Go => GoManifests::manifest_targets(
                 pkg_name,
                 workspace_path,
@@ -307,6 +605,8 @@ impl UpdaterPackage {
 mod tests {
     use std::path::Path;
 
+    use crate::forge::request::FileUpdateType;
+
     use super::*;
 
     fn create_test_manifest_params() -> (String, PathBuf, PathBuf) {
@@ -361,4 +661,231 @@ mod tests {
             );
         }
     }
+
+    fn releasable(release_type: ReleaseType) -> ReleasablePackage {
+        ReleasablePackage {
+            name: "my-pkg".into(),
+            release_type,
+            tag: Tag {
+                name: "v2.0.0".into(),
+                semver: semver::Version::parse("2.0.0").unwrap(),
+                ..Tag::default()
+            },
+            ..ReleasablePackage::default()
+        }
+    }
+
+    fn manifest(path: &str, content: &str) -> ManifestFile {
+        ManifestFile {
+            path: path.into(),
+            basename: path.into(),
+            content: content.into(),
+        }
+    }
+
+    fn additional_target(path: &str) -> CompiledAdditionalManifest {
+        CompiledAdditionalManifest {
+            path: path.into(),
+            version_regex: crate::config::package::GENERIC_VERSION_REGEX
+                .clone(),
+        }
+    }
+
+    fn additional_manifest(
+        path: &str,
+        content: &str,
+    ) -> AdditionalManifestFile {
+        AdditionalManifestFile {
+            path: path.into(),
+            basename: path.into(),
+            content: content.into(),
+            ..AdditionalManifestFile::default()
+        }
+    }
+
+    #[test]
+    fn verify_reports_nothing_when_every_target_is_updated() {
+        let mut package = releasable(ReleaseType::Node);
+        package.manifest_files =
+            Some(vec![manifest("package.json", r#"{"version": "1.0.0"}"#)]);
+        package.additional_manifest_files = Some(vec![additional_manifest(
+            "VERSION.txt",
+            "version = \"1.0.0\"",
+        )]);
+
+        let changes =
+            UpdateManager::get_package_manifest_file_changes(&package, &[])
+                .unwrap();
+
+        let problems = UpdateManager::verify_package_manifest_file_changes(
+            &package,
+            &[additional_target("VERSION.txt")],
+            &changes,
+        );
+
+        assert!(problems.is_empty(), "unexpected problems: {problems:?}");
+    }
+
+    #[test]
+    fn verify_reports_release_type_without_manifests() {
+        let package = releasable(ReleaseType::Rust);
+
+        let problems = UpdateManager::verify_package_manifest_file_changes(
+            &package,
+            &[],
+            &[],
+        );
+
+        assert_eq!(problems, vec!["my-pkg: no rust manifest files found"]);
+    }
+
+    #[test]
+    fn verify_reports_manifests_that_were_not_updated() {
+        let mut package = releasable(ReleaseType::Node);
+        package.manifest_files = Some(vec![manifest(
+            "package.json",
+            r#"{"name": "my-pkg", "version": "1.0.0"}"#,
+        )]);
+
+        let problems = UpdateManager::verify_package_manifest_file_changes(
+            &package,
+            &[],
+            &[],
+        );
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("no node manifest was updated"));
+        assert!(problems[0].contains("package.json"));
+    }
+
+    #[test]
+    fn verify_skips_manifests_without_a_version() {
+        let mut package = releasable(ReleaseType::Rust);
+        package.manifest_files = Some(vec![manifest(
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )]);
+
+        let changes =
+            UpdateManager::get_package_manifest_file_changes(&package, &[])
+                .unwrap();
+
+        let problems = UpdateManager::verify_package_manifest_file_changes(
+            &package,
+            &[],
+            &changes,
+        );
+
+        assert!(problems.is_empty(), "unexpected problems: {problems:?}");
+    }
+
+    #[test]
+    fn verify_reports_manifest_where_only_a_dependency_has_next_version() {
+        let mut package = releasable(ReleaseType::Rust);
+        package.manifest_files = Some(vec![manifest(
+            "Cargo.toml",
+            "[package]\nname = \"my-pkg\"\nversion = \"1.0.0\"\n",
+        )]);
+
+        let changes = vec![FileChange {
+            path: "Cargo.toml".into(),
+            content: "[package]\nname = \"my-pkg\"\nversion = \"1.0.0\"\n\n\
+                      [dependencies]\nother = \"2.0.0\"\n"
+                .into(),
+            update_type: FileUpdateType::Replace,
+        }];
+
+        let problems = UpdateManager::verify_package_manifest_file_changes(
+            &package,
+            &[],
+            &changes,
+        );
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("no rust manifest was updated"));
+    }
+
+    #[test]
+    fn verify_reports_manifest_with_a_longer_version_containing_next() {
+        let mut package = releasable(ReleaseType::Node);
+        package.manifest_files =
+            Some(vec![manifest("package.json", r#"{"version": "1.0.0"}"#)]);
+
+        let changes = vec![FileChange {
+            path: "package.json".into(),
+            content: r#"{"version": "12.0.0"}"#.into(),
+            update_type: FileUpdateType::Replace,
+        }];
+
+        let problems = UpdateManager::verify_package_manifest_file_changes(
+            &package,
+            &[],
+            &changes,
+        );
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("no node manifest was updated"));
+    }
+
+    #[test]
+    fn verify_ignores_generic_release_type() {
+        let package = releasable(ReleaseType::Generic);
+
+        let problems = UpdateManager::verify_package_manifest_file_changes(
+            &package,
+            &[],
+            &[],
+        );
+
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn verify_reports_additional_manifest_regex_without_match() {
+        let mut package = releasable(ReleaseType::Generic);
+        package.additional_manifest_files =
+            Some(vec![additional_manifest("VERSION.txt", "no version here")]);
+
+        let problems = UpdateManager::verify_package_manifest_file_changes(
+            &package,
+            &[additional_target("VERSION.txt")],
+            &[],
+        );
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("VERSION.txt was not updated"));
+    }
+
+    #[test]
+    fn verify_reports_missing_additional_manifest() {
+        let package = releasable(ReleaseType::Generic);
+
+        let problems = UpdateManager::verify_package_manifest_file_changes(
+            &package,
+            &[additional_target("VERSION.txt")],
+            &[],
+        );
+
+        assert_eq!(
+            problems,
+            vec!["my-pkg: additional manifest not found: VERSION.txt"]
+        );
+    }
+
+    #[test]
+    fn verify_accepts_additional_manifest_already_at_next_version() {
+        let mut package = releasable(ReleaseType::Generic);
+        package.additional_manifest_files = Some(vec![additional_manifest(
+            "VERSION.txt",
+            "version = \"2.0.0\"",
+        )]);
+
+        let problems = UpdateManager::verify_package_manifest_file_changes(
+            &package,
+            &[additional_target("VERSION.txt")],
+            &[],
+        );
+
+        assert!(problems.is_empty(), "unexpected problems: {problems:?}");
+    }
 }
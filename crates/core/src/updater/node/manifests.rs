@@ -1,6 +1,10 @@
 use std::path::Path;
 
-use crate::updater::{manager::ManifestTarget, traits::ManifestTargets};
+use crate::updater::{
+    manager::ManifestTarget,
+    node::package_json::PackageJson,
+    traits::{ManifestTargets, ManifestVersion},
+};
 
 pub struct NodeManifests {}
 
@@ -50,6 +54,19 @@ impl ManifestTargets for NodeManifests {
     }
 }
 
+impl ManifestVersion for NodeManifests {
+    fn manifest_version(
+        _pkg_name: &str,
+        basename: &str,
+        content: &str,
+    ) -> Option<String> {
+        match basename {
+            "package.json" => PackageJson::read_version(content),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
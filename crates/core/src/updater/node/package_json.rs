@@ -21,6 +21,12 @@ impl PackageJson {
         Self {}
     }
 
+    /// The top-level `version` a package.json declares.
+    pub fn read_version(content: &str) -> Option<String> {
+        let doc: Value = serde_json::from_str(content).ok()?;
+        doc.get("version")?.as_str().map(String::from)
+    }
+
     fn update_deps(
         &self,
         doc: &mut Value,
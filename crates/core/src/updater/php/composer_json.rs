@@ -15,6 +15,12 @@ impl ComposerJson {
         Self {}
     }
 
+    /// The top-level `version` a composer.json declares.
+    pub fn read_version(content: &str) -> Option<String> {
+        let doc: Value = serde_json::from_str(content).ok()?;
+        doc.get("version")?.as_str().map(String::from)
+    }
+
     /// Load and parse composer.json file from repository into serde_json Value.
     fn load_doc(&self, content: &str) -> Result<Option<Value>> {
         let doc: Value = serde_json::from_str(content)?;
@@ -1,6 +1,10 @@
 use std::path::Path;
 
-use crate::updater::{manager::ManifestTarget, traits::ManifestTargets};
+use crate::updater::{
+    manager::ManifestTarget,
+    php::composer_json::ComposerJson,
+    traits::{ManifestTargets, ManifestVersion},
+};
 
 pub struct PhpManifests {}
 
@@ -23,6 +27,19 @@ impl ManifestTargets for PhpManifests {
     }
 }
 
+impl ManifestVersion for PhpManifests {
+    fn manifest_version(
+        _pkg_name: &str,
+        basename: &str,
+        content: &str,
+    ) -> Option<String> {
+        match basename {
+            "composer.json" => ComposerJson::read_version(content),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
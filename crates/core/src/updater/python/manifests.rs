@@ -1,6 +1,14 @@
 use std::path::Path;
 
-use crate::updater::{manager::ManifestTarget, traits::ManifestTargets};
+use crate::{
+    config::package::GENERIC_VERSION_REGEX,
+    updater::{
+        generic::updater::GenericUpdater,
+        manager::ManifestTarget,
+        python::pyproject::PyProject,
+        traits::{ManifestTargets, ManifestVersion},
+    },
+};
 
 pub struct PythonManifests {}
 
@@ -25,6 +33,22 @@ impl ManifestTargets for PythonManifests {
     }
 }
 
+impl ManifestVersion for PythonManifests {
+    fn manifest_version(
+        _pkg_name: &str,
+        basename: &str,
+        content: &str,
+    ) -> Option<String> {
+        match basename {
+            "pyproject.toml" => PyProject::read_version(content),
+            "setup.cfg" | "setup.py" => {
+                GenericUpdater::read_version(content, &GENERIC_VERSION_REGEX)
+            }
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
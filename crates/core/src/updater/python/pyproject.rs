@@ -17,6 +17,19 @@ impl PyProject {
         let doc = content.parse::<DocumentMut>()?;
         Ok(doc)
     }
+
+    /// The `project.version` a pyproject.toml declares, falling back to
+    /// `tool.poetry.version` as the update does.
+    pub fn read_version(content: &str) -> Option<String> {
+        let doc = content.parse::<DocumentMut>().ok()?;
+
+        let project = match doc.get("project").and_then(|p| p.as_table()) {
+            Some(project) => project,
+            None => doc.get("tool")?.get("poetry")?.as_table()?,
+        };
+
+        project.get("version")?.as_str().map(String::from)
+    }
 }
 
 impl Default for PyProject {
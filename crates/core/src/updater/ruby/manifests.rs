@@ -1,6 +1,13 @@
 use std::path::Path;
 
-use crate::updater::{manager::ManifestTarget, traits::ManifestTargets};
+use crate::{
+    config::package::GENERIC_VERSION_REGEX,
+    updater::{
+        generic::updater::GenericUpdater,
+        manager::ManifestTarget,
+        traits::{ManifestTargets, ManifestVersion},
+    },
+};
 
 pub struct RubyManifests {}
 
@@ -34,6 +41,23 @@ impl ManifestTargets for RubyManifests {
     }
 }
 
+impl ManifestVersion for RubyManifests {
+    fn manifest_version(
+        _pkg_name: &str,
+        basename: &str,
+        content: &str,
+    ) -> Option<String> {
+        if basename == "version.rb" || basename.ends_with(".gemspec") {
+            return GenericUpdater::read_version(
+                content,
+                &GENERIC_VERSION_REGEX,
+            );
+        }
+
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
@@ -63,6 +63,15 @@ impl CargoToml {
         let doc = content.parse::<DocumentMut>()?;
         Ok(doc)
     }
+
+    /// The `package.version` a Cargo.toml declares.
+    pub fn read_version(content: &str) -> Option<String> {
+        let doc = content.parse::<DocumentMut>().ok()?;
+        doc.get("package")?
+            .get("version")?
+            .as_str()
+            .map(String::from)
+    }
 }
 
 impl PackageUpdater for CargoToml {
@@ -1,6 +1,10 @@
 use std::path::Path;
 
-use crate::updater::{manager::ManifestTarget, traits::ManifestTargets};
+use crate::updater::{
+    manager::ManifestTarget,
+    rust::cargo_toml::CargoToml,
+    traits::{ManifestTargets, ManifestVersion},
+};
 
 pub struct RustManifests {}
 
@@ -49,6 +53,19 @@ impl ManifestTargets for RustManifests {
     }
 }
 
+impl ManifestVersion for RustManifests {
+    fn manifest_version(
+        _pkg_name: &str,
+        basename: &str,
+        content: &str,
+    ) -> Option<String> {
+        match basename {
+            "Cargo.toml" => CargoToml::read_version(content),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
@@ -28,3 +28,17 @@ pub trait ManifestTargets {
         pkg_path: &Path,
     ) -> Vec<ManifestTarget>;
 }
+
+/// Reads a package's own version back out of its release type manifests,
+/// so rewritten manifests can be verified with the same parsing the
+/// updaters write them with.
+pub trait ManifestVersion {
+    /// The version `content` declares for `pkg_name`, when `basename` is a
+    /// manifest this release type keeps the package version in. `None` for
+    /// other files, such as lock files, and for manifests without one.
+    fn manifest_version(
+        pkg_name: &str,
+        basename: &str,
+        content: &str,
+    ) -> Option<String>;
+}